@@ -2,10 +2,26 @@ use rustc_version::Version;
 
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(rust_1_41, rust_1_55, rust_1_74, rust_1_78)");
 
     let rver = rustc_version::version().unwrap();
 
     if Version::new(1, 41, 0) <= rver {
         println!("cargo:rustc-cfg=rust_1_41");
     }
+
+    // Enables the accessor impls for `core::ops::ControlFlow`
+    if Version::new(1, 55, 0) <= rver {
+        println!("cargo:rustc-cfg=rust_1_55");
+    }
+
+    // Enables the accessor impls for `core::num::Saturating`
+    if Version::new(1, 74, 0) <= rver {
+        println!("cargo:rustc-cfg=rust_1_74");
+    }
+
+    // Enables the `#[diagnostic::on_unimplemented]` attributes
+    if Version::new(1, 78, 0) <= rver {
+        println!("cargo:rustc-cfg=rust_1_78");
+    }
 }
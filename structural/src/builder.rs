@@ -0,0 +1,96 @@
+/*!
+Items used by the type-state builders that the `Structural` derive generates
+with the [`#[struc(builder)]`](../docs/structural_macro/index.html#strucbuilder) attribute.
+
+A `#[struc(builder)]` struct named `Foo` gets a `FooBuilder` type,
+whose last type parameter is a type-level list of the names of the fields that were set.
+Fields are set with the `field` method (`.field(ts!(name), value)`),
+or with a method named after the field (`.name(value)`),
+and `build` can only be called once all the fields without an `init_with_*` attribute
+have been set.
+
+Checking whether the fields were set uses type-level comparison of [`TStr`]s,
+which only supports field names of up to 32 bytes,
+and is not available when the `use_const_str` feature is enabled.
+
+Because the setter methods are named after the fields,
+public fields can't be named `new`,`default`,`field`,or `build`.
+
+# Example
+
+```rust
+use structural::{Structural, ts};
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, builder)]
+struct Server {
+    host: String,
+    port: u16,
+    #[struc(init_with_lit = 8)]
+    workers: u32,
+    #[struc(init_with_default)]
+    verbose: bool,
+}
+
+let server = ServerBuilder::new()
+    .port(8080)
+    .field(ts!(host), "localhost".to_string())
+    .verbose(true)
+    .build();
+
+assert_eq!(
+    server,
+    Server{ host: "localhost".to_string(), port: 8080, workers: 8, verbose: true },
+);
+
+```
+
+### Unset fields
+
+This doesn't compile because the `port` field isn't set:
+
+```compile_fail
+use structural::{Structural, ts};
+
+#[derive(Structural)]
+#[struc(public, builder)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+let _ = ServerBuilder::new().host("localhost".to_string()).build();
+```
+
+### Conflicting field names
+
+This doesn't compile because the setter for the `build` field would conflict with
+the `build` method of the builder:
+
+```compile_fail
+use structural::Structural;
+
+#[derive(Structural)]
+#[struc(public, builder)]
+struct Job {
+    name: String,
+    build: u32,
+}
+```
+
+[`TStr`]: ../struct.TStr.html
+
+*/
+
+/// Sets the `FieldName` field of a builder generated by the
+/// `#[struc(builder)]` attribute,changing the type of the builder.
+pub trait BuilderField<FieldName>: Sized {
+    /// The type of the field.
+    type Ty;
+
+    /// The type of the builder after setting the field.
+    type Output;
+
+    /// Sets the `field_name` field to `value`.
+    fn set_field(self, field_name: FieldName, value: Self::Ty) -> Self::Output;
+}
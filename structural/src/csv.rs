@@ -0,0 +1,693 @@
+/*!
+Exporting and importing values as rows of comma-separated values (CSV).
+
+The [`header`],[`to_csv_row`],and [`from_csv_row`] functions
+use the fields that a path set refers to,
+with the (possibly renamed) field names as the column names.
+
+The [`header_of`] and [`row_of`] functions use all the accessible fields of a value,
+passing them to a [`FieldVisitor`],
+which works with any type that implements [`VisitFields`],
+including the types created with the [`make_struct`] macro.
+
+Fields are written with their `Display` impl,and parsed with their `FromStr` impl.
+Fields that contain commas,double quotes,or line breaks are surrounded by double quotes,
+with double quotes in the field escaped by doubling them.
+
+Only paths to fields of the exported/imported values (not nested fields) are supported,
+with names of up to 32 bytes.
+Rows can be parsed from up to 12 fields,the most fields that a [`Record`] can have.
+
+This module requires the "alloc" feature,
+and is not available when the `use_const_str` feature is enabled.
+
+# Example
+
+```rust
+use structural::{
+    csv::{self, CsvError},
+    Structural, fp, make_struct,
+};
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+struct Sale {
+    #[struc(rename = "product_name")]
+    product: String,
+    units: u32,
+    price: f64,
+}
+
+let sales = vec![
+    Sale{ product: "Pen".to_string(), units: 3, price: 1.5 },
+    Sale{ product: "Paper, A4".to_string(), units: 10, price: 4.25 },
+];
+
+let mut table = csv::header(fp!(product_name, units, price));
+for sale in &sales {
+    table.push('\n');
+    table.push_str(&csv::to_csv_row(sale, fp!(product_name, units, price)));
+}
+
+assert_eq!(
+    table,
+    "product_name,units,price\n\
+     Pen,3,1.5\n\
+     \"Paper, A4\",10,4.25",
+);
+
+let parsed = table.lines()
+    .skip(1)
+    .map(|row| csv::from_csv_row(row, fp!(product_name, units, price)) )
+    .collect::<Result<Vec<Sale>, CsvError>>()
+    .unwrap();
+
+assert_eq!(parsed, sales);
+
+// Exporting all the fields of an anonymous struct
+let total = make_struct!{ product: "Pen", revenue: 4.5 };
+assert_eq!( csv::header_of(&total), "product,revenue" );
+assert_eq!( csv::row_of(&total), "Pen,4.5" );
+
+```
+
+[`header`]: ./fn.header.html
+[`to_csv_row`]: ./fn.to_csv_row.html
+[`from_csv_row`]: ./fn.from_csv_row.html
+[`header_of`]: ./fn.header_of.html
+[`row_of`]: ./fn.row_of.html
+[`FieldVisitor`]: ../visit_fields/trait.FieldVisitor.html
+[`VisitFields`]: ../visit_fields/trait.VisitFields.html
+[`make_struct`]: ../macro.make_struct.html
+[`Record`]: ../record/struct.Record.html
+
+*/
+
+use crate::{
+    alloc::{
+        borrow::Cow,
+        string::{String, ToString},
+        vec::Vec,
+    },
+    convert::FromStructural,
+    field::GetField,
+    path::{FieldPathSet, TStr, UniquePaths},
+    record::{
+        BuildRecordFields, BuiltRecord, ForEachName, MakeField, NameOp, PathNames, PathNamesOut,
+        Record,
+    },
+    type_level::to_value_traits::ToStr,
+    visit_fields::{FieldInfo, FieldVisitor, VisitFields},
+};
+
+use std_::{
+    fmt::{self, Display, Write},
+    slice,
+    str::FromStr,
+};
+
+#[cfg(feature = "std")]
+use std::error::Error as StdError;
+
+/// Gets the header row for the fields that `path` refers to,
+/// with the names of the fields.
+///
+/// `path` can be a single field name (eg:`fp!(foo)`),
+/// or a set of field names (eg:`fp!(foo, bar, baz)`).
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{csv, fp};
+///
+/// assert_eq!( csv::header(fp!(name, age)), "name,age" );
+/// assert_eq!( csv::header(fp!("first, last")), "\"first, last\"" );
+///
+/// ```
+pub fn header<P>(path: P) -> String
+where
+    P: CsvHeader,
+{
+    let mut writer = CsvWriter::new();
+    path.write_header(&mut writer);
+    writer.into_string()
+}
+
+/// Gets a row with the values of the fields that `path` refers to,
+/// in the order that they're listed in the path.
+///
+/// `path` can be a single field name (eg:`fp!(foo)`),
+/// or a set of field names (eg:`fp!(foo, bar, baz)`).
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{csv, fp, make_struct};
+///
+/// let this = make_struct!{ name: "Bob \"the builder\"", age: 30, hidden: () };
+///
+/// assert_eq!( csv::to_csv_row(&this, fp!(age, name)), "30,\"Bob \"\"the builder\"\"\"" );
+///
+/// assert_eq!( csv::to_csv_row(&(3, 'a', "hi"), fp!(0, 2)), "3,hi" );
+///
+/// ```
+pub fn to_csv_row<T, P>(this: &T, path: P) -> String
+where
+    T: ?Sized,
+    P: WriteCsvRow<T>,
+{
+    let mut writer = CsvWriter::new();
+    path.write_row(this, &mut writer);
+    writer.into_string()
+}
+
+/// Parses a row into a `T`,
+/// parsing the fields that `path` refers to in the order that they're listed in the path,
+/// and then converting them into a `T` with `FromStructural`.
+///
+/// `path` can be a single field name (eg:`fp!(foo)`),
+/// or a set of up to 12 field names (eg:`fp!(foo, bar, baz)`).
+///
+/// The row can end with a line break,
+/// and must have as many fields as there are in `path`.
+///
+/// The `T` type can be a [`Record`],
+/// to parse rows into values with the same fields as the anonymous structs
+/// created with the [`make_struct`] macro.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{
+///     csv::{self, CsvError},
+///     Record, StructuralExt, Structural, fp,
+/// };
+///
+/// #[derive(Structural, Debug, PartialEq)]
+/// #[struc(public, from_structural)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_eq!( csv::from_csv_row("3,-5\n", fp!(x, y)), Ok(Point{ x: 3, y: -5 }) );
+/// assert_eq!( csv::from_csv_row("-5,3", fp!(y, x)), Ok(Point{ x: 3, y: -5 }) );
+///
+/// assert_eq!(
+///     csv::from_csv_row::<Point, _>("3", fp!(x, y)),
+///     Err(CsvError::FieldCount{ expected: 2, found: 1 }),
+/// );
+///
+/// let err = csv::from_csv_row::<Point, _>("3,hello", fp!(x, y)).unwrap_err();
+/// assert_eq!( err.to_string(), "could not parse \"hello\" for the `y` field: invalid digit found in string" );
+///
+/// let rec: Record!{ name: String, admin: bool } =
+///     csv::from_csv_row("\"Smith, John\",false", fp!(name, admin)).unwrap();
+/// assert_eq!( rec.fields(fp!(name, admin)), (&"Smith, John".to_string(), &false) );
+///
+/// ```
+///
+/// [`Record`]: ../record/struct.Record.html
+/// [`make_struct`]: ../macro.make_struct.html
+pub fn from_csv_row<T, P>(row: &str, path: P) -> Result<T, CsvError>
+where
+    P: ParseCsvRow<T>,
+{
+    path.parse_row(&split_row(row)?)
+}
+
+/// Gets the header row for all the accessible fields of `this`,
+/// with the names of the fields.
+///
+/// For enums,this only has the fields of the current variant.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{csv, Structural, make_struct};
+///
+/// #[derive(Structural)]
+/// struct Hello {
+///     pub foo: u32,
+///     #[struc(rename = "BAR")]
+///     pub bar: String,
+///     private: (),
+/// }
+///
+/// let this = Hello{ foo: 3, bar: "hello".to_string(), private: () };
+///
+/// assert_eq!( csv::header_of(&this), "foo,BAR" );
+/// assert_eq!( csv::header_of(&make_struct!{ a: 0, b: "" }), "a,b" );
+///
+/// ```
+pub fn header_of<T>(this: &T) -> String
+where
+    T: ?Sized + VisitFields<HeaderWriter>,
+{
+    let mut writer = HeaderWriter(CsvWriter::new());
+    this.visit_fields(&mut writer);
+    writer.0.into_string()
+}
+
+/// Gets a row with the values of all the accessible fields of `this`,
+/// in declaration order.
+///
+/// For enums,this only has the fields of the current variant.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{csv, Structural, make_struct};
+///
+/// #[derive(Structural)]
+/// struct Hello {
+///     pub foo: u32,
+///     #[struc(rename = "BAR")]
+///     pub bar: String,
+///     private: (),
+/// }
+///
+/// let this = Hello{ foo: 3, bar: "hello\nworld".to_string(), private: () };
+///
+/// assert_eq!( csv::row_of(&this), "3,\"hello\nworld\"" );
+/// assert_eq!( csv::row_of(&make_struct!{ a: 0, b: "" }), "0," );
+/// assert_eq!( csv::row_of(&[3, 5, 8]), "3,5,8" );
+///
+/// ```
+pub fn row_of<T>(this: &T) -> String
+where
+    T: ?Sized + VisitFields<CsvWriter>,
+{
+    let mut writer = CsvWriter::new();
+    this.visit_fields(&mut writer);
+    writer.into_string()
+}
+
+/// Splits a row into its fields,unescaping the quoted ones.
+///
+/// The row can end with a line break.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::csv::{self, CsvError};
+///
+/// assert_eq!( csv::split_row("foo,\"bar, \"\"baz\"\"\",\r\n").unwrap(), ["foo", "bar, \"baz\"", ""] );
+///
+/// assert_eq!( csv::split_row("\"foo"), Err(CsvError::UnclosedQuote{ column: 0 }) );
+/// assert_eq!( csv::split_row("a,\"foo\"bar"), Err(CsvError::TextAfterQuote{ column: 1 }) );
+///
+/// ```
+pub fn split_row(row: &str) -> Result<Vec<Cow<'_, str>>, CsvError> {
+    let row = row
+        .strip_suffix("\r\n")
+        .or_else(|| row.strip_suffix('\n'))
+        .unwrap_or(row);
+
+    let mut fields = Vec::new();
+    let mut rem = row;
+    loop {
+        let column = fields.len();
+        if let Some(mut quoted) = rem.strip_prefix('"') {
+            let mut unescaped = String::new();
+            loop {
+                let end = quoted.find('"').ok_or(CsvError::UnclosedQuote { column })?;
+                unescaped.push_str(&quoted[..end]);
+                quoted = &quoted[end + 1..];
+                match quoted.strip_prefix('"') {
+                    Some(after_quote) => {
+                        unescaped.push('"');
+                        quoted = after_quote;
+                    }
+                    None => break,
+                }
+            }
+            fields.push(Cow::Owned(unescaped));
+            if quoted.is_empty() {
+                break;
+            } else if let Some(after_comma) = quoted.strip_prefix(',') {
+                rem = after_comma;
+            } else {
+                return Err(CsvError::TextAfterQuote { column });
+            }
+        } else {
+            match rem.find(',') {
+                Some(end) => {
+                    fields.push(Cow::Borrowed(&rem[..end]));
+                    rem = &rem[end + 1..];
+                }
+                None => {
+                    fields.push(Cow::Borrowed(rem));
+                    break;
+                }
+            }
+        }
+    }
+    Ok(fields)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Writes the names of the fields that `Self` refers to,as a header row.
+///
+/// This is implemented for `TStr`,and for `FieldPathSet`s of `TStr`s.
+pub trait CsvHeader {
+    /// Writes the names of the fields that `self` refers to into `writer`.
+    fn write_header(self, writer: &mut CsvWriter);
+}
+
+/// Writes the values of the fields that `Self` refers to in `T`,as a row.
+///
+/// This is implemented for `TStr`,and for `FieldPathSet`s of `TStr`s.
+pub trait WriteCsvRow<T: ?Sized> {
+    /// Writes the values of the fields that `self` refers to in `this` into `writer`.
+    fn write_row(self, this: &T, writer: &mut CsvWriter);
+}
+
+/// Parses the fields that `Self` refers to,converting them into a `T`.
+///
+/// This is implemented for `TStr`,and for `FieldPathSet`s of up to 12 `TStr`s.
+pub trait ParseCsvRow<T> {
+    /// Parses `fields`,in the order of the fields that `self` refers to,
+    /// converting them into a `T` with `FromStructural`.
+    fn parse_row(self, fields: &[Cow<'_, str>]) -> Result<T, CsvError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Builds a row,escaping the fields that need it.
+///
+/// This is a [`FieldVisitor`] that writes the values of the visited fields.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::csv::CsvWriter;
+///
+/// let mut writer = CsvWriter::new();
+/// writer.push_field(&"hello");
+/// writer.push_field(&3);
+/// writer.push_field(&"a \"quote\"");
+///
+/// assert_eq!( writer.len(), 3 );
+/// assert_eq!( writer.as_str(), "hello,3,\"a \"\"quote\"\"\"" );
+///
+/// ```
+///
+/// [`FieldVisitor`]: ../visit_fields/trait.FieldVisitor.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CsvWriter {
+    row: String,
+    len: usize,
+}
+
+impl CsvWriter {
+    /// Constructs an empty `CsvWriter`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            row: String::new(),
+            len: 0,
+        }
+    }
+
+    /// Writes `value` as the next field of the row,using its `Display` impl.
+    pub fn push_field<T>(&mut self, value: &T)
+    where
+        T: ?Sized + Display,
+    {
+        if self.len != 0 {
+            self.row.push(',');
+        }
+        self.len += 1;
+
+        let start = self.row.len();
+        // Writing to a `String` can only fail if the `Display` impl returns an error.
+        let _ = write!(self.row, "{}", value);
+        if self.row[start..].contains(&[',', '"', '\n', '\r'][..]) {
+            let field = self.row.split_off(start);
+            self.row.push('"');
+            self.row.push_str(&field.replace('"', "\"\""));
+            self.row.push('"');
+        }
+    }
+
+    /// The amount of fields in the row.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the row has no fields.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the row built so far.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.row
+    }
+
+    /// Unwraps this into the row built so far.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.row
+    }
+}
+
+impl<T> FieldVisitor<T> for CsvWriter
+where
+    T: ?Sized + Display,
+{
+    #[inline]
+    fn visit_field(&mut self, _: FieldInfo, value: &T) {
+        self.push_field(value);
+    }
+}
+
+/// A [`FieldVisitor`] that writes the names of the visited fields into a [`CsvWriter`],
+/// used by [`header_of`].
+///
+/// [`FieldVisitor`]: ../visit_fields/trait.FieldVisitor.html
+/// [`CsvWriter`]: ./struct.CsvWriter.html
+/// [`header_of`]: ./fn.header_of.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HeaderWriter(pub CsvWriter);
+
+impl<T> FieldVisitor<T> for HeaderWriter
+where
+    T: ?Sized,
+{
+    #[inline]
+    fn visit_field(&mut self, field: FieldInfo, _: &T) {
+        self.0.push_field(field.name);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The error returned when a row can't be split or parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CsvError {
+    /// A quoted field was not closed.
+    UnclosedQuote {
+        /// The index of the field.
+        column: usize,
+    },
+    /// A quoted field was followed by something other than a comma.
+    TextAfterQuote {
+        /// The index of the field.
+        column: usize,
+    },
+    /// The row doesn't have the expected amount of fields.
+    FieldCount {
+        /// The amount of fields that were expected.
+        expected: usize,
+        /// The amount of fields in the row.
+        found: usize,
+    },
+    /// A field could not be parsed with its `FromStr` impl.
+    Parse {
+        /// The name of the field.
+        field: &'static str,
+        /// The text that could not be parsed.
+        value: String,
+        /// The error message of the `FromStr::Err`.
+        message: String,
+    },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::UnclosedQuote { column } => {
+                write!(f, "the quoted field at column {} was not closed", column)
+            }
+            CsvError::TextAfterQuote { column } => write!(
+                f,
+                "the quoted field at column {} was followed by text other than a comma",
+                column,
+            ),
+            CsvError::FieldCount { expected, found } => {
+                write!(f, "expected {} fields,found {}", expected, found)
+            }
+            CsvError::Parse {
+                field,
+                value,
+                message,
+            } => write!(
+                f,
+                "could not parse {:?} for the `{}` field: {}",
+                value, field, message,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for CsvError {}
+
+#[inline]
+fn parse_field<T>(field: &'static str, value: &str) -> Result<T, CsvError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse::<T>().map_err(|e| CsvError::Parse {
+        field,
+        value: value.to_string(),
+        message: e.to_string(),
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+impl<S> CsvHeader for TStr<S>
+where
+    FieldPathSet<(Self,), UniquePaths>: CsvHeader,
+{
+    #[inline(always)]
+    fn write_header(self, writer: &mut CsvWriter) {
+        self.into_set().write_header(writer)
+    }
+}
+
+impl<T, S> WriteCsvRow<T> for TStr<S>
+where
+    T: ?Sized,
+    FieldPathSet<(Self,), UniquePaths>: WriteCsvRow<T>,
+{
+    #[inline(always)]
+    fn write_row(self, this: &T, writer: &mut CsvWriter) {
+        self.into_set().write_row(this, writer)
+    }
+}
+
+impl<T, S> ParseCsvRow<T> for TStr<S>
+where
+    FieldPathSet<(Self,), UniquePaths>: ParseCsvRow<T>,
+{
+    #[inline(always)]
+    fn parse_row(self, fields: &[Cow<'_, str>]) -> Result<T, CsvError> {
+        self.into_set().parse_row(fields)
+    }
+}
+
+impl<U, P> CsvHeader for FieldPathSet<P, U>
+where
+    Self: PathNames,
+    for<'a> PathNamesOut<Self>: ForEachName<HeaderOp<'a>>,
+{
+    #[inline]
+    fn write_header(self, writer: &mut CsvWriter) {
+        PathNamesOut::<Self>::for_each_name(&mut HeaderOp(writer));
+    }
+}
+
+impl<T, U, P> WriteCsvRow<T> for FieldPathSet<P, U>
+where
+    T: ?Sized,
+    Self: PathNames,
+    for<'a> PathNamesOut<Self>: ForEachName<RowOp<'a, T>>,
+{
+    #[inline]
+    fn write_row(self, this: &T, writer: &mut CsvWriter) {
+        PathNamesOut::<Self>::for_each_name(&mut RowOp { this, writer });
+    }
+}
+
+impl<T, U, P> ParseCsvRow<T> for FieldPathSet<P, U>
+where
+    Self: PathNames,
+    PathNamesOut<Self>: ForEachName<usize>,
+    for<'a, 'b> PathNamesOut<Self>: BuildRecordFields<T, (), ParseOp<'a, 'b>, CsvError>,
+    for<'a, 'b> T: FromStructural<BuiltRecord<T, Self, ParseOp<'a, 'b>, CsvError>>,
+{
+    fn parse_row(self, fields: &[Cow<'_, str>]) -> Result<T, CsvError> {
+        let mut expected = 0;
+        PathNamesOut::<Self>::for_each_name(&mut expected);
+        if fields.len() != expected {
+            return Err(CsvError::FieldCount {
+                expected,
+                found: fields.len(),
+            });
+        }
+        let mut op = ParseOp(fields.iter());
+        let record_fields = PathNamesOut::<Self>::build_record_fields((), &mut op)?;
+        Ok(T::from_structural(Record(record_fields)))
+    }
+}
+
+/// Writes the name of each field in a path set,for the `CsvHeader` impls.
+#[doc(hidden)]
+pub struct HeaderOp<'a>(&'a mut CsvWriter);
+
+impl<'a, N> NameOp<N> for HeaderOp<'a>
+where
+    N: ToStr,
+{
+    #[inline]
+    fn name_op(&mut self, name: N) {
+        self.0.push_field(name.to_str());
+    }
+}
+
+/// Writes the value of each field in a path set,for the `WriteCsvRow` impls.
+#[doc(hidden)]
+pub struct RowOp<'a, T: ?Sized> {
+    this: &'a T,
+    writer: &'a mut CsvWriter,
+}
+
+impl<'a, T, N> NameOp<N> for RowOp<'a, T>
+where
+    T: ?Sized + GetField<N>,
+    T::Ty: Display,
+{
+    #[inline]
+    fn name_op(&mut self, name: N) {
+        self.writer.push_field(self.this.get_field_(name));
+    }
+}
+
+/// Parses each field in a path set,for the `ParseCsvRow` impls.
+#[doc(hidden)]
+pub struct ParseOp<'a, 'b>(slice::Iter<'a, Cow<'b, str>>);
+
+impl<'a, 'b, N, Ty> MakeField<N, Ty> for ParseOp<'a, 'b>
+where
+    N: ToStr,
+    Ty: FromStr,
+    Ty::Err: Display,
+{
+    type Error = CsvError;
+
+    #[inline]
+    fn make_field(&mut self, name: N) -> Result<Ty, CsvError> {
+        // The amount of fields is checked before parsing them.
+        let value = self.0.next().expect("expected a field for every path");
+        parse_field(name.to_str(), value)
+    }
+}
@@ -0,0 +1,481 @@
+/*!
+Computing the differences between the fields of two values,
+and applying them to other values with the same fields.
+
+The [`diff`] function compares the fields that a path set refers to,
+returning a [`Patch`] with the old and new values of the fields that differ.
+That [`Patch`] can then be applied to any type with the same fields,
+with the [`apply`] function or the [`Patch::apply`] method.
+
+Only paths to fields of the compared values (not nested fields) are supported,
+which can be up to 8 fields with names of up to 32 bytes.
+
+# Example
+
+This example shows how the fields of a client-side model can be synchronized with
+a server-side model,logging the changes.
+
+```rust
+use structural::{
+    diff::{self, ChangeVisitor, FieldChange},
+    Structural, fp,
+};
+
+use std::fmt::{Debug, Write};
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public)]
+struct ClientUser {
+    name: String,
+    email: String,
+    age: u32,
+    dark_mode: bool,
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public)]
+struct ServerUser {
+    id: u64,
+    name: String,
+    email: String,
+    age: u32,
+}
+
+struct AuditLog(String);
+
+impl<T: Debug> ChangeVisitor<T> for AuditLog {
+    fn visit_change(&mut self, name: &'static str, change: &FieldChange<T>) {
+        write!(self.0, "{}: {:?} -> {:?}; ", name, change.old, change.new).unwrap();
+    }
+}
+
+let before = ClientUser{
+    name: "bob".to_string(),
+    email: "bob@example.com".to_string(),
+    age: 30,
+    dark_mode: false,
+};
+
+let mut after = before.clone();
+after.age = 31;
+after.email = "robert@example.com".to_string();
+after.dark_mode = true;
+
+let patch = diff::diff(&before, &after, fp!(name, email, age));
+assert_eq!( patch.len(), 2 );
+
+let mut log = AuditLog(String::new());
+patch.visit_changes(&mut log);
+assert_eq!(
+    log.0,
+    r#"email: "bob@example.com" -> "robert@example.com"; age: 30 -> 31; "#,
+);
+
+let mut server = ServerUser{
+    id: 1001,
+    name: "bob".to_string(),
+    email: "bob@example.com".to_string(),
+    age: 30,
+};
+diff::apply(&mut server, patch);
+
+assert_eq!(
+    server,
+    ServerUser{
+        id: 1001,
+        name: "bob".to_string(),
+        email: "robert@example.com".to_string(),
+        age: 31,
+    },
+);
+
+```
+
+*/
+
+use crate::{
+    field::{GetField, GetFieldMut, GetFieldType},
+    path::{FieldPathSet, TStr, UniquePaths},
+    type_level::to_value_traits::ToStr,
+};
+
+use std_::fmt::{self, Debug};
+
+/// The old and new values of a field that differs between two values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FieldChange<T> {
+    /// The value of the field in the first value passed to [`diff`](./fn.diff.html).
+    pub old: T,
+    /// The value of the field in the second value passed to [`diff`](./fn.diff.html).
+    pub new: T,
+}
+
+impl<T> FieldChange<T> {
+    #[inline]
+    fn between(old: &T, new: &T) -> Option<Self>
+    where
+        T: PartialEq + Clone,
+    {
+        if old == new {
+            None
+        } else {
+            Some(FieldChange {
+                old: old.clone(),
+                new: new.clone(),
+            })
+        }
+    }
+}
+
+/// The changes to the fields that `P` refers to,returned by the [`diff`] function.
+///
+/// `C` is a tuple with an `Option<FieldChange<_>>` for each field in `P`,
+/// which is `None` for fields that didn't change.
+///
+/// For an example,look at the [module-level documentation](./index.html).
+///
+/// [`diff`]: ./fn.diff.html
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Patch<P, C> {
+    /// The path to the fields that were compared.
+    pub path: P,
+    /// The changes to each field in `path`.
+    pub changes: C,
+}
+
+/// The type of the changes to the fields that `P` refers to,
+/// from `A` to `B`.
+pub type DiffFieldsOut<P, A, B> = <P as DiffFields<A, B>>::Changes;
+
+/// The type of the [`Patch`] returned by [`diff`] for the `P` path,
+/// from `A` to `B`.
+///
+/// [`Patch`]: ./struct.Patch.html
+/// [`diff`]: ./fn.diff.html
+pub type PatchOf<P, A, B> = Patch<P, DiffFieldsOut<P, A, B>>;
+
+impl<P, C> Patch<P, C> {
+    /// Applies the changes in this patch to the fields of `this`.
+    ///
+    /// This is equivalent to the [`apply`](./fn.apply.html) function.
+    #[inline]
+    pub fn apply<T>(self, this: &mut T)
+    where
+        T: ?Sized,
+        P: ApplyChanges<T, C>,
+    {
+        self.path.apply_changes(this, self.changes)
+    }
+
+    /// Calls `visitor` with the name,old value,and new value of every changed field,
+    /// in the order that they're listed in the path.
+    #[inline]
+    pub fn visit_changes<V>(&self, visitor: &mut V)
+    where
+        V: ?Sized,
+        P: VisitChanges<C, V> + Copy,
+    {
+        self.path.visit_changes(&self.changes, visitor)
+    }
+
+    /// The amount of fields that changed.
+    #[inline]
+    pub fn len(&self) -> usize
+    where
+        C: FieldChanges,
+    {
+        self.changes.changed_count()
+    }
+
+    /// Whether no field changed.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    where
+        C: FieldChanges,
+    {
+        self.changes.changed_count() == 0
+    }
+}
+
+/// Prints the changed fields as a map from the name of the field to its change.
+impl<P, C> Debug for Patch<P, C>
+where
+    P: Copy + for<'a, 'b> VisitChanges<C, DebugChanges<'a, 'b>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut visitor = DebugChanges { map: f.debug_map() };
+        self.visit_changes(&mut visitor);
+        visitor.map.finish()
+    }
+}
+
+/// Compares the fields that `path` refers to in `old` and `new`,
+/// returning a [`Patch`] with the fields that differ.
+///
+/// `path` can be a single field name (eg:`fp!(foo)`),
+/// or a set of up to 8 field names (eg:`fp!(foo, bar, baz)`).
+///
+/// The fields must have the same type in both `old` and `new`.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{diff::{diff, FieldChange}, fp, make_struct};
+///
+/// let patch = diff(&(3, "foo", 'a'), &(3, "bar", 'b'), fp!(0, 1));
+///
+/// assert_eq!( patch.len(), 1 );
+/// assert_eq!( patch.changes, (None, Some(FieldChange{ old: "foo", new: "bar" })) );
+///
+/// let before = make_struct!{ x: 0, y: 0 };
+/// let after = make_struct!{ x: 0, y: 0 };
+///
+/// assert!( diff(&before, &after, fp!(x, y)).is_empty() );
+///
+/// ```
+///
+/// [`Patch`]: ./struct.Patch.html
+#[inline]
+pub fn diff<A, B, P>(old: &A, new: &B, path: P) -> Patch<P, P::Changes>
+where
+    A: ?Sized,
+    B: ?Sized,
+    P: DiffFields<A, B> + Copy,
+{
+    Patch {
+        path,
+        changes: path.diff_fields(old, new),
+    }
+}
+
+/// Applies the changes in `patch` to the fields of `this`.
+///
+/// `this` can be of any type with the fields in the patch,
+/// it doesn't have to be the same type as the values that were compared.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{diff::{apply, diff}, StructuralExt, fp, make_struct};
+///
+/// let patch = diff(
+///     &make_struct!{ x: 3, y: 5, z: 8 },
+///     &make_struct!{ x: 3, y: 13, z: 21 },
+///     fp!(x, y),
+/// );
+///
+/// let mut other = make_struct!{ x: 0, y: 0, label: "hello" };
+///
+/// apply(&mut other, patch);
+/// assert_eq!( other.into_fields(fp!(x, y, label)), (0, 13, "hello") );
+///
+/// ```
+#[inline]
+pub fn apply<T, P, C>(this: &mut T, patch: Patch<P, C>)
+where
+    T: ?Sized,
+    P: ApplyChanges<T, C>,
+{
+    patch.apply(this)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Compares the fields that `Self` refers to in `A` and `B`.
+///
+/// This is implemented for `TStr`,and for `FieldPathSet`s of up to 8 `TStr`s.
+pub trait DiffFields<A: ?Sized, B: ?Sized> {
+    /// A tuple with an `Option<FieldChange<_>>` for each field.
+    type Changes;
+
+    /// Compares the fields that `self` refers to in `old` and `new`.
+    fn diff_fields(self, old: &A, new: &B) -> Self::Changes;
+}
+
+/// Applies the `C` changes to the fields that `Self` refers to in `This`.
+///
+/// This is implemented for `TStr`,and for `FieldPathSet`s of up to 8 `TStr`s.
+pub trait ApplyChanges<This: ?Sized, C> {
+    /// Applies `changes` to the fields that `self` refers to in `this`.
+    fn apply_changes(self, this: &mut This, changes: C);
+}
+
+/// Passes every changed field in `C` to the `V` visitor,
+/// using the names of the fields that `Self` refers to.
+///
+/// This is implemented for `TStr`,and for `FieldPathSet`s of up to 8 `TStr`s.
+pub trait VisitChanges<C, V: ?Sized> {
+    /// Passes every changed field in `changes` to `visitor`.
+    fn visit_changes(self, changes: &C, visitor: &mut V);
+}
+
+/// A visitor of [`FieldChange`]s of type `T`,passed to [`Patch::visit_changes`].
+///
+/// To visit changes to fields of any type,implement this trait generically over `T`.
+///
+/// [`FieldChange`]: ./struct.FieldChange.html
+/// [`Patch::visit_changes`]: ./struct.Patch.html#method.visit_changes
+pub trait ChangeVisitor<T> {
+    /// Visits the change to the `name` field.
+    fn visit_change(&mut self, name: &'static str, change: &FieldChange<T>);
+}
+
+/// The tuples of `Option<FieldChange<_>>` stored in a [`Patch`].
+///
+/// [`Patch`]: ./struct.Patch.html
+pub trait FieldChanges {
+    /// The amount of fields that changed.
+    fn changed_count(&self) -> usize;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+mod debug_changes {
+    use super::{ChangeVisitor, FieldChange};
+
+    use std_::fmt::{Debug, DebugMap};
+
+    /// The visitor used to print a `Patch`.
+    pub struct DebugChanges<'a, 'b> {
+        pub(super) map: DebugMap<'a, 'b>,
+    }
+
+    impl<'a, 'b, T: Debug> ChangeVisitor<T> for DebugChanges<'a, 'b> {
+        fn visit_change(&mut self, name: &'static str, change: &FieldChange<T>) {
+            self.map.entry(&name, change);
+        }
+    }
+}
+use self::debug_changes::DebugChanges;
+
+////////////////////////////////////////////////////////////////////////////////
+
+impl<A, B, S> DiffFields<A, B> for TStr<S>
+where
+    A: ?Sized,
+    B: ?Sized,
+    FieldPathSet<(Self,), UniquePaths>: DiffFields<A, B>,
+{
+    type Changes = DiffFieldsOut<FieldPathSet<(Self,), UniquePaths>, A, B>;
+
+    #[inline(always)]
+    fn diff_fields(self, old: &A, new: &B) -> Self::Changes {
+        self.into_set().diff_fields(old, new)
+    }
+}
+
+impl<This, C, S> ApplyChanges<This, C> for TStr<S>
+where
+    This: ?Sized,
+    FieldPathSet<(Self,), UniquePaths>: ApplyChanges<This, C>,
+{
+    #[inline(always)]
+    fn apply_changes(self, this: &mut This, changes: C) {
+        self.into_set().apply_changes(this, changes)
+    }
+}
+
+impl<C, V, S> VisitChanges<C, V> for TStr<S>
+where
+    V: ?Sized,
+    FieldPathSet<(Self,), UniquePaths>: VisitChanges<C, V>,
+{
+    #[inline(always)]
+    fn visit_changes(self, changes: &C, visitor: &mut V) {
+        self.into_set().visit_changes(changes, visitor)
+    }
+}
+
+macro_rules! impl_diff_traits {
+    ( $( ($path:ident, $ty:ident, $p:ident, $c:ident) )* ) => {
+        impl<A, B, U, $($path,)*> DiffFields<A, B> for FieldPathSet<($($path,)*), U>
+        where
+            A: ?Sized $( + GetField<$path> )*,
+            B: ?Sized $( + GetField<$path, Ty = GetFieldType<A, $path>> )*,
+            $(
+                $path: Copy,
+                GetFieldType<A, $path>: PartialEq + Clone,
+            )*
+        {
+            type Changes = ( $( Option<FieldChange<GetFieldType<A, $path>>>, )* );
+
+            #[inline]
+            #[allow(unused_variables, clippy::unused_unit)]
+            fn diff_fields(self, old: &A, new: &B) -> Self::Changes {
+                let ($($p,)*) = self.into_paths();
+                (
+                    $(
+                        FieldChange::between(old.get_field_($p), new.get_field_($p)),
+                    )*
+                )
+            }
+        }
+
+        impl<This, U, $($path, $ty,)*> ApplyChanges<This, ($(Option<FieldChange<$ty>>,)*)>
+        for FieldPathSet<($($path,)*), U>
+        where
+            This: ?Sized $( + GetFieldMut<$path, Ty = $ty> )*,
+        {
+            #[inline]
+            #[allow(unused_variables)]
+            fn apply_changes(self, this: &mut This, changes: ($(Option<FieldChange<$ty>>,)*)) {
+                let ($($p,)*) = self.into_paths();
+                let ($($c,)*) = changes;
+                $(
+                    if let Some(change) = $c {
+                        *this.get_field_mut_($p) = change.new;
+                    }
+                )*
+            }
+        }
+
+        impl<V, U, $($path, $ty,)*> VisitChanges<($(Option<FieldChange<$ty>>,)*), V>
+        for FieldPathSet<($($path,)*), U>
+        where
+            V: ?Sized $( + ChangeVisitor<$ty> )*,
+            $( $path: ToStr, )*
+        {
+            #[inline]
+            #[allow(unused_variables)]
+            fn visit_changes(self, changes: &($(Option<FieldChange<$ty>>,)*), visitor: &mut V) {
+                let ($($p,)*) = self.into_paths();
+                let ($($c,)*) = changes;
+                $(
+                    if let Some(change) = $c {
+                        visitor.visit_change($p.to_str(), change);
+                    }
+                )*
+            }
+        }
+
+        impl<$($ty,)*> FieldChanges for ($(Option<FieldChange<$ty>>,)*) {
+            #[inline]
+            fn changed_count(&self) -> usize {
+                let ($($c,)*) = self;
+                0 $( + ($c.is_some() as usize) )*
+            }
+        }
+    };
+}
+
+impl_diff_traits! {}
+impl_diff_traits! { (P0, T0, p0, c0) }
+impl_diff_traits! { (P0, T0, p0, c0) (P1, T1, p1, c1) }
+impl_diff_traits! { (P0, T0, p0, c0) (P1, T1, p1, c1) (P2, T2, p2, c2) }
+impl_diff_traits! { (P0, T0, p0, c0) (P1, T1, p1, c1) (P2, T2, p2, c2) (P3, T3, p3, c3) }
+impl_diff_traits! {
+    (P0, T0, p0, c0) (P1, T1, p1, c1) (P2, T2, p2, c2) (P3, T3, p3, c3)
+    (P4, T4, p4, c4)
+}
+impl_diff_traits! {
+    (P0, T0, p0, c0) (P1, T1, p1, c1) (P2, T2, p2, c2) (P3, T3, p3, c3)
+    (P4, T4, p4, c4) (P5, T5, p5, c5)
+}
+impl_diff_traits! {
+    (P0, T0, p0, c0) (P1, T1, p1, c1) (P2, T2, p2, c2) (P3, T3, p3, c3)
+    (P4, T4, p4, c4) (P5, T5, p5, c5) (P6, T6, p6, c6)
+}
+impl_diff_traits! {
+    (P0, T0, p0, c0) (P1, T1, p1, c1) (P2, T2, p2, c2) (P3, T3, p3, c3)
+    (P4, T4, p4, c4) (P5, T5, p5, c5) (P6, T6, p6, c6) (P7, T7, p7, c7)
+}
@@ -27,6 +27,14 @@ for pub fields.
 - Implementation of the [`DropFields`] trait,
 if the type has by-value accessors (implements [`IntoField`]) for any field.
 
+- Implementation of the [`AllFields`] trait,
+  if every field has mutable accessors (implements [`GetFieldMut`]),
+  which allows initializing the struct one field at a time with [`UninitBuilder`].
+
+- Implementations of the [`FieldOffset`] trait for pub fields,
+  if the struct is `#[repr(C)]` (and not `#[repr(packed)]`),
+  which allows getting pointers to (possibly nested) fields with [`field_ptr`].
+
 - A trait named `<DerivingType>_SI`,aliasing the accessor traits for the type,
 implemented for all types with the same accessor trait impls.
 
@@ -51,6 +59,16 @@ Many of these can be overriden.
 
 [`DropFields`]: ../../field/ownership/trait.DropFields.html
 
+[`MovedOutFields`]: ../../field/ownership/struct.MovedOutFields.html
+
+[`AllFields`]: ../../field/trait.AllFields.html
+
+[`FieldOffset`]: ../../field/field_offset/trait.FieldOffset.html
+
+[`field_ptr`]: ../../field/field_offset/fn.field_ptr.html
+
+[`UninitBuilder`]: ../../uninit_builder/struct.UninitBuilder.html
+
 [`DropFields::drop_fields`]:
 ../../field/ownership/trait.DropFields.html#tymethod.drop_fields
 
@@ -115,6 +133,26 @@ Disables the generation of the `*SI` traits.
 
 [Here is an example using this attribute](#disabling-the-trait-alias)
 
+### `#[struc(assoc_types)]`
+
+Replaces the type parameters of the `*SI` traits with associated types of the same name,
+so that `P: Point_SI` can be used instead of `P: Point_SI<T>` for a `Point<T>` struct.
+
+The type parameters that are the type of an accessible field
+(eg: `x: T`) become associated types,which are the type of that field.
+The bounds of the type parameter (including `T: Trait` where predicates)
+become bounds of the associated type.
+The other type parameters (eg: `T` in `x: Vec<T>`) stay as type parameters of the trait.
+
+The `*SI` traits with associated types can't be used as trait objects,
+because Rust requires the types of all the fields to be specified in a `dyn Trait` type,
+even when they're declared as equal to an associated type
+(eg: `dyn Point_SI<T = u32>` is an error).
+The `*SI` trait that the derive generates without this attribute
+can be used as a trait object (eg: `dyn Point_SI<u32>`).
+
+[Here is an example using this attribute](#associated-types-in-the-trait-alias)
+
 ### `#[struc(no_docs)]`
 
 Removes the docs for the generated traits,and impl of `Structural`.
@@ -131,6 +169,200 @@ by-value accessors with the same names and type as this type's public fields.
 Private fields must be annotated with one of the [`#[struc(init_*)]`](#init-attributes)
 attributes.
 
+When combined with any `#[struc(validate = "...")]` attribute,
+this only derives [`TryFromStructural`],
+with [`ValidationErrors`] as the error type.
+The converted-from fields are validated before they're moved out,
+so that the converted-from value can be returned in the error.
+If they're valid,the container validator runs on a value constructed from
+clones of the converted-from fields,which requires those fields to implement `Clone`.
+If the container validator fails the converted-from value is returned in the error,
+otherwise it's dropped.
+The validators of fields initialized with `#[struc(init_*)]` attributes
+are not run by the conversion
+(you can call [`Validate::validate`] on the converted value).
+
+### `#[struc(builder)]`
+
+This is only usable on structs.
+
+Generates a `<DerivingType>Builder` type-state builder,
+with the same visibility and generic parameters as the deriving type,
+plus a trailing type parameter that defaults to an empty type-level list,
+and is the list of the names of the fields that were set.
+
+Public fields can be set with the `field` method (eg: `.field(ts!(name), value)`),
+or with a method named after the field (eg: `.name(value)`,only for named fields).
+Setting a field again replaces the previous value.
+
+The `build` method constructs the struct,
+and only compiles once every public field without an
+[`#[struc(init_*)]`](#init-attributes) attribute has been set.
+Public fields with an `init_*` attribute are initialized with it if they're not set,
+while private fields must have an `init_*` attribute.
+
+Public fields can't be named `new`,`default`,`field`,or `build`,
+since the setter methods would conflict with the methods of the builder.
+
+This attribute can't be used when the `use_const_str` feature is enabled,
+since it relies on type-level comparison of field names.
+
+[Here is an example using this attribute](../../builder/index.html#example)
+
+### `#[struc(soa)]`
+
+This is only usable on structs,and requires the "alloc" feature.
+
+Implements [`SoaFields`] for the type,so that it can be stored in a [`SoaVec`],
+which stores each public field in its own `Vec`.
+
+Generates a `<DerivingType>Columns` struct,
+with the same visibility and generic parameters as the deriving type,
+which has a `Vec` for each public field,
+implementing the accessor traits with the same (possibly renamed) field names.
+
+All the public fields must have by-value accessors,
+since they're moved into the columns with [`StructuralExt::into_fields`].
+Private fields are dropped when an element is pushed into a [`SoaVec`].
+
+[Here is an example using this attribute](../../soa/index.html#example)
+
+[`SoaFields`]: ../../soa/trait.SoaFields.html
+[`SoaVec`]: ../../soa/struct.SoaVec.html
+
+### `#[struc(from_str_map)]`
+
+This is only usable on structs,and requires the "alloc" feature.
+
+Implements [`StrMapFields`] for the type,
+so that it can be loaded from a map of string keys to string values,
+with [`str_map::from_str_map`] or [`str_map::from_env`].
+This also derives `FromStructural`,as the [`#[struc(from_structural)]`](#strucfrom_structural)
+attribute does,if it isn't used.
+
+Every public field is parsed with its `FromStr` impl,
+using the (possibly renamed) name of the field as the key,
+and then converted into the type.
+Public fields with an [`#[struc(init_*)]`](#init-attributes) attribute
+are initialized with it if their key is missing,
+and must have mutable access,
+while private fields must have an `init_*` attribute.
+
+Public fields with the [`#[struc(str_map_nested)]`](#strucstr_map_nested) attribute
+are loaded from the keys with their name as a prefix instead.
+
+If the type uses the `#[struc(validate = "...")]`
+attributes,the loaded value is validated after every field is loaded
+(including the fields with `init_*` attributes),
+returning the errors in [`StrMapError::Invalid`].
+
+[Here is an example using this attribute](../../str_map/index.html#example)
+
+[`StrMapFields`]: ../../str_map/trait.StrMapFields.html
+[`str_map::from_str_map`]: ../../str_map/fn.from_str_map.html
+[`str_map::from_env`]: ../../str_map/fn.from_env.html
+[`StrMapError::Invalid`]: ../../str_map/enum.StrMapError.html#variant.Invalid
+
+### `#[struc(validate = "path::to::function")]`
+
+This is only usable on structs,and requires the "alloc" feature.
+
+Derives the [`Validate`] trait,
+calling `function` with a reference to the struct to validate it as a whole.
+
+`function` must have a `fn(&Self) -> Result<(), E>` signature,
+where `E` is any type that converts into a `Cow<'static, str>`
+(eg: `&'static str`,`String`).
+
+This attribute can also be used on fields,
+as described in the [Field Attributes](#field-attributes) section.
+
+### `#[struc(remote = "path::to::Type")]`
+
+This is only usable on structs,
+and can't be combined with the `delegate_to`,`delegate_fields`,`pin`,`validate`,
+`builder`,or `from_structural` attributes.
+
+Implements the accessor traits for the `Type` struct (which must not have generic arguments),
+instead of the deriving struct,
+which must mirror all the fields of `Type`,with the same types and generic parameters.
+This is a compile-time error if any field is missing,or has a different type.
+
+The attributes on the fields of the deriving struct apply to the fields of `Type`,
+eg: a private field of the deriving struct doesn't get accessor impls.
+
+The `<DerivingType>_SI` trait is named after the deriving struct.
+
+Implementing traits for types from other crates is only possible
+where the orphan rules allow it,
+for those you can add the `#[struc(newtype = "NewtypeName")]` attribute,
+which declares a `NewtypeName` tuple struct wrapping `Type` in a public field,
+with the same visibility and generic parameters as the deriving struct.
+The accessor traits are then implemented for the newtype,
+the `<NewtypeName>_SI` trait is generated instead of `<DerivingType>_SI`,
+and every field of the deriving struct must get accessor impls.
+The newtype implements `From` to convert from and into `Type`.
+
+Types with public fields can also be wrapped in a newtype with the
+[`remote_structural`](../../macro.remote_structural.html) macro,
+without declaring a mirroring struct.
+
+Example:
+
+```rust
+use structural::{fp, Structural, StructuralExt};
+
+// Pretend that this is a module in another crate
+mod shapes {
+    pub struct Rectangle {
+        pub width: u32,
+        pub height: u32,
+    }
+
+    pub struct Circle<T> {
+        pub radius: T,
+    }
+}
+
+use shapes::{Circle, Rectangle};
+
+// Implements the accessor traits for `shapes::Rectangle`.
+#[derive(Structural)]
+#[struc(remote = "shapes::Rectangle")]
+struct RectangleDef {
+    pub width: u32,
+    pub height: u32,
+}
+
+// Implements the accessor traits for `CircleS<T>`,
+// a newtype wrapping `shapes::Circle<T>`.
+#[derive(Structural)]
+#[struc(remote = "shapes::Circle", newtype = "CircleS")]
+pub struct CircleDef<T> {
+    pub radius: T,
+}
+
+fn area(this: &impl RectangleDef_SI) -> u32 {
+    let (width, height) = this.fields(fp!(width, height));
+    width * height
+}
+
+let mut rect = Rectangle{ width: 3, height: 5 };
+assert_eq!( area(&rect), 15 );
+
+*rect.field_mut(fp!(width)) = 4;
+assert_eq!( area(&rect), 20 );
+
+fn radius<T: Copy>(this: &impl CircleS_SI<T>) -> T {
+    *this.field_(fp!(radius))
+}
+
+let circle = CircleS::from(Circle{ radius: 8 });
+assert_eq!( radius(&circle), 8 );
+assert_eq!( circle.into_field(fp!(radius)), 8 );
+
+```
+
 ### `#[non_exhaustive]`
 
 This is only usable on enums.
@@ -233,9 +465,6 @@ The name can be anything,including non-ascii identifiers.
 
 ### `#[struc(impl="<trait bounds>")]`
 
-This requires the `nightly_impl_fields` cargo feature
-(or `impl_fields` if associated type bounds stabilized after the latest release).
-
 Changes the generated `*SI` traits (which aliases the accessor traits for this type)
 not to refer to the type of this field,
 instead it will be required to implement the bounds passed to this attribute.
@@ -261,11 +490,76 @@ Optional arguments for `delegate_to`:
 - `mut_bound="T:bound"`: Adds the constraint to the `GetField` impl.
 - `into_bound="T:bound"`: Adds the constraint to the `IntoField` impl.
 
+### `#[struc(delegate_fields(<field names>))]`
+
+This can only be used with structs.
+
+Delegates the [`GetField`] and [`GetFieldMut`] impls of the listed fields
+to the field this attribute is on,
+the field names are identifiers,integers (for tuple fields),or string literals.
+Mutable access is only delegated for the fields that the type of this field
+has a `GetFieldMut` impl for.
+
+Fields with this attribute are treated as private fields (they don't get accessor impls),
+since accessing them by mutable reference alongside the delegated fields would be unsound.
+
+Unlike `#[struc(delegate_to)]`,this can be used on multiple fields,
+combined with the accessor impls for the other fields of the struct.
+
+By-value access ([`IntoField`]) is delegated if the field this attribute is on
+has by-value access (the default,changed with the [`access`](#strucaccess) attribute),
+in which case the type of the field must implement [`DropFields`].
+The bits of [`MovedOutFields`] that aren't used by the other fields of the struct
+are split evenly between the fields with this attribute,
+to track the fields moved out of each one.
+
+The delegated fields are included in the `*_SI` trait,
+with the access of the field this attribute is on.
+
+This uses [the `delegate_fields` form](../../macro.unsafe_delegate_structural_with.html#partial-delegation)
+of the `unsafe_delegate_structural_with` macro.
+
+Example:
+
+```rust
+use structural::{Structural, StructuralExt, fp};
+
+#[derive(Structural)]
+#[struc(public)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Structural)]
+#[struc(public)]
+struct Sprite {
+    visible: bool,
+    #[struc(delegate_fields(x, y))]
+    pos: Position,
+    #[struc(delegate_fields(0))]
+    size: (u32, u32),
+}
+
+let mut sprite = Sprite{ visible: true, pos: Position{ x: 3.0, y: 5.0 }, size: (8, 13) };
+
+*sprite.field_mut(fp!(x)) += 1.0;
+assert_eq!( sprite.fields(fp!(visible, x, y, 0)), (&true, &4.0, &5.0, &8) );
+
+fn area(this: impl Sprite_SI) -> u32 {
+    let (w, x) = this.into_fields(fp!(0, x));
+    w * (x as u32)
+}
+assert_eq!( area(sprite), 32 );
+
+```
+
 <span id="init-attributes"></span>
 
 ### `#[struc(init_with_fn = "<callable_expression>")]`
 
-This can only be used in combination with the `#[struc(from_structural)]` container attribute.
+This can only be used in combination with the `#[struc(from_structural)]`,
+`#[struc(builder)]`,or `#[struc(from_str_map)]` container attributes.
 
 Initialize the field in the `FromStructural` impl  with the return value of
 calling the `<callable_expression>` expression.
@@ -282,7 +576,8 @@ Examples:<br>
 
 ### `#[struc(init_with_val = "<expression>")]`
 
-This can only be used in combination with the `#[struc(from_structural)]` container attribute.
+This can only be used in combination with the `#[struc(from_structural)]`,
+`#[struc(builder)]`,or `#[struc(from_str_map)]` container attributes.
 
 Initialize the field in the `FromStructural` impl  with the `<expression>` expression.
 
@@ -304,7 +599,8 @@ Examples:<br>
 <span id="init_with_lit"></span>
 ### `#[struc(init_with_lit = <literal>)]`
 
-This can only be used in combination with the `#[struc(from_structural)]` container attribute.
+This can only be used in combination with the `#[struc(from_structural)]`,
+`#[struc(builder)]`,or `#[struc(from_str_map)]` container attributes.
 
 Initialize the field in the `FromStructural` impl  with the `<literal>` literal.
 Note that only literals parseable as `syn::Lit` can be used here,
@@ -323,7 +619,8 @@ Examples:<br>
 
 ### `#[struc(init_with_default)]`
 
-This can only be used in combination with the `#[struc(from_structural)]` container attribute.
+This can only be used in combination with the `#[struc(from_structural)]`,
+`#[struc(builder)]`,or `#[struc(from_str_map)]` container attributes.
 
 Initialize the field with its default value, requires the field type to implement `Default`.
 
@@ -333,6 +630,78 @@ with private fields.
 Public fields with `init_*` attributes aren't added as bounds for the converted-from type,
 which means that removeing the `init_*` attributes is a breaking change.
 
+### `#[struc(validate = "path::to::function")]`
+
+This is only usable on structs,and requires the "alloc" feature.
+
+Validates the field with `function` in the derived [`Validate`] impl,
+reporting errors with the (possibly renamed) name of the field.
+
+`function` must have a `fn(&FieldType) -> Result<(), E>` signature,
+where `E` is any type that converts into a `Cow<'static, str>`
+(eg: `&'static str`,`String`).
+
+Every field with accessor impls gets a [`ValidateField`] impl
+(which does nothing for fields without this attribute),
+so that a subset of the fields can be validated with [`Validate::validate_fields`].
+
+[Here is an example using this attribute](../../validate/index.html#example)
+
+### `#[struc(pin)]`
+
+This is only usable on structs.
+
+Marks the field as structurally pinned,
+so that [`StructuralExt::pin_field_mut`] and [`StructuralExt::pin_fields_mut`]
+return a `Pin<&mut FieldType>` for it from a `Pin<&mut Self>`,
+returning a `&mut FieldType` for the fields without this attribute.
+
+Using this attribute on any field causes these impls to be generated:
+
+- [`PinField`] impls for every field with mutable accessor impls.
+  These are also supertraits of the `<deriving_type>_SI` trait,
+  so that the `pin_field*` methods can be called on a `Pin<&mut impl Foo_SI>`.
+
+- An `Unpin` impl that only requires the types of the pinned fields to be `Unpin`.
+  This means that you can't implement `Unpin` for the type yourself.
+
+- An impl that prevents you from implementing `Drop` for the type,
+  since a `Drop` impl could move the pinned fields.
+
+This example demonstrates that implementing `Drop` for a type with pinned fields
+causes a compile-time error:
+
+```compile_fail
+use structural::Structural;
+
+#[derive(Structural)]
+#[struc(public)]
+struct Foo<F> {
+    #[struc(pin)]
+    future: F,
+}
+
+impl<F> Drop for Foo<F> {
+    fn drop(&mut self) {}
+}
+```
+
+[Here is an example using this attribute](../../field/pin_projection/index.html#example)
+
+### `#[struc(str_map_nested)]`
+
+This can only be used in combination with the `#[struc(from_str_map)]` container attribute,
+on public fields.
+
+Loads the field like [`str_map::from_str_map`] loads its type,
+using the name of the field followed by two underscores as the prefix of its keys,
+eg: the `host` field of the `db` field is loaded from the `db__host` key.
+
+If the field also has an `init_*` attribute,
+it's initialized with it when any key of the nested value is missing.
+
+[`str_map::from_str_map`]: ../../str_map/fn.from_str_map.html
+
 # Container/Variant/Field Attributes
 
 Unless stated otherwise,
@@ -704,22 +1073,63 @@ where
 
 ```
 
-### Impl trait fields
+### Associated types in the trait alias
 
-This is an example of using the `#[struc(impl="<trait_bounds>")]` attribute
+This is an example of using the `#[struc(assoc_types)]` attribute
 
-This requires the `nightly_impl_fields` cargo feature
-(or `impl_fields` if associated type bounds stabilized after the latest release).
+```rust
+use structural::{Structural,fp,make_struct,StructuralExt};
 
-*/
-#![cfg_attr(not(feature="nightly_impl_fields"),doc="```ignore")]
-#![cfg_attr(feature="nightly_impl_fields",doc="```rust")]
-/*!
+use std::ops::Add;
+
+#[derive(Structural)]
+#[struc(public,assoc_types)]
+struct Point3D<T>{
+    x:T,
+    y:T,
+    z:T,
+}
 
-// Remove this if associated type bounds (eg: `T: Iterator<Item: Debug>`)
-// work without it.
-#![feature(associated_type_bounds)]
+// `P::T` is the type of the `x`,`y`,and `z` fields.
+fn sum<P>(this:&P)->P::T
+where
+    P: Point3D_SI,
+    P::T: Copy+Add<Output=P::T>,
+{
+    let (x,y,z)=this.fields(fp!(x,y,z));
+    *x + *y + *z
+}
+
+assert_eq!( sum(&Point3D{x:3,y:5,z:8}), 16 );
+assert_eq!( sum(&make_struct!{x:0.5,y:1.0,z:2.0}), 3.5 );
+
+```
+
+The `Point3D_SI` trait can't be used as a trait object,
+because the types of the `x`,`y`,and `z` fields would have to be specified
+separately from the `T` associated type.
+
+```compile_fail
+use structural::{Structural,fp,StructuralExt};
 
+#[derive(Structural)]
+#[struc(public,assoc_types)]
+struct Point3D<T>{
+    x:T,
+    y:T,
+    z:T,
+}
+
+let point:&dyn Point3D_SI<T = u32>=&Point3D{x:3,y:5,z:8};
+assert_eq!( point.fields(fp!(x,y,z)), (&3,&5,&8) );
+
+```
+
+### Impl trait fields
+
+This is an example of using the `#[struc(impl="<trait_bounds>")]` attribute
+
+```rust
 use std::borrow::Borrow;
 
 use structural::{Structural,fp,make_struct,StructuralExt};
@@ -1067,6 +1477,14 @@ impl Drop for WithDropLogic<'_>{
 
 [`FromStructural`]: ../../convert/trait.FromStructural.html
 [`TryFromStructural`]: ../../convert/trait.TryFromStructural.html
+[`Validate`]: ../../validate/trait.Validate.html
+[`Validate::validate`]: ../../validate/trait.Validate.html#tymethod.validate
+[`Validate::validate_fields`]: ../../validate/trait.Validate.html#method.validate_fields
+[`ValidateField`]: ../../validate/trait.ValidateField.html
+[`ValidationErrors`]: ../../validate/struct.ValidationErrors.html
+[`PinField`]: ../../field/pin_projection/trait.PinField.html
+[`StructuralExt::pin_field_mut`]: ../../trait.StructuralExt.html#method.pin_field_mut
+[`StructuralExt::pin_fields_mut`]: ../../trait.StructuralExt.html#method.pin_fields_mut
 
 
 */
@@ -0,0 +1,911 @@
+/*!
+A compact positional binary encoding for the fields that a path set refers to.
+
+The [`encode`] function writes the fields that a path set refers to,
+in the order that they're listed in the path,
+and the [`decode`] function reads them back,
+converting them into any type that implements `FromStructural`.
+Two types with the same fields can share the same wire format,
+without converting to a common concrete type.
+
+The fields are written without names or lengths,
+so the encoded fields must be decoded with a path set with the same field types,
+in the same order.
+
+Fields are encoded with the [`Encode`] trait and decoded with the [`Decode`] trait,
+which are implemented for primitive types,`Option`,and arrays of up to 32 elements.
+Types with fields (eg: a `Structural` struct) can implement them
+with the [`impl_encoding`] macro,so that they can be nested fields.
+
+Integers and floats are encoded in little-endian,
+`bool` as one byte,and `char` as a `u32`.
+`Option`s are encoded as a `0` byte for `None`,
+and as a `1` byte followed by the value for `Some`.
+
+Only paths to fields of the encoded/decoded values (not nested fields) are supported,
+and values can be decoded from up to 12 fields,the most fields that a [`Record`] can have.
+
+This module does not require the "alloc" feature,
+except for the [`encode_to_vec`] function and the [`WriteBytes`] impl for `Vec<u8>`.
+It is not available when the `use_const_str` feature is enabled.
+
+# Example
+
+```rust
+use structural::{
+    encoding::{self, SliceWriter},
+    Structural, fp, impl_encoding,
+};
+
+// The firmware of the sensor
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+struct SensorReading {
+    sensor_id: u16,
+    #[struc(rename = "celsius")]
+    temperature: f32,
+    position: Position,
+    #[struc(init_with_lit = 0)]
+    samples_taken: u32,
+}
+
+#[derive(Structural, Debug, PartialEq, Copy, Clone)]
+#[struc(public, from_structural)]
+struct Position {
+    x: i16,
+    y: i16,
+}
+
+// Encoding `Position` as a nested field,with its `x` and `y` fields.
+impl_encoding!{
+    impl[] Position { fields = fp!(x, y) }
+}
+
+// The firmware of the controller,which only shares the protocol fields with the sensor
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+struct Measurement {
+    celsius: f32,
+    sensor_id: u16,
+    position: Position,
+}
+
+let reading = SensorReading {
+    sensor_id: 7,
+    temperature: 21.5,
+    position: Position{ x: -3, y: 5 },
+    samples_taken: 100,
+};
+
+let mut buffer = [0u8; 32];
+let mut writer = SliceWriter::new(&mut buffer);
+encoding::encode(&reading, fp!(sensor_id, celsius, position), &mut writer).unwrap();
+
+assert_eq!(
+    writer.written(),
+    &[7, 0, 0, 0, 172, 65, 253, 255, 5, 0][..],
+);
+assert_eq!( encoding::encoded_len(&reading, fp!(sensor_id, celsius, position)), 10 );
+
+let mut bytes = writer.written();
+let measurement: Measurement =
+    encoding::decode(&mut bytes, fp!(sensor_id, celsius, position)).unwrap();
+
+assert!(bytes.is_empty());
+assert_eq!(
+    measurement,
+    Measurement{ celsius: 21.5, sensor_id: 7, position: Position{ x: -3, y: 5 } },
+);
+
+```
+
+[`encode`]: ./fn.encode.html
+[`decode`]: ./fn.decode.html
+[`encode_to_vec`]: ./fn.encode_to_vec.html
+[`Encode`]: ./trait.Encode.html
+[`Decode`]: ./trait.Decode.html
+[`WriteBytes`]: ./trait.WriteBytes.html
+[`impl_encoding`]: ../macro.impl_encoding.html
+[`Record`]: ../record/struct.Record.html
+
+*/
+
+use crate::{
+    convert::FromStructural,
+    field::GetField,
+    path::{FieldPathSet, TStr, UniquePaths},
+    record::{
+        BuildRecordFields, BuiltRecord, ForEachName, MakeField, NameOp, PathNames, PathNamesOut,
+        Record,
+    },
+};
+
+use std_::fmt::{self, Display};
+
+#[cfg(feature = "alloc")]
+use crate::alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::error::Error as StdError;
+
+/// Writes the fields that `path` refers to into `writer`,
+/// in the order that they're listed in the path.
+///
+/// `path` can be a single field name (eg:`fp!(foo)`),
+/// or a set of field names (eg:`fp!(foo, bar, baz)`).
+///
+/// This checks that all the fields fit in `writer` with [`WriteBytes::reserve_bytes`]
+/// before writing them,
+/// so that nothing is written if they don't fit.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{encoding::{self, EncodeError, SliceWriter}, fp, make_struct};
+///
+/// let this = make_struct!{ id: 0x0102_u16, flag: true, ignored: "hi" };
+///
+/// let mut buffer = [0u8; 3];
+/// let mut writer = SliceWriter::new(&mut buffer);
+/// encoding::encode(&this, fp!(flag, id), &mut writer).unwrap();
+/// assert_eq!( writer.written(), &[1, 2, 1][..] );
+///
+/// assert_eq!(
+///     encoding::encode(&this, fp!(id), &mut writer),
+///     Err(EncodeError::BufferFull{ needed: 2, remaining: 0 }),
+/// );
+///
+/// let mut buffer = [0u8; 2];
+/// let mut writer = SliceWriter::new(&mut buffer);
+/// assert_eq!(
+///     encoding::encode(&this, fp!(flag, id), &mut writer),
+///     Err(EncodeError::BufferFull{ needed: 3, remaining: 2 }),
+/// );
+/// assert!( writer.is_empty() );
+///
+/// ```
+///
+/// [`WriteBytes::reserve_bytes`]: ./trait.WriteBytes.html#method.reserve_bytes
+#[inline]
+pub fn encode<T, P, W>(this: &T, path: P, writer: &mut W) -> Result<(), EncodeError>
+where
+    T: ?Sized,
+    P: EncodeFields<T>,
+    W: ?Sized + WriteBytes,
+{
+    path.encode_fields(this, writer)
+}
+
+/// The amount of bytes that [`encode`] writes for the fields that `path` refers to.
+///
+/// `path` can be a single field name (eg:`fp!(foo)`),
+/// or a set of field names (eg:`fp!(foo, bar, baz)`).
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{encoding, fp};
+///
+/// assert_eq!( encoding::encoded_len(&(0u8, 0u32, Some(0u16)), fp!(1, 2)), 7 );
+/// assert_eq!( encoding::encoded_len(&(0u8, 0u32, None::<u16>), fp!(1, 2)), 5 );
+///
+/// ```
+///
+/// [`encode`]: ./fn.encode.html
+#[inline]
+pub fn encoded_len<T, P>(this: &T, path: P) -> usize
+where
+    T: ?Sized,
+    P: EncodeFields<T>,
+{
+    path.fields_encoded_len(this)
+}
+
+/// Writes the fields that `path` refers to into the start of `buffer`,
+/// returning the amount of bytes written.
+///
+/// `path` can be a single field name (eg:`fp!(foo)`),
+/// or a set of field names (eg:`fp!(foo, bar, baz)`).
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{encoding, fp};
+///
+/// let mut buffer = [0u8; 8];
+///
+/// let len = encoding::encode_to_slice(&(3u8, 'a', -2i16), fp!(0, 2), &mut buffer).unwrap();
+/// assert_eq!( &buffer[..len], &[3, 254, 255][..] );
+///
+/// ```
+#[inline]
+pub fn encode_to_slice<T, P>(this: &T, path: P, buffer: &mut [u8]) -> Result<usize, EncodeError>
+where
+    T: ?Sized,
+    P: EncodeFields<T>,
+{
+    let mut writer = SliceWriter::new(buffer);
+    path.encode_fields(this, &mut writer)?;
+    Ok(writer.len())
+}
+
+/// Writes the fields that `path` refers to into a `Vec<u8>`.
+///
+/// `path` can be a single field name (eg:`fp!(foo)`),
+/// or a set of field names (eg:`fp!(foo, bar, baz)`).
+///
+/// This requires the "alloc" feature.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{encoding, fp, make_struct};
+///
+/// let this = make_struct!{ a: [1u8, 2, 3], b: 0xAABB_u16 };
+///
+/// assert_eq!( encoding::encode_to_vec(&this, fp!(b, a)), vec![0xBB, 0xAA, 1, 2, 3] );
+///
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_to_vec<T, P>(this: &T, path: P) -> Vec<u8>
+where
+    T: ?Sized,
+    P: EncodeFields<T> + Copy,
+{
+    let mut vec = Vec::with_capacity(path.fields_encoded_len(this));
+    // Writing to a `Vec<u8>` never fails.
+    let _ = path.encode_fields(this, &mut vec);
+    vec
+}
+
+/// Reads the fields that `path` refers to from the start of `bytes`,
+/// in the order that they're listed in the path,
+/// converting them into a `T` with `FromStructural`.
+///
+/// This advances `bytes` past the decoded fields,
+/// leaving it unchanged if any field can't be decoded.
+///
+/// `path` can be a single field name (eg:`fp!(foo)`),
+/// or a set of up to 12 field names (eg:`fp!(foo, bar, baz)`).
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{
+///     encoding::{self, DecodeError},
+///     Structural, fp,
+/// };
+///
+/// #[derive(Structural, Debug, PartialEq)]
+/// #[struc(public, from_structural)]
+/// struct Packet {
+///     kind: u8,
+///     urgent: bool,
+/// }
+///
+/// let mut bytes = &[3, 1, 8, 2][..];
+///
+/// assert_eq!( encoding::decode(&mut bytes, fp!(kind, urgent)), Ok(Packet{ kind: 3, urgent: true }) );
+/// assert_eq!( bytes, &[8, 2][..] );
+///
+/// assert_eq!(
+///     encoding::decode::<Packet, _>(&mut bytes, fp!(kind, urgent)),
+///     Err(DecodeError::InvalidBool(2)),
+/// );
+/// // The bytes are only advanced when the value is decoded
+/// assert_eq!( bytes, &[8, 2][..] );
+///
+/// let mut bytes = &[5][..];
+/// assert_eq!(
+///     encoding::decode::<Packet, _>(&mut bytes, fp!(kind, urgent)),
+///     Err(DecodeError::UnexpectedEnd{ needed: 1, remaining: 0 }),
+/// );
+///
+/// ```
+#[inline]
+pub fn decode<T, P>(bytes: &mut &[u8], path: P) -> Result<T, DecodeError>
+where
+    P: DecodeFields<T>,
+{
+    path.decode_fields(bytes)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A value that can be written with the compact positional binary encoding of this module.
+///
+/// This is implemented for primitive types,`Option`,and arrays of up to 32 elements.
+///
+/// Types with fields can implement this with the [`impl_encoding`] macro.
+///
+/// [`impl_encoding`]: ../macro.impl_encoding.html
+pub trait Encode {
+    /// The amount of bytes that `encode` writes.
+    fn encoded_len(&self) -> usize;
+
+    /// Writes this value into `writer`.
+    fn encode<W>(&self, writer: &mut W) -> Result<(), EncodeError>
+    where
+        W: ?Sized + WriteBytes;
+}
+
+/// A value that can be read with the compact positional binary encoding of this module.
+///
+/// This is implemented for primitive types,`Option`,and arrays of up to 32 elements.
+///
+/// Types with fields can implement this with the [`impl_encoding`] macro.
+///
+/// [`impl_encoding`]: ../macro.impl_encoding.html
+pub trait Decode: Sized {
+    /// Reads a value from the start of `bytes`,advancing it past the read bytes.
+    fn decode(bytes: &mut &[u8]) -> Result<Self, DecodeError>;
+}
+
+/// A destination for encoded bytes.
+pub trait WriteBytes {
+    /// Writes all of `bytes`,
+    /// returning an error if they don't fit.
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EncodeError>;
+
+    /// Checks that `len` bytes can be written,
+    /// returning an error if they don't fit.
+    ///
+    /// This is called before writing the fields of a value,
+    /// so that writers that can run out of space don't write only some of the fields.
+    /// The default implementation always returns `Ok(())`.
+    #[inline]
+    fn reserve_bytes(&mut self, len: usize) -> Result<(), EncodeError> {
+        let _ = len;
+        Ok(())
+    }
+}
+
+/// Writes the fields that `Self` refers to in `T`.
+///
+/// This is implemented for `TStr`,and for `FieldPathSet`s of `TStr`s.
+pub trait EncodeFields<T: ?Sized> {
+    /// The amount of bytes that `encode_fields` writes.
+    fn fields_encoded_len(self, this: &T) -> usize;
+
+    /// Writes the fields that `self` refers to in `this` into `writer`,
+    /// checking that they fit with `WriteBytes::reserve_bytes` before writing them.
+    fn encode_fields<W>(self, this: &T, writer: &mut W) -> Result<(), EncodeError>
+    where
+        W: ?Sized + WriteBytes;
+}
+
+/// Reads the fields that `Self` refers to,converting them into a `T`.
+///
+/// This is implemented for `TStr`,and for `FieldPathSet`s of up to 12 `TStr`s.
+pub trait DecodeFields<T> {
+    /// Reads the fields that `self` refers to from the start of `bytes`,
+    /// converting them into a `T` with `FromStructural`.
+    ///
+    /// This only advances `bytes` if all the fields are decoded.
+    fn decode_fields(self, bytes: &mut &[u8]) -> Result<T, DecodeError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A [`WriteBytes`] that writes into a byte slice,
+/// for encoding without the "alloc" feature.
+///
+/// For an example,look at the [module-level documentation](./index.html).
+///
+/// [`WriteBytes`]: ./trait.WriteBytes.html
+#[derive(Debug)]
+pub struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Constructs a `SliceWriter` that writes from the start of `buffer`.
+    #[inline]
+    pub fn new(buffer: &'a mut [u8]) -> Self {
+        Self { buffer, len: 0 }
+    }
+
+    /// The amount of bytes written.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no bytes were written.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The amount of bytes that can still be written.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.len
+    }
+
+    /// Gets the written bytes.
+    #[inline]
+    pub fn written(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+
+    /// Unwraps this into the written bytes.
+    #[inline]
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.buffer[..self.len]
+    }
+}
+
+impl<'a> WriteBytes for SliceWriter<'a> {
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EncodeError> {
+        self.reserve_bytes(bytes.len())?;
+        let end = self.len + bytes.len();
+        self.buffer[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+
+    #[inline]
+    fn reserve_bytes(&mut self, len: usize) -> Result<(), EncodeError> {
+        let remaining = self.remaining();
+        if len > remaining {
+            return Err(EncodeError::BufferFull {
+                needed: len,
+                remaining,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl WriteBytes for Vec<u8> {
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EncodeError> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    #[inline]
+    fn reserve_bytes(&mut self, len: usize) -> Result<(), EncodeError> {
+        self.reserve(len);
+        Ok(())
+    }
+}
+
+impl<W> WriteBytes for &mut W
+where
+    W: ?Sized + WriteBytes,
+{
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EncodeError> {
+        (**self).write_bytes(bytes)
+    }
+
+    #[inline]
+    fn reserve_bytes(&mut self, len: usize) -> Result<(), EncodeError> {
+        (**self).reserve_bytes(len)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The error returned when the encoded bytes can't be written.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EncodeError {
+    /// The buffer is too small to write the bytes.
+    BufferFull {
+        /// The amount of bytes that were being written.
+        needed: usize,
+        /// The amount of bytes that could still be written into the buffer.
+        remaining: usize,
+    },
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::BufferFull { needed, remaining } => write!(
+                f,
+                "could not write {} bytes into a buffer with {} bytes remaining",
+                needed, remaining,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for EncodeError {}
+
+/// The error returned when the encoded bytes can't be read.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DecodeError {
+    /// The bytes ended before the value could be read.
+    UnexpectedEnd {
+        /// The amount of bytes that were being read.
+        needed: usize,
+        /// The amount of bytes left.
+        remaining: usize,
+    },
+    /// A `bool` was encoded as something other than `0` or `1`.
+    InvalidBool(u8),
+    /// A `char` was encoded as a `u32` that isn't a valid `char`.
+    InvalidChar(u32),
+    /// An `Option` was encoded with a tag other than `0` or `1`.
+    InvalidOptionTag(u8),
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd { needed, remaining } => write!(
+                f,
+                "could not read {} bytes with {} bytes remaining",
+                needed, remaining,
+            ),
+            DecodeError::InvalidBool(x) => write!(f, "{} is not a valid bool", x),
+            DecodeError::InvalidChar(x) => write!(f, "{:#x} is not a valid char", x),
+            DecodeError::InvalidOptionTag(x) => write!(f, "{} is not a valid Option tag", x),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for DecodeError {}
+
+/// Reads the first `N` bytes of `bytes` into `buffer`,advancing `bytes` past them.
+#[inline]
+fn read_bytes(bytes: &mut &[u8], buffer: &mut [u8]) -> Result<(), DecodeError> {
+    if bytes.len() < buffer.len() {
+        return Err(DecodeError::UnexpectedEnd {
+            needed: buffer.len(),
+            remaining: bytes.len(),
+        });
+    }
+    let (read, rem) = bytes.split_at(buffer.len());
+    buffer.copy_from_slice(read);
+    *bytes = rem;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_encoding_for_numbers {
+    ( $( ($ty:ty, $len:expr, $to_bytes:expr, $from_bytes:expr) )* ) => {
+        $(
+            impl Encode for $ty {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    $len
+                }
+
+                #[inline]
+                fn encode<W>(&self, writer: &mut W) -> Result<(), EncodeError>
+                where
+                    W: ?Sized + WriteBytes,
+                {
+                    writer.write_bytes(&$to_bytes(*self))
+                }
+            }
+
+            impl Decode for $ty {
+                #[inline]
+                fn decode(bytes: &mut &[u8]) -> Result<Self, DecodeError> {
+                    let mut buffer = [0u8; $len];
+                    read_bytes(bytes, &mut buffer)?;
+                    Ok($from_bytes(buffer))
+                }
+            }
+        )*
+    };
+}
+
+impl_encoding_for_numbers! {
+    (u8, 1, u8::to_le_bytes, u8::from_le_bytes)
+    (u16, 2, u16::to_le_bytes, u16::from_le_bytes)
+    (u32, 4, u32::to_le_bytes, u32::from_le_bytes)
+    (u64, 8, u64::to_le_bytes, u64::from_le_bytes)
+    (u128, 16, u128::to_le_bytes, u128::from_le_bytes)
+    (i8, 1, i8::to_le_bytes, i8::from_le_bytes)
+    (i16, 2, i16::to_le_bytes, i16::from_le_bytes)
+    (i32, 4, i32::to_le_bytes, i32::from_le_bytes)
+    (i64, 8, i64::to_le_bytes, i64::from_le_bytes)
+    (i128, 16, i128::to_le_bytes, i128::from_le_bytes)
+    (f32, 4, |x: f32| x.to_bits().to_le_bytes(), |x| f32::from_bits(u32::from_le_bytes(x)))
+    (f64, 8, |x: f64| x.to_bits().to_le_bytes(), |x| f64::from_bits(u64::from_le_bytes(x)))
+}
+
+impl Encode for bool {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        1
+    }
+
+    #[inline]
+    fn encode<W>(&self, writer: &mut W) -> Result<(), EncodeError>
+    where
+        W: ?Sized + WriteBytes,
+    {
+        (*self as u8).encode(writer)
+    }
+}
+
+impl Decode for bool {
+    #[inline]
+    fn decode(bytes: &mut &[u8]) -> Result<Self, DecodeError> {
+        match u8::decode(bytes)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            x => Err(DecodeError::InvalidBool(x)),
+        }
+    }
+}
+
+impl Encode for char {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        4
+    }
+
+    #[inline]
+    fn encode<W>(&self, writer: &mut W) -> Result<(), EncodeError>
+    where
+        W: ?Sized + WriteBytes,
+    {
+        (*self as u32).encode(writer)
+    }
+}
+
+impl Decode for char {
+    #[inline]
+    fn decode(bytes: &mut &[u8]) -> Result<Self, DecodeError> {
+        let x = u32::decode(bytes)?;
+        std_::char::from_u32(x).ok_or(DecodeError::InvalidChar(x))
+    }
+}
+
+impl Encode for () {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        0
+    }
+
+    #[inline]
+    fn encode<W>(&self, _: &mut W) -> Result<(), EncodeError>
+    where
+        W: ?Sized + WriteBytes,
+    {
+        Ok(())
+    }
+}
+
+impl Decode for () {
+    #[inline]
+    fn decode(_: &mut &[u8]) -> Result<Self, DecodeError> {
+        Ok(())
+    }
+}
+
+impl<T: Encode> Encode for Option<T> {
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        1 + self.as_ref().map_or(0, T::encoded_len)
+    }
+
+    fn encode<W>(&self, writer: &mut W) -> Result<(), EncodeError>
+    where
+        W: ?Sized + WriteBytes,
+    {
+        match self {
+            Some(x) => {
+                1u8.encode(writer)?;
+                x.encode(writer)
+            }
+            None => 0u8.encode(writer),
+        }
+    }
+}
+
+impl<T: Decode> Decode for Option<T> {
+    fn decode(bytes: &mut &[u8]) -> Result<Self, DecodeError> {
+        match u8::decode(bytes)? {
+            0 => Ok(None),
+            1 => T::decode(bytes).map(Some),
+            x => Err(DecodeError::InvalidOptionTag(x)),
+        }
+    }
+}
+
+impl<T> Encode for &T
+where
+    T: ?Sized + Encode,
+{
+    #[inline]
+    fn encoded_len(&self) -> usize {
+        (**self).encoded_len()
+    }
+
+    #[inline]
+    fn encode<W>(&self, writer: &mut W) -> Result<(), EncodeError>
+    where
+        W: ?Sized + WriteBytes,
+    {
+        (**self).encode(writer)
+    }
+}
+
+macro_rules! impl_encoding_for_arrays {
+    (@impl $len:literal, [$($elem:ident)*]) => {
+        impl<T: Encode> Encode for [T; $len] {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                self.iter().map(T::encoded_len).sum()
+            }
+
+            fn encode<W>(&self, writer: &mut W) -> Result<(), EncodeError>
+            where
+                W: ?Sized + WriteBytes,
+            {
+                self.iter().try_for_each(|x| x.encode(writer))
+            }
+        }
+
+        impl<T: Decode> Decode for [T; $len] {
+            #[allow(unused_variables)]
+            fn decode(bytes: &mut &[u8]) -> Result<Self, DecodeError> {
+                Ok([ $( { let $elem = T::decode(bytes)?; $elem }, )* ])
+            }
+        }
+    };
+    ( [$($prev:ident)*] ($len:literal, $elem:ident) $($rem:tt)* ) => {
+        impl_encoding_for_arrays!{ @impl $len, [$($prev)* $elem] }
+        impl_encoding_for_arrays!{ [$($prev)* $elem] $($rem)* }
+    };
+    ( [$($prev:ident)*] ) => {};
+}
+
+impl_encoding_for_arrays! { @impl 0, [] }
+impl_encoding_for_arrays! {
+    []
+    (1, e0) (2, e1) (3, e2) (4, e3) (5, e4) (6, e5) (7, e6) (8, e7)
+    (9, e8) (10, e9) (11, e10) (12, e11) (13, e12) (14, e13) (15, e14) (16, e15)
+    (17, e16) (18, e17) (19, e18) (20, e19) (21, e20) (22, e21) (23, e22) (24, e23)
+    (25, e24) (26, e25) (27, e26) (28, e27) (29, e28) (30, e29) (31, e30) (32, e31)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T, S> EncodeFields<T> for TStr<S>
+where
+    T: ?Sized,
+    FieldPathSet<(Self,), UniquePaths>: EncodeFields<T>,
+{
+    #[inline(always)]
+    fn fields_encoded_len(self, this: &T) -> usize {
+        self.into_set().fields_encoded_len(this)
+    }
+
+    #[inline(always)]
+    fn encode_fields<W>(self, this: &T, writer: &mut W) -> Result<(), EncodeError>
+    where
+        W: ?Sized + WriteBytes,
+    {
+        self.into_set().encode_fields(this, writer)
+    }
+}
+
+impl<T, S> DecodeFields<T> for TStr<S>
+where
+    FieldPathSet<(Self,), UniquePaths>: DecodeFields<T>,
+{
+    #[inline(always)]
+    fn decode_fields(self, bytes: &mut &[u8]) -> Result<T, DecodeError> {
+        self.into_set().decode_fields(bytes)
+    }
+}
+
+impl<T, U, P> EncodeFields<T> for FieldPathSet<P, U>
+where
+    T: ?Sized,
+    Self: PathNames,
+    for<'a> PathNamesOut<Self>: ForEachName<EncodedLenOp<'a, T>>,
+    for<'a> PathNamesOut<Self>: ForEachName<EncodeOp<'a, T>>,
+{
+    #[inline]
+    fn fields_encoded_len(self, this: &T) -> usize {
+        let mut op = EncodedLenOp { this, len: 0 };
+        PathNamesOut::<Self>::for_each_name(&mut op);
+        op.len
+    }
+
+    #[inline]
+    fn encode_fields<W>(self, this: &T, mut writer: &mut W) -> Result<(), EncodeError>
+    where
+        W: ?Sized + WriteBytes,
+    {
+        writer.reserve_bytes(self.fields_encoded_len(this))?;
+        let mut op = EncodeOp {
+            this,
+            writer: &mut writer,
+            result: Ok(()),
+        };
+        PathNamesOut::<Self>::for_each_name(&mut op);
+        op.result
+    }
+}
+
+impl<T, U, P> DecodeFields<T> for FieldPathSet<P, U>
+where
+    Self: PathNames,
+    for<'a, 'b> PathNamesOut<Self>: BuildRecordFields<T, (), DecodeOp<'a, 'b>, DecodeError>,
+    for<'a, 'b> T: FromStructural<BuiltRecord<T, Self, DecodeOp<'a, 'b>, DecodeError>>,
+{
+    #[inline]
+    fn decode_fields(self, bytes: &mut &[u8]) -> Result<T, DecodeError> {
+        // Decoding from a copy of the slice,so that `bytes` is unchanged on errors.
+        let mut remaining = *bytes;
+        let fields = PathNamesOut::<Self>::build_record_fields((), &mut DecodeOp(&mut remaining))?;
+        let this = T::from_structural(Record(fields));
+        *bytes = remaining;
+        Ok(this)
+    }
+}
+
+/// Adds up the encoded length of each field in a path set,for the `EncodeFields` impls.
+#[doc(hidden)]
+pub struct EncodedLenOp<'a, T: ?Sized> {
+    this: &'a T,
+    len: usize,
+}
+
+impl<'a, T, N> NameOp<N> for EncodedLenOp<'a, T>
+where
+    T: ?Sized + GetField<N>,
+    T::Ty: Encode,
+{
+    #[inline]
+    fn name_op(&mut self, name: N) {
+        self.len += self.this.get_field_(name).encoded_len();
+    }
+}
+
+/// Encodes each field in a path set,for the `EncodeFields` impls.
+///
+/// The fields after the first one that errors are not written.
+#[doc(hidden)]
+pub struct EncodeOp<'a, T: ?Sized> {
+    this: &'a T,
+    writer: &'a mut dyn WriteBytes,
+    result: Result<(), EncodeError>,
+}
+
+impl<'a, T, N> NameOp<N> for EncodeOp<'a, T>
+where
+    T: ?Sized + GetField<N>,
+    T::Ty: Encode,
+{
+    #[inline]
+    fn name_op(&mut self, name: N) {
+        if self.result.is_ok() {
+            self.result = self.this.get_field_(name).encode(self.writer);
+        }
+    }
+}
+
+/// Decodes each field in a path set,for the `DecodeFields` impls.
+#[doc(hidden)]
+pub struct DecodeOp<'a, 'b>(&'a mut &'b [u8]);
+
+impl<'a, 'b, N, Ty> MakeField<N, Ty> for DecodeOp<'a, 'b>
+where
+    Ty: Decode,
+{
+    type Error = DecodeError;
+
+    #[inline]
+    fn make_field(&mut self, _: N) -> Result<Ty, DecodeError> {
+        Ty::decode(self.0)
+    }
+}
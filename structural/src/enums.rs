@@ -79,6 +79,14 @@ use crate::path::AssertTStrParam;
 ///
 ///
 /// ```
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "type `{Self}` is missing a variant",
+        label = "`{V}` encodes the name of the missing variant",
+        note = "names are spelled out with one type per character, eg: `TStr<__TS<(__f, __o, __o)>>` is the `foo` name",
+    )
+)]
 pub unsafe trait IsVariant<V>: AssertTStrParam<V> {
     /// Checks whether this enum is the variant that `V` stands for.
     fn is_variant_(&self, variant: V) -> bool;
@@ -142,6 +142,13 @@ use std_::marker::PhantomData;
 ///
 ///
 /// ```
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "type `{Self}` does not declare its amount of variants",
+        note = "`VariantCount` is implemented by enums deriving `Structural` that aren't `#[non_exhaustive]`",
+    )
+)]
 pub unsafe trait VariantCount {
     /// This is a [TStr](../struct.TStr.html) (eg:`TS!(3)`)
     /// representing the amount of variants of the enum.
@@ -119,6 +119,21 @@ transforms the `Result<T,_>`s from inside a type returned by `Rev` traits into e
 The type that `Foo` is converted into when calling
 `Foo::normalize_fields( foo )`.
 
+### Pin projection
+
+The [pin_projection](./pin_projection/index.html)
+module contains the [PinField](./pin_projection/trait.PinField.html) trait,
+which declares whether a field is structurally pinned,
+used to access fields through a `Pin<&mut Self>`.
+
+### Field offsets
+
+The [field_offset](./field_offset/index.html)
+module contains the [FieldOffset](./field_offset/trait.FieldOffset.html) trait,
+with the byte offset of (possibly nested) fields in `#[repr(C)]` structs,
+and the [field_ptr](./field_offset/fn.field_ptr.html) function
+to get a pointer to a field from a pointer to the struct.
+
 
 */
 
@@ -131,10 +146,12 @@ use std_::ptr::NonNull;
 mod array_impls;
 mod enum_impls;
 pub mod errors;
+pub mod field_offset;
 mod most_impls;
 pub mod multi_fields;
 mod normalize_fields;
 pub mod ownership;
+pub mod pin_projection;
 pub mod rev_get_field;
 pub(crate) mod tuple_impls;
 
@@ -146,6 +163,7 @@ pub use self::{
     errors::{
         CombinedErrs, CombinedErrsOut, FailedAccess, InfallibleAccess, IntoFieldErr, IsFieldErr,
     },
+    field_offset::{field_ptr, field_ptr_mut, FieldOffset},
     multi_fields::{
         RevGetMultiField, RevGetMultiFieldImpl, RevGetMultiFieldMut, RevGetMultiFieldMutImpl,
         RevGetMultiFieldMutOut, RevGetMultiFieldMutRaw, RevGetMultiFieldOut, RevIntoMultiField,
@@ -153,6 +171,7 @@ pub use self::{
     },
     normalize_fields::{NormalizeFields, NormalizeFieldsOut},
     ownership::{DropFields, FieldBit, MovedOutFields, PrePostDropFields},
+    pin_projection::{PinField, PinFieldOut, RevPinFieldsMut},
     rev_get_field::{
         OptRevGetField, OptRevGetFieldMut, OptRevIntoField, OptRevIntoFieldMut, OptRevIntoFieldRef,
         RevFieldErr, RevFieldErrOut, RevFieldType, RevGetField, RevGetFieldImpl, RevGetFieldMut,
@@ -167,11 +186,106 @@ pub use self::{
 ///
 /// Structs generally implement this with a `TStr` parameter,
 /// while enums implement this with a `VariantField` parameter
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "type `{Self}` is missing a field",
+        label = "`{FieldPath}` encodes the path to the missing field",
+        note = "names are spelled out with one type per character, eg: `TStr<__TS<(__f, __o, __o)>>` is the `foo` name",
+    )
+)]
 pub trait FieldType<FieldPath> {
     /// The type of the `FieldPath` field.
     type Ty;
 }
 
+/// Gets the index of the `FieldName` field in `Self`,
+/// which is different for each field of the type.
+///
+/// This is implemented for struct fields by the `Structural` derive,
+/// tuples,arrays,`Record`s,and the types created with the `make_struct` macro.
+///
+/// For enums,this is implemented for the `VariantField<V, F>` paths to variant fields,
+/// where the index is only different for each field of the same variant.
+///
+/// This is used by [`Tracked`] to store its dirty flags in a bitset.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{field::FieldIndex, Structural, FP};
+///
+/// #[derive(Structural)]
+/// #[struc(public)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// assert_eq!( <Point as FieldIndex<FP!(x)>>::INDEX, 0 );
+/// assert_eq!( <Point as FieldIndex<FP!(y)>>::INDEX, 1 );
+///
+/// assert_eq!( <(u8, u8, u8) as FieldIndex<FP!(2)>>::INDEX, 2 );
+///
+/// assert_eq!( <Option<u8> as FieldIndex<FP!(::Some.0)>>::INDEX, 0 );
+///
+/// ```
+///
+/// [`Tracked`]: ../struct.Tracked.html
+pub trait FieldIndex<FieldName> {
+    /// The index of the field,which is less than 64.
+    const INDEX: u8;
+}
+
+/// The names of all the fields in `Self`,as a type-level list of [`TStr`].
+///
+/// This is implemented by the `Structural` derive for structs in which every field
+/// has mutable accessor impls,and by tuples.
+///
+/// This is used by [`UninitBuilder`] to require every field to be initialized.
+///
+/// # Safety
+///
+/// `Names` must be a [`TList`] that contains the name of every field in `Self`
+/// (each one exactly once),
+/// and `Self` must implement `GetFieldMut` for each one of those names,
+/// returning a pointer to a different field in `GetFieldMut::get_field_raw_mut`,
+/// without reading from the pointed-to value.
+///
+/// Initializing every one of those fields must fully initialize a value of `Self`.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{
+///     field::AllFields,
+///     type_level::{TList, TNil},
+///     Structural, FP,
+/// };
+///
+/// #[derive(Structural)]
+/// #[struc(public)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// let _: <Point as AllFields>::Names = <TList<FP!(x), TList<FP!(y), TNil>>>::NEW;
+///
+/// let _: <(u8, u8) as AllFields>::Names = <TList<FP!(0), TList<FP!(1), TNil>>>::NEW;
+///
+/// ```
+///
+/// [`TStr`]: ../struct.TStr.html
+/// [`TList`]: ../type_level/list/struct.TList.html
+/// [`UninitBuilder`]: ../uninit_builder/struct.UninitBuilder.html
+pub unsafe trait AllFields {
+    /// A [`TList`] with the names of all the fields.
+    ///
+    /// [`TList`]: ../type_level/list/struct.TList.html
+    type Names;
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 /// Provides shared access to the `FieldName` field.
@@ -230,6 +344,14 @@ pub trait FieldType<FieldPath> {
 ///
 /// ```
 ///
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "type `{Self}` is missing a field accessible by reference",
+        label = "`{FieldName}` encodes the name of the missing field",
+        note = "names are spelled out with one type per character, eg: `TStr<__TS<(__f, __o, __o)>>` is the `foo` name",
+    )
+)]
 pub trait GetField<FieldName>: FieldType<FieldName> {
     /// Accesses the `FieldName` field by reference.
     fn get_field_(&self, field_name: FieldName) -> &Self::Ty;
@@ -462,6 +584,14 @@ pub type GetFieldType4<This, FieldPath, FieldPath2, FieldPath3, FieldPath4> =
 ///
 /// ```
 ///
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "type `{Self}` is missing a field accessible by mutable reference",
+        label = "`{FieldName}` encodes the name of the missing field",
+        note = "names are spelled out with one type per character, eg: `TStr<__TS<(__f, __o, __o)>>` is the `foo` name",
+    )
+)]
 pub unsafe trait GetFieldMut<FieldName>: GetField<FieldName> {
     /// Accesses the `FieldName` field by mutable reference.
     fn get_field_mut_(&mut self, field_name: FieldName) -> &mut Self::Ty;
@@ -656,6 +786,14 @@ pub type GetFieldRawMutFn<FieldName, FieldTy> = unsafe fn(*mut (), FieldName) ->
 ///
 /// ```
 ///
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "type `{Self}` is missing a field accessible by value",
+        label = "`{FieldName}` encodes the name of the missing field",
+        note = "names are spelled out with one type per character, eg: `TStr<__TS<(__f, __o, __o)>>` is the `foo` name",
+    )
+)]
 pub unsafe trait IntoField<FieldName>: GetField<FieldName> + DropFields {
     /// Converts this into the field by value.
     fn into_field_(self, field_name: FieldName) -> Self::Ty;
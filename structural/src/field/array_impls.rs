@@ -2,8 +2,8 @@
 
 use crate::{
     field::{
-        DropFields, FieldBit, FieldType, GetField, GetFieldMut, GetFieldRawMutFn, IntoField,
-        MovedOutFields,
+        DropFields, FieldBit, FieldIndex, FieldType, GetField, GetFieldMut, GetFieldRawMutFn,
+        IntoField, MovedOutFields,
     },
     path::{
         array_paths::{
@@ -13,6 +13,7 @@ use crate::{
         FieldPathSet, LargePathSet,
     },
     structural_trait::Structural,
+    visit_fields::{FieldAccess, FieldInfo, FieldVisitor, VisitFields, ARRAY_FIELD_NAMES},
     StructuralExt,
 };
 
@@ -58,6 +59,13 @@ macro_rules! array_impls {
                 }
             }
 
+            impl<T,P> FieldIndex<P> for [T;$index]
+            where
+                P:IsPathForArray<Self>,
+            {
+                const INDEX: u8 = P::INDEX as u8;
+            }
+
             unsafe impl<T,P> GetFieldMut<P> for [T;$index]
             where
                 P:IsPathForArray<Self>,
@@ -106,6 +114,33 @@ macro_rules! array_impls {
                 }
             }
 
+            impl<T,V> VisitFields<V> for [T;$index]
+            where
+                V: ?Sized + FieldVisitor<T>,
+            {
+                fn visit_fields(&self, visitor: &mut V) {
+                    for (&name, field) in ARRAY_FIELD_NAMES.iter().zip(self) {
+                        let info = FieldInfo{
+                            variant: None,
+                            name,
+                            access: FieldAccess::MutValue,
+                        };
+                        visitor.visit_field(info, field);
+                    }
+                }
+
+                fn visit_fields_mut(&mut self, visitor: &mut V) {
+                    for (&name, field) in ARRAY_FIELD_NAMES.iter().zip(self) {
+                        let info = FieldInfo{
+                            variant: None,
+                            name,
+                            access: FieldAccess::MutValue,
+                        };
+                        visitor.visit_field_mut(info, field);
+                    }
+                }
+            }
+
             unsafe impl<T> DropFields for [T;$index]{
                 #[inline(always)]
                 fn pre_move(&mut self){}
@@ -3,12 +3,28 @@ use crate::{
     structural_aliases as sa,
 };
 
+#[cfg(rust_1_55)]
+use std_::ops::ControlFlow;
+
+use std_::{ops::Bound, task::Poll};
+
 tstr_aliases! {
     mod strings {
         Ok,
         Err,
         Some,
         None,
+        Included,
+        Excluded,
+        Unbounded,
+        Ready,
+        Pending,
+        Continue,
+        Break,
+        Borrowed,
+        Owned,
+        Occupied,
+        Vacant,
         field0=0,
     }
 }
@@ -27,9 +43,17 @@ _private_impl_getters_for_derive_enum! {
             strings::Some,
             kind=regular,
             not_public(),
-            fields((IntoVariantFieldMut,0:T,dropping(f0, 0),strings::field0))
+            fields((IntoVariantFieldMut,0:T,dropping(f0, 0),strings::field0)),
+            names("Some", fields("0")),
+        )
+        (
+            None,
+            strings::None,
+            kind=regular,
+            not_public(),
+            fields(),
+            names("None", fields()),
         )
-        (None,strings::None,kind=regular,not_public(),fields())
     }
 }
 
@@ -74,14 +98,16 @@ _private_impl_getters_for_derive_enum! {
             strings::Ok,
             kind=regular,
             not_public(),
-            fields((IntoVariantFieldMut,0:T,dropping(f0, 0),strings::field0))
+            fields((IntoVariantFieldMut,0:T,dropping(f0, 0),strings::field0)),
+            names("Ok", fields("0")),
         )
         (
             Err,
             strings::Err,
             kind=regular,
             not_public(),
-            fields((IntoVariantFieldMut,0:E,dropping(f0, 0),strings::field0))
+            fields((IntoVariantFieldMut,0:E,dropping(f0, 0),strings::field0)),
+            names("Err", fields("0")),
         )
     }
 }
@@ -114,3 +140,323 @@ where
 }
 
 ///////////////////////////////////////////////////////////////////////////////
+
+_private_impl_getters_for_derive_enum! {
+    impl[T,] Bound<T>
+    where[]
+    {
+        enum=Bound
+        drop_fields={just_fields,}
+        variant_count=TS!(3),
+        (
+            Included,
+            strings::Included,
+            kind=regular,
+            not_public(),
+            fields((IntoVariantFieldMut,0:T,dropping(f0, 0),strings::field0)),
+            names("Included", fields("0")),
+        )
+        (
+            Excluded,
+            strings::Excluded,
+            kind=regular,
+            not_public(),
+            fields((IntoVariantFieldMut,0:T,dropping(f0, 0),strings::field0)),
+            names("Excluded", fields("0")),
+        )
+        (
+            Unbounded,
+            strings::Unbounded,
+            kind=regular,
+            not_public(),
+            fields(),
+            names("Unbounded", fields()),
+        )
+    }
+}
+
+impl<F, T> FromStructural<F> for Bound<T>
+where
+    F: sa::BoundMove_ESI<T>,
+{
+    fn from_structural(this: F) -> Self {
+        switch! {this;
+            Included(x)=>Bound::Included(x),
+            Excluded(x)=>Bound::Excluded(x),
+            Unbounded=>Bound::Unbounded,
+        }
+    }
+}
+
+impl<F, T> TryFromStructural<F> for Bound<T>
+where
+    F: sa::BoundMove_SI<T>,
+{
+    type Error = EmptyTryFromError;
+
+    fn try_from_structural(this: F) -> Result<Self, TryFromError<F, Self::Error>> {
+        Ok(switch! {this;
+            Included(x)=>Bound::Included(x),
+            Excluded(x)=>Bound::Excluded(x),
+            Unbounded=>Bound::Unbounded,
+            _=>return Err(TryFromError::with_empty_error(this)),
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+_private_impl_getters_for_derive_enum! {
+    impl[T,] Poll<T>
+    where[]
+    {
+        enum=Poll
+        drop_fields={just_fields,}
+        variant_count=TS!(2),
+        (
+            Ready,
+            strings::Ready,
+            kind=regular,
+            not_public(),
+            fields((IntoVariantFieldMut,0:T,dropping(f0, 0),strings::field0)),
+            names("Ready", fields("0")),
+        )
+        (
+            Pending,
+            strings::Pending,
+            kind=regular,
+            not_public(),
+            fields(),
+            names("Pending", fields()),
+        )
+    }
+}
+
+impl<F, T> FromStructural<F> for Poll<T>
+where
+    F: sa::PollMove_ESI<T>,
+{
+    fn from_structural(this: F) -> Self {
+        switch! {this;
+            Ready(x)=>Poll::Ready(x),
+            Pending=>Poll::Pending,
+        }
+    }
+}
+
+impl<F, T> TryFromStructural<F> for Poll<T>
+where
+    F: sa::PollMove_SI<T>,
+{
+    type Error = EmptyTryFromError;
+
+    fn try_from_structural(this: F) -> Result<Self, TryFromError<F, Self::Error>> {
+        Ok(switch! {this;
+            Ready(x)=>Poll::Ready(x),
+            Pending=>Poll::Pending,
+            _=>return Err(TryFromError::with_empty_error(this)),
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(rust_1_55)]
+_private_impl_getters_for_derive_enum! {
+    impl[B,C,] ControlFlow<B,C>
+    where[]
+    {
+        enum=ControlFlow
+        drop_fields={just_fields,}
+        variant_count=TS!(2),
+        (
+            Continue,
+            strings::Continue,
+            kind=regular,
+            not_public(),
+            fields((IntoVariantFieldMut,0:C,dropping(f0, 0),strings::field0)),
+            names("Continue", fields("0")),
+        )
+        (
+            Break,
+            strings::Break,
+            kind=regular,
+            not_public(),
+            fields((IntoVariantFieldMut,0:B,dropping(f0, 0),strings::field0)),
+            names("Break", fields("0")),
+        )
+    }
+}
+
+#[cfg(rust_1_55)]
+impl<F, B, C> FromStructural<F> for ControlFlow<B, C>
+where
+    F: sa::ControlFlowMove_ESI<B, C>,
+{
+    fn from_structural(this: F) -> Self {
+        switch! {this;
+            Continue(x)=>ControlFlow::Continue(x),
+            Break(x)=>ControlFlow::Break(x),
+        }
+    }
+}
+
+#[cfg(rust_1_55)]
+impl<F, B, C> TryFromStructural<F> for ControlFlow<B, C>
+where
+    F: sa::ControlFlowMove_SI<B, C>,
+{
+    type Error = EmptyTryFromError;
+
+    fn try_from_structural(this: F) -> Result<Self, TryFromError<F, Self::Error>> {
+        Ok(switch! {this;
+            Continue(x)=>ControlFlow::Continue(x),
+            Break(x)=>ControlFlow::Break(x),
+            _=>return Err(TryFromError::with_empty_error(this)),
+        })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use super::strings;
+
+    use crate::{
+        alloc::borrow::{Cow, ToOwned},
+        convert::{EmptyTryFromError, FromStructural, TryFromError, TryFromStructural},
+        structural_aliases as sa,
+    };
+
+    _private_impl_getters_for_derive_enum! {
+        impl['a,B,] Cow<'a,B>
+        where[B: 'a + ?Sized + ToOwned,]
+        {
+            enum=Cow
+            drop_fields={just_fields,}
+            variant_count=TS!(2),
+            (
+                Borrowed,
+                strings::Borrowed,
+                kind=regular,
+                not_public(),
+                fields((IntoVariantFieldMut,0:&'a B,dropping(f0, 0),strings::field0)),
+                names("Borrowed", fields("0")),
+            )
+            (
+                Owned,
+                strings::Owned,
+                kind=regular,
+                not_public(),
+                fields((IntoVariantFieldMut,0:B::Owned,dropping(f0, 0),strings::field0)),
+                names("Owned", fields("0")),
+            )
+        }
+    }
+
+    impl<'a, F, B> FromStructural<F> for Cow<'a, B>
+    where
+        B: 'a + ?Sized + ToOwned,
+        F: sa::CowMove_ESI<'a, B>,
+    {
+        fn from_structural(this: F) -> Self {
+            switch! {this;
+                Borrowed(x)=>Cow::Borrowed(x),
+                Owned(x)=>Cow::Owned(x),
+            }
+        }
+    }
+
+    impl<'a, F, B> TryFromStructural<F> for Cow<'a, B>
+    where
+        B: 'a + ?Sized + ToOwned,
+        F: sa::CowMove_SI<'a, B>,
+    {
+        type Error = EmptyTryFromError;
+
+        fn try_from_structural(this: F) -> Result<Self, TryFromError<F, Self::Error>> {
+            Ok(switch! {this;
+                Borrowed(x)=>Cow::Borrowed(x),
+                Owned(x)=>Cow::Owned(x),
+                _=>return Err(TryFromError::with_empty_error(this)),
+            })
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "std")]
+mod std_impls {
+    use super::strings;
+
+    use crate::{
+        convert::{EmptyTryFromError, FromStructural, TryFromError, TryFromStructural},
+        std::collections::hash_map::{Entry, OccupiedEntry, VacantEntry},
+        structural_aliases as sa,
+    };
+
+    _private_impl_getters_for_derive_enum! {
+        impl['a,K,V,] Entry<'a,K,V>
+        where[]
+        {
+            enum=Entry
+            drop_fields={just_fields,}
+            variant_count=TS!(2),
+            (
+                Occupied,
+                strings::Occupied,
+                kind=regular,
+                not_public(),
+                fields((
+                    IntoVariantFieldMut,
+                    0:OccupiedEntry<'a,K,V>,
+                    dropping(f0, 0),
+                    strings::field0
+                )),
+                names("Occupied", fields("0")),
+            )
+            (
+                Vacant,
+                strings::Vacant,
+                kind=regular,
+                not_public(),
+                fields((
+                    IntoVariantFieldMut,
+                    0:VacantEntry<'a,K,V>,
+                    dropping(f0, 0),
+                    strings::field0
+                )),
+                names("Vacant", fields("0")),
+            )
+        }
+    }
+
+    impl<'a, F, K, V> FromStructural<F> for Entry<'a, K, V>
+    where
+        F: sa::HashMapEntryMove_ESI<'a, K, V>,
+    {
+        fn from_structural(this: F) -> Self {
+            switch! {this;
+                Occupied(x)=>Entry::Occupied(x),
+                Vacant(x)=>Entry::Vacant(x),
+            }
+        }
+    }
+
+    impl<'a, F, K, V> TryFromStructural<F> for Entry<'a, K, V>
+    where
+        F: sa::HashMapEntryMove_SI<'a, K, V>,
+    {
+        type Error = EmptyTryFromError;
+
+        fn try_from_structural(this: F) -> Result<Self, TryFromError<F, Self::Error>> {
+            Ok(switch! {this;
+                Occupied(x)=>Entry::Occupied(x),
+                Vacant(x)=>Entry::Vacant(x),
+                _=>return Err(TryFromError::with_empty_error(this)),
+            })
+        }
+    }
+}
@@ -0,0 +1,162 @@
+/*!
+Byte offsets of the fields of `#[repr(C)]` structs,
+to get pointers to (possibly nested) fields from a pointer to the struct.
+
+The `Structural` derive implements [`FieldOffset`] for `#[repr(C)]` structs
+(which aren't also `#[repr(packed)]`),
+for every field with accessor impls.
+
+[`FieldOffset`] is implemented for nested field paths (eg: `FP!(a.b.c)`)
+of up to 8 fields,when every type in the path implements it for the next field.
+
+[`FieldOffset`]: ./trait.FieldOffset.html
+
+# Example
+
+```rust
+use structural::{
+    field::{field_ptr, field_ptr_mut, FieldOffset},
+    Structural, FP, fp,
+};
+
+#[repr(C)]
+#[derive(Structural, Debug, Copy, Clone, PartialEq)]
+#[struc(public)]
+struct Point {
+    x: u8,
+    y: u32,
+}
+
+#[repr(C)]
+#[derive(Structural, Debug, Copy, Clone, PartialEq)]
+struct Line {
+    pub start: Point,
+    id: u16,
+    pub end: Point,
+}
+
+assert_eq!( <Point as FieldOffset<FP!(x)>>::OFFSET, 0 );
+assert_eq!( <Point as FieldOffset<FP!(y)>>::OFFSET, 4 );
+assert_eq!( <Line as FieldOffset<FP!(end)>>::OFFSET, 12 );
+assert_eq!( <Line as FieldOffset<FP!(end.y)>>::OFFSET, 16 );
+
+let mut line = Line{
+    start: Point{ x: 3, y: 5 },
+    id: 8,
+    end: Point{ x: 13, y: 21 },
+};
+
+unsafe{
+    let ptr: *const Line = &line;
+    assert_eq!( *field_ptr(ptr, fp!(start.y)), 5 );
+    assert_eq!( *field_ptr(ptr, fp!(end)), Point{ x: 13, y: 21 } );
+
+    let ptr: *mut Line = &mut line;
+    *field_ptr_mut(ptr, fp!(end.x)) = 34;
+}
+assert_eq!( line.end, Point{ x: 34, y: 21 } );
+
+```
+
+*/
+
+use crate::path::{NestedFieldPath, TStr};
+
+use std_::mem;
+
+/// Declares the offset in bytes of the `FieldPath` field from the start of `Self`.
+///
+/// This is implemented by the `Structural` derive for `#[repr(C)]` structs,
+/// and for nested field paths (`NestedFieldPath`),
+/// adding up the offsets of every field in the path.
+///
+/// # Safety
+///
+/// `OFFSET` must be the offset in bytes of a field of type `Ty` inside of `Self`,
+/// for every value of `Self`.
+pub unsafe trait FieldOffset<FieldPath> {
+    /// The type of the field.
+    type Ty;
+
+    /// The offset of the field in bytes.
+    const OFFSET: usize;
+}
+
+/// Gets a pointer to the `path` field from a pointer to a `T`,
+/// using the [`FieldOffset`] impl of `T`.
+///
+/// The returned pointer can only be dereferenced if `ptr` points to a valid `T`.
+///
+/// [`FieldOffset`]: ./trait.FieldOffset.html
+#[inline(always)]
+pub fn field_ptr<T, P>(ptr: *const T, _: P) -> *const T::Ty
+where
+    T: FieldOffset<P>,
+{
+    (ptr as *const u8).wrapping_add(<T as FieldOffset<P>>::OFFSET) as *const T::Ty
+}
+
+/// Gets a mutable pointer to the `path` field from a mutable pointer to a `T`,
+/// using the [`FieldOffset`] impl of `T`.
+///
+/// The returned pointer can only be dereferenced if `ptr` points to a valid `T`.
+///
+/// [`FieldOffset`]: ./trait.FieldOffset.html
+#[inline(always)]
+pub fn field_ptr_mut<T, P>(ptr: *mut T, _: P) -> *mut T::Ty
+where
+    T: FieldOffset<P>,
+{
+    (ptr as *mut u8).wrapping_add(<T as FieldOffset<P>>::OFFSET) as *mut T::Ty
+}
+
+/// Computes the offset of a `#[repr(C)]` field of type `Next`,
+/// which comes after a field of type `Prev` at the `prev_offset` offset.
+#[doc(hidden)]
+pub const fn next_field_offset<Prev, Next>(prev_offset: usize) -> usize {
+    let end = prev_offset + mem::size_of::<Prev>();
+    let align = mem::align_of::<Next>();
+    (end + align - 1) & !(align - 1)
+}
+
+// The path components are `TStr`s so that these impls don't recurse into each other,
+// since that causes overflow errors when the path type isn't inferred yet.
+macro_rules! impl_nested_field_offset {
+    (
+        $( ($this:ident, $path:ident, $next:ident) )*
+        ; ($last:ident, $last_path:ident)
+    ) => {
+        unsafe impl<T0, $($path, $next,)* $last_path>
+            FieldOffset<NestedFieldPath<($(TStr<$path>,)* TStr<$last_path>,)>>
+        for T0
+        where
+            $( $this: FieldOffset<TStr<$path>, Ty = $next>, )*
+            $last: FieldOffset<TStr<$last_path>>,
+        {
+            type Ty = <$last as FieldOffset<TStr<$last_path>>>::Ty;
+
+            const OFFSET: usize =
+                $( <$this as FieldOffset<TStr<$path>>>::OFFSET + )*
+                <$last as FieldOffset<TStr<$last_path>>>::OFFSET;
+        }
+    };
+}
+
+impl_nested_field_offset! { ; (T0, F0) }
+impl_nested_field_offset! { (T0, F0, T1) ; (T1, F1) }
+impl_nested_field_offset! { (T0, F0, T1) (T1, F1, T2) ; (T2, F2) }
+impl_nested_field_offset! { (T0, F0, T1) (T1, F1, T2) (T2, F2, T3) ; (T3, F3) }
+impl_nested_field_offset! { (T0, F0, T1) (T1, F1, T2) (T2, F2, T3) (T3, F3, T4) ; (T4, F4) }
+impl_nested_field_offset! {
+    (T0, F0, T1) (T1, F1, T2) (T2, F2, T3) (T3, F3, T4) (T4, F4, T5)
+    ; (T5, F5)
+}
+impl_nested_field_offset! {
+    (T0, F0, T1) (T1, F1, T2) (T2, F2, T3) (T3, F3, T4) (T4, F4, T5) (T5, F5, T6)
+    ; (T6, F6)
+}
+impl_nested_field_offset! {
+    (T0, F0, T1) (T1, F1, T2) (T2, F2, T3) (T3, F3, T4) (T4, F4, T5) (T5, F5, T6)
+    (T6, F6, T7)
+    ; (T7, F7)
+}
@@ -1,25 +1,42 @@
 #![allow(non_camel_case_types)]
 
-use crate::{fp, FieldType, GetField, IntoField, Structural};
+use crate::{
+    convert::{EmptyTryFromError, TryFromError, TryFromStructural},
+    field::{
+        DropFields, FieldBit, InfallibleAccess, MovedOutFields, RevIntoFieldImpl,
+        RevMoveOutFieldImpl,
+    },
+    fp, FieldType, GetField, IntoField, Structural,
+};
 
 #[allow(unused_imports)]
 use crate::StructuralExt;
 
+#[cfg(rust_1_74)]
+use std_::num::Saturating;
+
 use std_::{
+    cell::{Cell, UnsafeCell},
+    cmp::Reverse,
     //marker::Unpin,
     mem::ManuallyDrop,
-    ops::Deref,
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+    },
+    ops::{Deref, DerefMut},
     ops::{Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive},
     pin::Pin,
 };
 
 type Start_STR = FP!(start);
 type End_STR = FP!(end);
+type Field0_STR = FP!(0);
 
 ///////////////////////////////////////////////////////
 
 _private_impl_getters_for_derive_struct! {
-    impl[T] Range<T>
+    impl[T,] Range<T>
     where[]
     {
         DropFields{ drop_fields={just_fields,} }
@@ -42,7 +59,7 @@ z_impl_from_structural! {
 }
 
 _private_impl_getters_for_derive_struct! {
-    impl[T] RangeFrom<T>
+    impl[T,] RangeFrom<T>
     where[]
     {
         DropFields{ drop_fields={just_fields,} }
@@ -64,7 +81,7 @@ z_impl_from_structural! {
 }
 
 _private_impl_getters_for_derive_struct! {
-    impl[T] RangeTo<T>
+    impl[T,] RangeTo<T>
     where[]
     {
         DropFields{ drop_fields={just_fields,} }
@@ -86,7 +103,7 @@ z_impl_from_structural! {
 }
 
 _private_impl_getters_for_derive_struct! {
-    impl[T] RangeToInclusive<T>
+    impl[T,] RangeToInclusive<T>
     where[]
     {
         DropFields{ drop_fields={just_fields,} }
@@ -143,6 +160,163 @@ z_impl_from_structural! {
 
 ///////////////////////////////////////////////////////
 
+macro_rules! impl_newtype_accessors {
+    ( $( $(#[$attr:meta])* $newtype:ident, )* ) => {
+        $(
+            $(#[$attr])*
+            _private_impl_getters_for_derive_struct! {
+                impl[T,] $newtype<T>
+                where[]
+                {
+                    DropFields{ drop_fields={just_fields,} }
+
+                    (IntoFieldMut < 0 : T,0,Field0_STR,"0",> )
+                }
+            }
+
+            $(#[$attr])*
+            z_impl_from_structural! {
+                impl[F, T] FromStructural<F> for $newtype<T>
+                where[
+                    F: IntoField<Field0_STR, Ty = T>,
+                ]{
+                    fn from_structural(this){
+                        $newtype(this.into_field(fp!(0)))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_newtype_accessors! {
+    Wrapping,
+    #[cfg(rust_1_74)]
+    Saturating,
+    Reverse,
+}
+
+///////////////////////////////////////////////////////
+
+macro_rules! impl_nonzero_accessors {
+    ( $( ($nonzero:ident, $int:ident) )* ) => {
+        $(
+            impl Structural for $nonzero {}
+
+            impl FieldType<Field0_STR> for $nonzero {
+                type Ty = $int;
+            }
+
+            impl GetField<Field0_STR> for $nonzero {
+                #[inline(always)]
+                fn get_field_(&self, _: Field0_STR) -> &Self::Ty {
+                    // `NonZero*` types have the same layout as the integer they wrap
+                    unsafe { &*(self as *const $nonzero as *const $int) }
+                }
+            }
+
+            impl<F> TryFromStructural<F> for $nonzero
+            where
+                F: GetField<Field0_STR, Ty = $int>,
+            {
+                type Error = EmptyTryFromError;
+
+                fn try_from_structural(
+                    this: F,
+                ) -> Result<Self, TryFromError<F, EmptyTryFromError>> {
+                    match $nonzero::new(*this.field_(fp!(0))) {
+                        Some(x) => Ok(x),
+                        None => Err(TryFromError::with_empty_error(this)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_nonzero_accessors! {
+    (NonZeroU8, u8)
+    (NonZeroU16, u16)
+    (NonZeroU32, u32)
+    (NonZeroU64, u64)
+    (NonZeroU128, u128)
+    (NonZeroUsize, usize)
+    (NonZeroI8, i8)
+    (NonZeroI16, i16)
+    (NonZeroI32, i32)
+    (NonZeroI64, i64)
+    (NonZeroI128, i128)
+    (NonZeroIsize, isize)
+}
+
+///////////////////////////////////////////////////////
+
+// `Cell` and `UnsafeCell` don't implement `GetField`(nor `IntoField`,which requires it),
+// because it can't return a reference to their contents soundly,
+// so their `0` field is only accessible by value,
+// through the `Rev*` traits that `StructuralExt::{into_field, into_fields}` use.
+
+macro_rules! impl_cell_accessors {
+    ( $( ($cell:ident, $as_ptr:ident) )* ) => {
+        $(
+            impl<T> Structural for $cell<T> {}
+
+            impl<T> FieldType<Field0_STR> for $cell<T> {
+                type Ty = T;
+            }
+
+            impl<T> RevIntoFieldImpl<$cell<T>> for Field0_STR {
+                #[inline(always)]
+                fn rev_into_field(self, this: $cell<T>) -> Result<T, InfallibleAccess> {
+                    Ok(this.into_inner())
+                }
+            }
+
+            unsafe impl<T> RevMoveOutFieldImpl<$cell<T>> for Field0_STR {
+                #[inline(always)]
+                unsafe fn rev_move_out_field(
+                    self,
+                    this: &mut $cell<T>,
+                    moved: &mut MovedOutFields,
+                ) -> Result<T, InfallibleAccess> {
+                    moved.set_moved_out(FieldBit::new(0));
+                    Ok(std_::ptr::read(this.$as_ptr()))
+                }
+            }
+
+            unsafe impl<T> DropFields for $cell<T> {
+                #[inline(always)]
+                fn pre_move(&mut self) {}
+
+                #[inline(always)]
+                unsafe fn drop_fields(&mut self, moved: MovedOutFields) {
+                    if !moved.is_moved_out(FieldBit::new(0)) {
+                        std_::ptr::drop_in_place(self.$as_ptr());
+                    }
+                }
+            }
+
+            z_impl_from_structural! {
+                impl[F, T] FromStructural<F> for $cell<T>
+                where[
+                    F: IntoField<Field0_STR, Ty = T>,
+                ]{
+                    fn from_structural(this){
+                        $cell::new(this.into_field(fp!(0)))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_cell_accessors! {
+    (Cell, as_ptr)
+    (UnsafeCell, get)
+}
+
+///////////////////////////////////////////////////////
+
 // This allows using all the field accessors in T from `ManuallyDrop<T>`
 unsafe_delegate_structural_with! {
     impl[T,] ManuallyDrop<T>
@@ -186,6 +360,17 @@ unsafe_delegate_structural_with! {
 
     GetField { &*this }
 
+    GetFieldMut
+    where [
+        P: DerefMut,
+        P::Target: Unpin,
+    ]{
+        Pin::get_mut(this.as_mut())
+    }
+    as_delegating_raw{
+        &mut **(this as *mut Pin<P> as *mut P) as *mut P::Target
+    }
+
     FromStructural
     where [ P::Target: Unpin, ]
     {
@@ -199,7 +384,13 @@ fn delegated_pin() {
     let tup = (2, 3, 5, 8);
     let pin = Pin::new(&tup);
     assert_eq!(pin.fields(fp!(0, 1, 2, 3)), (&2, &3, &5, &8));
-    //assert_eq!( pin.fields_mut(fp!(0,1,2,3)), (&mut 2,&mut 3,&mut 5,&mut 8) );
+
+    let mut tup = (2, 3, 5, 8);
+    let mut pin = Pin::new(&mut tup);
+    assert_eq!(
+        pin.fields_mut(fp!(0, 1, 2, 3)),
+        (&mut 2, &mut 3, &mut 5, &mut 8)
+    );
 }
 
 ///////////////////////////////////////////////////////
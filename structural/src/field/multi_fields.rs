@@ -113,6 +113,13 @@ pub type RevIntoMultiFieldOut<Path, This> = <Path as RevIntoMultiField<This>>::I
 ///
 /// ```
 ///
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "the `{Self}` field paths can't access fields by reference in `{This}`",
+        label = "`{This}` doesn't have some of these fields accessible by reference",
+    )
+)]
 pub trait RevGetMultiFieldImpl<'a, This: ?Sized + 'a>: IsMultiFieldPath + Sized {
     /// A collection of `Results<&'a _,_>`s referencing fields.
     type UnnormFields: 'a + NormalizeFields;
@@ -349,6 +356,14 @@ where
 ///
 /// ```
 ///
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "the `{Self}` field paths can't access fields by mutable reference in `{This}`",
+        label = "`{This}` doesn't have some of these fields accessible by mutable reference",
+        note = "accessing fields mutably requires the field paths to be for disjoint fields (`UniquePaths`)",
+    )
+)]
 pub unsafe trait RevGetMultiFieldMutImpl<'a, This: ?Sized + 'a>:
     IsMultiFieldPath<PathUniqueness = UniquePaths> + Sized
 {
@@ -591,6 +606,14 @@ where
 /// [`RevIntoMultiField`]: ./trait.RevIntoMultiField.html
 ///
 ///
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "the `{Self}` field paths can't access fields by value in `{This}`",
+        label = "`{This}` doesn't have some of these fields accessible by value",
+        note = "accessing fields by value requires the field paths to be for disjoint fields (`UniquePaths`)",
+    )
+)]
 pub trait RevIntoMultiFieldImpl<This>:
     IsMultiFieldPath<PathUniqueness = UniquePaths> + Sized
 {
@@ -276,6 +276,99 @@ impl MovedOutFields {
     pub const fn is_moved_out(&self, bit: FieldBit) -> bool {
         (self.0 & bit.0) != 0
     }
+
+    /// Gets the `len` bits starting from the `start` bit,
+    /// as a `MovedOutFields` in which the `start` bit is the first one.
+    ///
+    /// This is used by types that delegate by-value access to some fields to
+    /// one of their fields (the delegated-to field),
+    /// tracking which fields were moved out of the delegated-to field
+    /// in a range of bits that the type doesn't use for its own fields.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `start + len > 64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::field::{MovedOutFields, FieldBit};
+    ///
+    /// let mut moved = MovedOutFields::new();
+    /// moved.set_moved_out(FieldBit::new(0));
+    ///
+    /// // The fields moved out of the delegated-to field use bits 8 to 15.
+    /// let mut delegated = moved.range(8, 8);
+    /// delegated.set_moved_out(FieldBit::new(1));
+    /// assert!( moved.set_range(8, 8, delegated) );
+    ///
+    /// assert!( moved.is_moved_out(FieldBit::new(0)) );
+    /// assert!( moved.is_moved_out(FieldBit::new(9)) );
+    /// assert!( !moved.is_moved_out(FieldBit::new(1)) );
+    ///
+    /// assert!( moved.range(8, 8).is_moved_out(FieldBit::new(1)) );
+    /// assert!( !moved.range(8, 8).is_moved_out(FieldBit::new(0)) );
+    ///
+    /// ```
+    #[inline]
+    pub fn range(&self, start: u8, len: u8) -> MovedOutFields {
+        let mask = range_mask(start, len);
+        MovedOutFields(self.0.checked_shr(u32::from(start)).unwrap_or(0) & mask)
+    }
+
+    /// Sets the `len` bits starting from the `start` bit to the first `len` bits of `moved`,
+    /// returning whether `moved` fits in that range.
+    ///
+    /// If `moved` has any bit set after the first `len` bits,
+    /// this returns false without modifying `self`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `start + len > 64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::field::{MovedOutFields, FieldBit};
+    ///
+    /// let mut moved = MovedOutFields::new();
+    ///
+    /// let mut delegated = MovedOutFields::new();
+    /// delegated.set_moved_out(FieldBit::new(2));
+    /// assert!( moved.set_range(60, 4, delegated) );
+    /// assert!( moved.is_moved_out(FieldBit::new(62)) );
+    /// assert!( !moved.is_moved_out(FieldBit::new(63)) );
+    ///
+    /// // The 5th bit doesn't fit in the 4 bits of the range.
+    /// delegated.set_moved_out(FieldBit::new(4));
+    /// assert!( !moved.set_range(60, 4, delegated) );
+    /// assert!( moved.is_moved_out(FieldBit::new(62)) );
+    /// assert!( !moved.is_moved_out(FieldBit::new(63)) );
+    ///
+    /// ```
+    #[inline]
+    pub fn set_range(&mut self, start: u8, len: u8, moved: MovedOutFields) -> bool {
+        let mask = range_mask(start, len);
+        if (moved.0 & !mask) != 0 {
+            return false;
+        }
+        let shift = |x: u64| x.checked_shl(u32::from(start)).unwrap_or(0);
+        self.0 = (self.0 & !shift(mask)) | shift(moved.0);
+        true
+    }
+}
+
+// The mask for the first `len` bits,panicking if `start + len > 64`.
+#[inline]
+fn range_mask(start: u8, len: u8) -> u64 {
+    assert!(
+        u32::from(start) + u32::from(len) <= 64,
+        "the range of bits must be within the 64 bits of `MovedOutFields`"
+    );
+    match len {
+        64 => !0,
+        _ => (1 << len) - 1,
+    }
 }
 
 /////////////////////////////////////////////////////////////////////////////////
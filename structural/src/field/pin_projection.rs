@@ -0,0 +1,255 @@
+/*!
+Pin projection,
+getting a `Pin<&mut Field>` or a `&mut Field` from a `Pin<&mut Self>`,
+depending on whether the field is structurally pinned.
+
+The [`StructuralExt::pin_field_mut`] and [`StructuralExt::pin_fields_mut`] methods
+use the [`PinField`] impls of a type to project a pin to its fields.
+
+[`PinField`] is implemented by the `Structural` derive for structs with at least one
+`#[struc(pin)]` field,
+for every field with mutable accessor impls.
+
+[`StructuralExt::pin_field_mut`]: ../../trait.StructuralExt.html#method.pin_field_mut
+[`StructuralExt::pin_fields_mut`]: ../../trait.StructuralExt.html#method.pin_fields_mut
+[`PinField`]: ./trait.PinField.html
+
+# Example
+
+```rust
+use structural::{
+    field::{
+        pin_projection::{Pinned, Unpinned},
+        PinField,
+    },
+    Structural, StructuralExt, FP, fp,
+};
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+// Polls the pinned `inner` future of any type,
+// counting the times it was polled in the unpinned `polls` field.
+fn poll_counted<T, F>(this: Pin<&mut T>, cx: &mut Context<'_>) -> Poll<F::Output>
+where
+    T: PinField<FP!(inner), Ty = F, Pinning = Pinned>,
+    T: PinField<FP!(polls), Ty = u32, Pinning = Unpinned>,
+    F: Future,
+{
+    let (inner, polls): (Pin<&mut F>, &mut u32) = this.pin_fields_mut(fp!(inner, polls));
+    *polls += 1;
+    inner.poll(cx)
+}
+
+#[derive(Structural)]
+#[struc(public)]
+struct Counted<F> {
+    #[struc(pin)]
+    inner: F,
+    polls: u32,
+}
+
+impl<F: Future> Future for Counted<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        poll_counted(self, cx)
+    }
+}
+
+let mut fut = Box::pin(Counted{ inner: async { 100 }, polls: 0 });
+
+let waker = noop_waker();
+let mut cx = Context::from_waker(&waker);
+assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(100));
+assert_eq!(fut.polls, 1);
+
+*fut.as_mut().pin_field_mut(fp!(polls)) = 10;
+assert_eq!(fut.polls, 10);
+
+# fn noop_waker() -> std::task::Waker {
+#     use std::task::{RawWaker, RawWakerVTable, Waker};
+#     fn clone(_: *const ()) -> RawWaker { RawWaker::new(std::ptr::null(), &VTABLE) }
+#     fn noop(_: *const ()) {}
+#     static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+#     unsafe{ Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+# }
+```
+
+*/
+
+use crate::{
+    field::{GetFieldMut, GetFieldType},
+    path::{FieldPathSet, UniquePaths},
+};
+
+use std_::pin::Pin;
+
+/// Marker type for a field that is structurally pinned,
+/// projected from a `Pin<&mut Self>` to a `Pin<&mut Field>`.
+#[derive(Debug, Copy, Clone)]
+pub struct Pinned;
+
+/// Marker type for a field that is not structurally pinned,
+/// projected from a `Pin<&mut Self>` to a `&mut Field`.
+#[derive(Debug, Copy, Clone)]
+pub struct Unpinned;
+
+/// How a field of type `T` is projected from a pinned reference,
+/// implemented by [`Pinned`] and [`Unpinned`].
+///
+/// [`Pinned`]: ./struct.Pinned.html
+/// [`Unpinned`]: ./struct.Unpinned.html
+pub trait PinProjection<'a, T: ?Sized + 'a>: pin_projection_sealed::Sealed {
+    /// The type that the field is projected to.
+    type Projected;
+
+    /// Projects a mutable reference to a field.
+    ///
+    /// # Safety
+    ///
+    /// `field` must be a field of a pinned value whose `PinField` impl
+    /// uses `Self` as the `Pinning` associated type.
+    unsafe fn project(field: &'a mut T) -> Self::Projected;
+}
+
+mod pin_projection_sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Pinned {}
+    impl Sealed for super::Unpinned {}
+}
+
+impl<'a, T> PinProjection<'a, T> for Pinned
+where
+    T: ?Sized + 'a,
+{
+    type Projected = Pin<&'a mut T>;
+
+    #[inline(always)]
+    unsafe fn project(field: &'a mut T) -> Self::Projected {
+        Pin::new_unchecked(field)
+    }
+}
+
+impl<'a, T> PinProjection<'a, T> for Unpinned
+where
+    T: ?Sized + 'a,
+{
+    type Projected = &'a mut T;
+
+    #[inline(always)]
+    unsafe fn project(field: &'a mut T) -> Self::Projected {
+        field
+    }
+}
+
+/// Declares whether the `FieldName` field is structurally pinned.
+///
+/// # Safety
+///
+/// If `Pinning` is [`Pinned`]:
+///
+/// - The type must not be `Unpin` unless the field type is `Unpin`.
+///
+/// - The field must not be moved out of a pinned value,
+///   including by a `Drop` impl of the type.
+///
+/// - The field must not be accessed by mutable reference
+///   through a `Pin<&mut Self>` other than through its `Pinned` projection.
+///
+/// If `Pinning` is [`Unpinned`]:
+/// the field must never be treated as pinned,
+/// by creating a `Pin<&mut Field>` from a `Pin<&mut Self>`.
+///
+/// Types implementing `GetFieldMut` by delegating to a single pointer or field
+/// can't implement this trait soundly,
+/// so they must not implement it for the delegated-to fields.
+///
+/// [`Pinned`]: ./struct.Pinned.html
+/// [`Unpinned`]: ./struct.Unpinned.html
+pub unsafe trait PinField<FieldName>: GetFieldMut<FieldName> {
+    /// Either [`Pinned`] or [`Unpinned`].
+    ///
+    /// [`Pinned`]: ./struct.Pinned.html
+    /// [`Unpinned`]: ./struct.Unpinned.html
+    type Pinning;
+}
+
+/// The type that the `FieldName` field of `This` is projected to,
+/// `Pin<&'a mut Field>` for pinned fields,
+/// `&'a mut Field` for unpinned fields.
+pub type PinFieldOut<'a, This, FieldName> = <<This as PinField<FieldName>>::Pinning as PinProjection<
+    'a,
+    GetFieldType<This, FieldName>,
+>>::Projected;
+
+/// Projects a pinned reference to multiple fields,
+/// used by [`StructuralExt::pin_fields_mut`].
+///
+/// This is implemented for `FieldPathSet`s of up to 8 field names,
+/// which must be unique paths (the default for `fp!(a, b, c)`).
+///
+/// [`StructuralExt::pin_fields_mut`]: ../../trait.StructuralExt.html#method.pin_fields_mut
+pub trait RevPinFieldsMut<'a, This: ?Sized> {
+    /// A tuple with the projection of each field.
+    type Fields;
+
+    /// Projects `this` to the fields that `self` refers to.
+    fn rev_pin_fields_mut(self, this: Pin<&'a mut This>) -> Self::Fields;
+}
+
+macro_rules! impl_rev_pin_fields_mut {
+    ( $( ($path:ident, $p:ident) )* ) => {
+        impl<'a, This, $($path,)*> RevPinFieldsMut<'a, This>
+        for FieldPathSet<($($path,)*), UniquePaths>
+        where
+            This: ?Sized + 'a,
+            $(
+                This: PinField<$path>,
+                GetFieldType<This, $path>: 'a,
+                <This as PinField<$path>>::Pinning: PinProjection<'a, GetFieldType<This, $path>>,
+            )*
+        {
+            type Fields = ($(PinFieldOut<'a, This, $path>,)*);
+
+            #[inline]
+            #[allow(unused_variables, clippy::unused_unit)]
+            fn rev_pin_fields_mut(self, this: Pin<&'a mut This>) -> Self::Fields {
+                let ($($p,)*) = self.into_paths();
+                unsafe {
+                    let this = Pin::get_unchecked_mut(this);
+                    $(
+                        let $p = (GetFieldMut::<$path>::get_field_raw_mut_fn(&*this), $p);
+                    )*
+                    let ptr = this as *mut This as *mut ();
+                    $(
+                        let $p = ($p.0)(ptr, $p.1);
+                    )*
+                    (
+                        $(
+                            <<This as PinField<$path>>::Pinning as PinProjection<'a, _>>::project(
+                                &mut *$p,
+                            ),
+                        )*
+                    )
+                }
+            }
+        }
+    };
+}
+
+impl_rev_pin_fields_mut! {}
+impl_rev_pin_fields_mut! { (P0, p0) }
+impl_rev_pin_fields_mut! { (P0, p0) (P1, p1) }
+impl_rev_pin_fields_mut! { (P0, p0) (P1, p1) (P2, p2) }
+impl_rev_pin_fields_mut! { (P0, p0) (P1, p1) (P2, p2) (P3, p3) }
+impl_rev_pin_fields_mut! { (P0, p0) (P1, p1) (P2, p2) (P3, p3) (P4, p4) }
+impl_rev_pin_fields_mut! { (P0, p0) (P1, p1) (P2, p2) (P3, p3) (P4, p4) (P5, p5) }
+impl_rev_pin_fields_mut! { (P0, p0) (P1, p1) (P2, p2) (P3, p3) (P4, p4) (P5, p5) (P6, p6) }
+impl_rev_pin_fields_mut! {
+    (P0, p0) (P1, p1) (P2, p2) (P3, p3) (P4, p4) (P5, p5) (P6, p6) (P7, p7)
+}
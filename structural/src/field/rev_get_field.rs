@@ -66,6 +66,14 @@ pub type RevFieldErrOut<FieldPath, This> = <FieldPath as RevFieldErr<This>>::Err
 
 /// Like `FieldType`,except that the parameters are reversed.
 /// `This` is the type we are accessing,and `Self` is a field path.
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "the `{Self}` field path can't access any field in `{This}`",
+        label = "`{This}` has no field at this path",
+        note = "this is usually caused by `{This}` not having one of the fields in the path",
+    )
+)]
 pub trait RevFieldType<This: ?Sized>: IsSingleFieldPath {
     /// The type of the field.
     type Ty: ?Sized;
@@ -147,6 +155,14 @@ pub trait RevFieldErr<This: ?Sized>: IsSingleFieldPath + RevFieldType<This> {
 ///
 ///
 /// ```
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "the `{Self}` field path can't access a field by reference in `{This}`",
+        label = "`{This}` has no field at this path accessible by reference",
+        note = "this is usually caused by `{This}` not having one of the fields in the path",
+    )
+)]
 pub trait RevGetFieldImpl<'a, This: ?Sized>: RevFieldErr<This> {
     /// Accesses the field that `self` represents inside of `this`,by reference.
     fn rev_get_field(self, this: &'a This) -> Result<&'a Self::Ty, Self::Err>;
@@ -265,6 +281,14 @@ pub trait RevGetFieldImpl<'a, This: ?Sized>: RevFieldErr<This> {
 /// }
 ///
 /// ```
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "the `{Self}` field path can't access a field by mutable reference in `{This}`",
+        label = "`{This}` has no field at this path accessible by mutable reference",
+        note = "this is usually caused by `{This}` not having one of the fields in the path",
+    )
+)]
 pub unsafe trait RevGetFieldMutImpl<'a, This: ?Sized>: RevGetFieldImpl<'a, This> {
     /// Accesses the field that `self` represents inside of `this`,by mutable reference.
     fn rev_get_field_mut(self, this: &'a mut This) -> Result<&'a mut Self::Ty, Self::Err>;
@@ -363,6 +387,14 @@ pub unsafe trait RevGetFieldMutImpl<'a, This: ?Sized>: RevGetFieldImpl<'a, This>
 ///     }
 /// }
 /// ```
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "the `{Self}` field path can't access a field by value in `{This}`",
+        label = "`{This}` has no field at this path accessible by value",
+        note = "this is usually caused by `{This}` not having one of the fields in the path",
+    )
+)]
 pub trait RevIntoFieldImpl<This: ?Sized>: RevFieldErr<This> {
     /// Accesses the field that `self` represents inside of `this`,by value.
     fn rev_into_field(self, this: This) -> Result<Self::Ty, Self::Err>
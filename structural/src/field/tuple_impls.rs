@@ -1,9 +1,11 @@
 #![allow(non_snake_case)]
 
 use crate::{
-    field::{DropFields, IntoField, IntoFieldMut, IntoVariantFieldMut, MovedOutFields},
+    field::{AllFields, DropFields, IntoField, IntoFieldMut, IntoVariantFieldMut, MovedOutFields},
     path::{array_paths as names, FieldPathSet, LargePathSet},
     structural_trait::Structural,
+    type_level::TNil,
+    visit_fields::{FieldAccess, FieldInfo, FieldVisitor, VisitFields},
     StructuralExt,
 };
 
@@ -76,6 +78,10 @@ macro_rules! impl_tuple {
             }
         }
 
+        unsafe impl<$($field_ty,)*> AllFields for $tuple_ty {
+            type Names = TList![ $(names::$field_param),* ];
+        }
+
         z_impl_from_structural!{
             impl[T, $($field_ty,)* ] FromStructural<T> for $tuple_ty
             where[ T: $move_trait<$($field_ty),*>, ]
@@ -111,6 +117,39 @@ macro_rules! impl_tuple {
                 )*
         {}
 
+        impl<$($field_ty,)* V> VisitFields<V> for $tuple_ty
+        where
+            V: ?Sized $( + FieldVisitor<$field_ty> )*,
+        {
+            fn visit_fields(&self, visitor: &mut V) {
+                $(
+                    <V as FieldVisitor<$field_ty>>::visit_field(
+                        visitor,
+                        FieldInfo{
+                            variant: None,
+                            name: stringify!($field),
+                            access: FieldAccess::MutValue,
+                        },
+                        &self.$field,
+                    );
+                )*
+            }
+
+            fn visit_fields_mut(&mut self, visitor: &mut V) {
+                $(
+                    <V as FieldVisitor<$field_ty>>::visit_field_mut(
+                        visitor,
+                        FieldInfo{
+                            variant: None,
+                            name: stringify!($field),
+                            access: FieldAccess::MutValue,
+                        },
+                        &mut self.$field,
+                    );
+                )*
+            }
+        }
+
         $(
             impl_tuple!{
                 inner;
@@ -325,6 +364,18 @@ impl_tuple! {
     (C0,C1,C2,C3,C4,C5,C6,C7,C8,C9,C10,C11,)
 }
 
+unsafe impl AllFields for () {
+    type Names = TNil;
+}
+
+unsafe impl DropFields for () {
+    #[inline(always)]
+    fn pre_move(&mut self) {}
+
+    #[inline(always)]
+    unsafe fn drop_fields(&mut self, _: MovedOutFields) {}
+}
+
 z_impl_from_structural! {
     impl[T] FromStructural<T> for ()
     where[]
@@ -135,6 +135,14 @@
 ///
 /// ```
 ///
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "type `{Self}` is missing a variant field accessible by reference",
+        label = "`{V}` encodes the name of the variant,`{F}` encodes the name of the field",
+        note = "names are spelled out with one type per character, eg: `TStr<__TS<(__f, __o, __o)>>` is the `foo` name",
+    )
+)]
 pub unsafe trait GetVariantField<V, F>:
     IsVariant<V> + FieldType<VariantField<V, F>>
 {
@@ -398,6 +406,14 @@ pub type GetVariantFieldType<This, Variant, Field> =
 /// ```
 ///
 ///
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "type `{Self}` is missing a variant field accessible by mutable reference",
+        label = "`{V}` encodes the name of the variant,`{F}` encodes the name of the field",
+        note = "names are spelled out with one type per character, eg: `TStr<__TS<(__f, __o, __o)>>` is the `foo` name",
+    )
+)]
 pub unsafe trait GetVariantFieldMut<V, F>: GetVariantField<V, F> {
     /// Accesses the `F` field in the `V` variant by mutable reference.
     fn get_vfield_mut_(&mut self, variant: V, field: F) -> Option<&mut Self::Ty>;
@@ -717,6 +733,14 @@ pub type GetVFieldRawMutFn<VariantName, FieldName, FieldTy> =
 /// ```
 ///
 ///
+#[cfg_attr(
+    rust_1_78,
+    diagnostic::on_unimplemented(
+        message = "type `{Self}` is missing a variant field accessible by value",
+        label = "`{V}` encodes the name of the variant,`{F}` encodes the name of the field",
+        note = "names are spelled out with one type per character, eg: `TStr<__TS<(__f, __o, __o)>>` is the `foo` name",
+    )
+)]
 pub unsafe trait IntoVariantField<V, F>: GetVariantField<V, F> + DropFields {
     /// Converts this into the `F` field in the `V` variant by value.
     fn into_vfield_(self, variant_name: V, field_name: F)->Option<Self::Ty>;
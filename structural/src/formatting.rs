@@ -0,0 +1,93 @@
+/*!
+Types used by the [`struc_format`],[`struc_write`],and [`struc_println`] macros
+to format fields.
+
+[`struc_format`]: ../macro.struc_format.html
+[`struc_write`]: ../macro.struc_write.html
+[`struc_println`]: ../macro.struc_println.html
+
+*/
+
+use std_::fmt::{self, Binary, Debug, Display, LowerExp, LowerHex, Octal, UpperExp, UpperHex};
+
+/// A field being formatted by the [`struc_format`] family of macros,
+/// which might not exist,if it was accessed through an optional field path.
+///
+/// This implements all the formatting traits that `T` implements,
+/// formatting the fallback string when the field doesn't exist.
+///
+/// [`struc_format`]: ../macro.struc_format.html
+pub enum FormatArg<'a, T: ?Sized> {
+    /// A reference to the field.
+    Field(&'a T),
+    /// The string that's formatted when the field doesn't exist.
+    Fallback(&'a str),
+}
+
+impl<'a, T: ?Sized> Copy for FormatArg<'a, T> {}
+
+impl<'a, T: ?Sized> Clone for FormatArg<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+/// Converts the return value of [`StructuralExt::field_`] into a [`FormatArg`].
+///
+/// This is used by the [`struc_format`] family of macros,
+/// `&T` is converted into `FormatArg::Field`,
+/// and `Option<&T>` is converted into `FormatArg::Field` if it's `Some`,
+/// otherwise it's converted into `FormatArg::Fallback` with the fallback string.
+///
+/// [`StructuralExt::field_`]: ../trait.StructuralExt.html#method.field_
+/// [`FormatArg`]: ./enum.FormatArg.html
+/// [`struc_format`]: ../macro.struc_format.html
+pub trait IntoFormatArg<'a> {
+    /// The type of the field.
+    type Field: ?Sized;
+
+    /// Performs the conversion.
+    fn into_format_arg(self, fallback: &'a str) -> FormatArg<'a, Self::Field>;
+}
+
+impl<'a, T: ?Sized> IntoFormatArg<'a> for &'a T {
+    type Field = T;
+
+    #[inline(always)]
+    fn into_format_arg(self, _fallback: &'a str) -> FormatArg<'a, T> {
+        FormatArg::Field(self)
+    }
+}
+
+impl<'a, T: ?Sized> IntoFormatArg<'a> for Option<&'a T> {
+    type Field = T;
+
+    #[inline(always)]
+    fn into_format_arg(self, fallback: &'a str) -> FormatArg<'a, T> {
+        match self {
+            Some(field) => FormatArg::Field(field),
+            None => FormatArg::Fallback(fallback),
+        }
+    }
+}
+
+macro_rules! impl_fmt_traits {
+    ( $($trait_:ident)* ) => {
+        $(
+            impl<'a, T> $trait_ for FormatArg<'a, T>
+            where
+                T: ?Sized + $trait_,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    match *self {
+                        FormatArg::Field(field) => $trait_::fmt(field, f),
+                        FormatArg::Fallback(fallback) => f.pad(fallback),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_traits! { Debug Display Binary LowerExp LowerHex Octal UpperExp UpperHex }
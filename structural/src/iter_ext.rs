@@ -0,0 +1,461 @@
+/*!
+Extension traits for iterators and slices,
+to access the fields of their elements with field paths.
+
+[`StructuralIterExt`] is implemented for all iterators of references,
+and [`StructuralSliceExt`] is implemented for all slices.
+
+# Example
+
+```rust
+use structural::{StructuralIterExt, StructuralSliceExt, Structural, fp};
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public)]
+struct Sale {
+    region: &'static str,
+    product: &'static str,
+    units: u32,
+}
+
+let mut sales = vec![
+    Sale{ region: "north", product: "tea", units: 30 },
+    Sale{ region: "south", product: "coffee", units: 20 },
+    Sale{ region: "north", product: "coffee", units: 50 },
+];
+
+let total: u32 = sales.iter().sum_field(fp!(units));
+assert_eq!( total, 100 );
+
+let products = sales.iter().pluck(fp!(product)).collect::<Vec<_>>();
+assert_eq!( products, vec![&"tea", &"coffee", &"coffee"] );
+
+let best = sales.iter().max_by_field(fp!(units)).unwrap();
+assert_eq!( best.region, "north" );
+
+for units in sales.iter_mut().pluck_mut(fp!(units)) {
+    *units *= 2;
+}
+
+sales.sort_by_fields(fp!(region, units));
+assert_eq!( sales.iter().pluck_cloned(fp!(units)).collect::<Vec<_>>(), vec![60, 100, 40] );
+
+assert_eq!( sales.binary_search_by_field(fp!(region), &"south"), Ok(2) );
+
+```
+
+*/
+
+use crate::{
+    field::{
+        InfallibleAccess, NormalizeFields, NormalizeFieldsOut, RevGetField, RevGetFieldImpl,
+        RevGetFieldMutImpl, RevGetFieldType,
+    },
+    StructuralExt,
+};
+
+#[cfg(feature = "alloc")]
+use crate::field::{RevGetMultiField, RevGetMultiFieldOut};
+
+use core_extensions::collection_traits::{Cloned, ClonedOut};
+
+use std_::iter::Sum;
+
+#[cfg(feature = "alloc")]
+use crate::alloc::{collections::BTreeMap, vec::Vec};
+
+/// Extension trait for iterators of references,
+/// to access a field of every element with a field path.
+///
+/// The methods of this trait are only callable on iterators of
+/// references (`&'a T`),except for [`pluck_mut`],
+/// which is only callable on iterators of mutable references (`&'a mut T`).
+///
+/// For examples of all the methods,look at the [module-level documentation](./index.html).
+///
+/// [`pluck_mut`]: #method.pluck_mut
+pub trait StructuralIterExt: Iterator {
+    /// Returns an iterator over references to the field at `path` of every element.
+    ///
+    /// Like [`StructuralExt::field_`],this returns `Option<&_>` for optional fields
+    /// (generally fields inside enums).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralIterExt, fp};
+    ///
+    /// let list = vec![(3, "foo"), (5, "bar")];
+    ///
+    /// assert_eq!( list.iter().pluck(fp!(1)).collect::<Vec<_>>(), vec![&"foo", &"bar"] );
+    ///
+    /// let options = vec![Some((3, 5)), None, Some((8, 13))];
+    /// assert_eq!(
+    ///     options.iter().pluck(fp!(?.0)).collect::<Vec<_>>(),
+    ///     vec![Some(&3), None, Some(&8)],
+    /// );
+    ///
+    /// ```
+    ///
+    /// [`StructuralExt::field_`]: ../trait.StructuralExt.html#method.field_
+    #[inline(always)]
+    fn pluck<'a, T, P>(self, path: P) -> Pluck<Self, P>
+    where
+        Self: Sized + Iterator<Item = &'a T>,
+        T: 'a + ?Sized,
+        P: RevGetFieldImpl<'a, T> + Copy,
+        P::Ty: 'a,
+        Result<&'a P::Ty, P::Err>: NormalizeFields,
+    {
+        Pluck { iter: self, path }
+    }
+
+    /// Returns an iterator over clones of the field at `path` of every element.
+    ///
+    /// Like [`StructuralExt::field_`],this returns `Option<_>` for optional fields
+    /// (generally fields inside enums).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralIterExt, fp};
+    ///
+    /// let list = vec![("foo".to_string(), 3), ("bar".to_string(), 5)];
+    ///
+    /// assert_eq!(
+    ///     list.iter().pluck_cloned(fp!(0)).collect::<Vec<String>>(),
+    ///     vec!["foo".to_string(), "bar".to_string()],
+    /// );
+    ///
+    /// ```
+    ///
+    /// [`StructuralExt::field_`]: ../trait.StructuralExt.html#method.field_
+    #[inline(always)]
+    fn pluck_cloned<'a, T, P>(self, path: P) -> PluckCloned<Self, P>
+    where
+        Self: Sized + Iterator<Item = &'a T>,
+        T: 'a + ?Sized,
+        P: RevGetFieldImpl<'a, T> + Copy,
+        P::Ty: 'a,
+        Result<&'a P::Ty, P::Err>: NormalizeFields,
+        NormalizeFieldsOut<Result<&'a P::Ty, P::Err>>: Cloned,
+    {
+        PluckCloned { iter: self, path }
+    }
+
+    /// Returns an iterator over mutable references to the field at `path` of every element.
+    ///
+    /// Like [`StructuralExt::field_mut`],this returns `Option<&mut _>` for optional fields
+    /// (generally fields inside enums).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralIterExt, fp};
+    ///
+    /// let mut list = vec![(3, 'a'), (5, 'b')];
+    ///
+    /// for x in list.iter_mut().pluck_mut(fp!(0)) {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!( list, vec![(30, 'a'), (50, 'b')] );
+    ///
+    /// ```
+    ///
+    /// [`StructuralExt::field_mut`]: ../trait.StructuralExt.html#method.field_mut
+    #[inline(always)]
+    fn pluck_mut<'a, T, P>(self, path: P) -> PluckMut<Self, P>
+    where
+        Self: Sized + Iterator<Item = &'a mut T>,
+        T: 'a + ?Sized,
+        P: RevGetFieldMutImpl<'a, T> + Copy,
+        P::Ty: 'a,
+        Result<&'a mut P::Ty, P::Err>: NormalizeFields,
+    {
+        PluckMut { iter: self, path }
+    }
+
+    /// Sums the field at `path` of every element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralIterExt, fp};
+    ///
+    /// let list = vec![("apple", 3.5), ("pear", 1.5)];
+    ///
+    /// assert_eq!( list.iter().sum_field::<_, _, f64>(fp!(1)), 5.0 );
+    ///
+    /// ```
+    #[inline]
+    fn sum_field<'a, T, P, S>(self, path: P) -> S
+    where
+        Self: Sized + Iterator<Item = &'a T>,
+        T: 'a + ?Sized,
+        P: RevGetFieldImpl<'a, T, Err = InfallibleAccess> + Copy,
+        P::Ty: 'a,
+        S: Sum<&'a P::Ty>,
+    {
+        self.map(move |x| x.field_(path)).sum()
+    }
+
+    /// Returns the element with the maximum value in the field at `path`.
+    ///
+    /// If there are multiple equally maximum elements,this returns the last one,
+    /// like `Iterator::max_by_key` does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralIterExt, fp};
+    ///
+    /// let list = vec![("foo", 3), ("bar", 8), ("baz", 5)];
+    ///
+    /// assert_eq!( list.iter().max_by_field(fp!(1)), Some(&("bar", 8)) );
+    /// assert_eq!( list.iter().max_by_field(fp!(0)), Some(&("foo", 3)) );
+    ///
+    /// ```
+    #[inline]
+    fn max_by_field<'a, T, P>(self, path: P) -> Option<&'a T>
+    where
+        Self: Sized + Iterator<Item = &'a T>,
+        T: 'a + ?Sized,
+        P: RevGetFieldImpl<'a, T, Err = InfallibleAccess> + Copy,
+        P::Ty: 'a,
+        P::Ty: Ord,
+    {
+        self.max_by_key(move |x| (*x).field_(path))
+    }
+
+    /// Groups the elements by the value of the field at `path`,
+    /// preserving the order of elements inside each group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralIterExt, fp};
+    ///
+    /// let list = vec![("fruit", "apple"), ("vegetable", "leek"), ("fruit", "pear")];
+    ///
+    /// let groups = list.iter().group_by_field(fp!(0));
+    ///
+    /// assert_eq!( groups.len(), 2 );
+    /// assert_eq!( groups["fruit"], vec![&("fruit", "apple"), &("fruit", "pear")] );
+    /// assert_eq!( groups["vegetable"], vec![&("vegetable", "leek")] );
+    ///
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn group_by_field<'a, T, P>(self, path: P) -> BTreeMap<P::Ty, Vec<&'a T>>
+    where
+        Self: Sized + Iterator<Item = &'a T>,
+        T: 'a + ?Sized,
+        P: RevGetFieldImpl<'a, T, Err = InfallibleAccess> + Copy,
+        P::Ty: 'a,
+        P::Ty: Ord + Clone,
+    {
+        let mut map = BTreeMap::<P::Ty, Vec<&'a T>>::new();
+        for elem in self {
+            map.entry(elem.field_(path).clone())
+                .or_insert_with(Vec::new)
+                .push(elem);
+        }
+        map
+    }
+}
+
+impl<I: ?Sized + Iterator> StructuralIterExt for I {}
+
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! declare_pluck_iter {
+    (
+        $(#[$attr:meta])*
+        struct $name:ident;
+        item = $elem:ty;
+        bounds = ($($bounds:tt)*);
+        output = $output:ty;
+        map = |$path:ident, $this:ident| $map:expr;
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone)]
+        pub struct $name<I, P> {
+            iter: I,
+            path: P,
+        }
+
+        impl<'a, I, T, P> Iterator for $name<I, P>
+        where
+            I: Iterator<Item = $elem>,
+            T: 'a + ?Sized,
+            $($bounds)*
+        {
+            type Item = $output;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let $path = self.path;
+                self.iter.next().map(|$this| $map)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<'a, I, T, P> DoubleEndedIterator for $name<I, P>
+        where
+            I: DoubleEndedIterator<Item = $elem>,
+            T: 'a + ?Sized,
+            $($bounds)*
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let $path = self.path;
+                self.iter.next_back().map(|$this| $map)
+            }
+        }
+
+        impl<'a, I, T, P> ExactSizeIterator for $name<I, P>
+        where
+            I: ExactSizeIterator<Item = $elem>,
+            T: 'a + ?Sized,
+            $($bounds)*
+        {}
+    };
+}
+
+declare_pluck_iter! {
+    /// An iterator over references to a field of every element in `I`.
+    ///
+    /// This is returned by the
+    /// [`StructuralIterExt::pluck`](./trait.StructuralIterExt.html#method.pluck) method.
+    struct Pluck;
+    item = &'a T;
+    bounds = (
+        P: RevGetFieldImpl<'a, T> + Copy,
+        P::Ty: 'a,
+        Result<&'a P::Ty, P::Err>: NormalizeFields,
+    );
+    output = NormalizeFieldsOut<Result<&'a P::Ty, P::Err>>;
+    map = |path, this| path.rev_get_field(this).normalize_fields();
+}
+
+declare_pluck_iter! {
+    /// An iterator over clones of a field of every element in `I`.
+    ///
+    /// This is returned by the
+    /// [`StructuralIterExt::pluck_cloned`](./trait.StructuralIterExt.html#method.pluck_cloned)
+    /// method.
+    struct PluckCloned;
+    item = &'a T;
+    bounds = (
+        P: RevGetFieldImpl<'a, T> + Copy,
+        P::Ty: 'a,
+        Result<&'a P::Ty, P::Err>: NormalizeFields,
+        NormalizeFieldsOut<Result<&'a P::Ty, P::Err>>: Cloned,
+    );
+    output = ClonedOut<NormalizeFieldsOut<Result<&'a P::Ty, P::Err>>>;
+    map = |path, this| path.rev_get_field(this).normalize_fields().cloned_();
+}
+
+declare_pluck_iter! {
+    /// An iterator over mutable references to a field of every element in `I`.
+    ///
+    /// This is returned by the
+    /// [`StructuralIterExt::pluck_mut`](./trait.StructuralIterExt.html#method.pluck_mut)
+    /// method.
+    struct PluckMut;
+    item = &'a mut T;
+    bounds = (
+        P: RevGetFieldMutImpl<'a, T> + Copy,
+        P::Ty: 'a,
+        Result<&'a mut P::Ty, P::Err>: NormalizeFields,
+    );
+    output = NormalizeFieldsOut<Result<&'a mut P::Ty, P::Err>>;
+    map = |path, this| path.rev_get_field_mut(this).normalize_fields();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Extension trait for slices,to sort and search them by the fields of their elements.
+///
+/// These methods are implemented with the `sort_by`/`binary_search_by` slice methods,
+/// whose comparison closures borrow the elements for an unnamed lifetime,
+/// so the field path bounds are required for every lifetime,
+/// which means that `T` can't contain non-`'static` references.
+///
+/// For examples of all the methods,look at the [module-level documentation](./index.html).
+pub trait StructuralSliceExt<T> {
+    /// Sorts the slice by the fields at `path`,
+    /// comparing them as a tuple in the order that they're listed in the path.
+    ///
+    /// This sort is stable (it doesn't reorder equal elements).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralSliceExt, fp};
+    ///
+    /// let mut list = vec![(3, 'b', 0), (1, 'b', 1), (2, 'a', 2), (1, 'b', 3)];
+    ///
+    /// list.sort_by_fields(fp!(1, 0));
+    /// assert_eq!( list, vec![(2, 'a', 2), (1, 'b', 1), (1, 'b', 3), (3, 'b', 0)] );
+    ///
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sort_by_fields<P>(&mut self, path: P)
+    where
+        P: for<'a> RevGetMultiField<'a, T> + Copy,
+        for<'a> RevGetMultiFieldOut<'a, P, T>: Ord;
+
+    /// Binary searches the slice for an element with `value` in the field at `path`,
+    /// expecting the slice to be sorted by that field.
+    ///
+    /// This returns the same values as the `binary_search` slice method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralSliceExt, fp};
+    ///
+    /// let list = vec![(1, "foo"), (3, "bar"), (8, "baz")];
+    ///
+    /// assert_eq!( list.binary_search_by_field(fp!(0), &3), Ok(1) );
+    /// assert_eq!( list.binary_search_by_field(fp!(0), &5), Err(2) );
+    ///
+    /// ```
+    fn binary_search_by_field<P>(
+        &self,
+        path: P,
+        value: &RevGetFieldType<P, T>,
+    ) -> Result<usize, usize>
+    where
+        P: for<'a> RevGetField<'a, T> + Copy,
+        RevGetFieldType<P, T>: Ord;
+}
+
+impl<T> StructuralSliceExt<T> for [T] {
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn sort_by_fields<P>(&mut self, path: P)
+    where
+        P: for<'a> RevGetMultiField<'a, T> + Copy,
+        for<'a> RevGetMultiFieldOut<'a, P, T>: Ord,
+    {
+        self.sort_by(|l, r| l.fields(path).cmp(&r.fields(path)));
+    }
+
+    #[inline]
+    fn binary_search_by_field<P>(
+        &self,
+        path: P,
+        value: &RevGetFieldType<P, T>,
+    ) -> Result<usize, usize>
+    where
+        P: for<'a> RevGetField<'a, T> + Copy,
+        RevGetFieldType<P, T>: Ord,
+    {
+        self.binary_search_by(|elem| elem.field_(path).cmp(value))
+    }
+}
@@ -0,0 +1,349 @@
+use crate::field::{RevGetMultiField, RevGetMultiFieldOut};
+
+use std_::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+/// A wrapper that implements `Hash`,`PartialEq`,`Eq`,`PartialOrd`,and `Ord`
+/// by only using the fields that the `P` path refers to.
+///
+/// This can be used as a key in `HashMap`s/`BTreeMap`s,
+/// or to deduplicate values by some of their fields.
+///
+/// The fields are compared as a tuple,in the order that they're listed in the path,
+/// and hashed the same way as [`StructuralExt::hash_fields`] does.
+///
+/// The trait impls of this type require the fields to be borrowable for any lifetime,
+/// which means that `T` can't contain non-`'static` references.
+/// [`KeyedByRef`] (which can be gotten with [`as_keyed_ref`](#method.as_keyed_ref))
+/// wraps a reference instead,which allows `T` to contain non-`'static` references.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{KeyedBy, Structural, FP, fp};
+///
+/// use std::collections::{BTreeSet, HashMap};
+///
+/// #[derive(Structural, Debug, Clone)]
+/// #[struc(public)]
+/// struct Row {
+///     country: String,
+///     city: String,
+///     population: u32,
+/// }
+///
+/// fn row(country: &str, city: &str, population: u32) -> Row {
+///     Row{ country: country.to_string(), city: city.to_string(), population }
+/// }
+///
+/// let rows = vec![
+///     row("Spain", "Madrid", 3_200_000),
+///     row("Spain", "Madrid", 3_300_000),
+///     row("Japan", "Tokyo", 13_900_000),
+///     row("Spain", "Barcelona", 1_600_000),
+/// ];
+///
+/// // Keeping the last row for each location
+/// let mut latest = HashMap::new();
+/// for row in rows.clone() {
+///     let population = row.population;
+///     latest.insert(KeyedBy::new(row, fp!(country, city)), population);
+/// }
+/// assert_eq!( latest.len(), 3 );
+/// assert_eq!( latest[&KeyedBy::new(row("Spain", "Madrid", 0), fp!(country, city))], 3_300_000 );
+///
+/// // Sorting rows by their location
+/// let sorted: BTreeSet<KeyedBy<Row, FP!(country, city)>> = rows.into_iter()
+///     .map(|row| KeyedBy::new(row, fp!(country, city)) )
+///     .collect();
+///
+/// let cities = sorted.iter().map(|row| &*row.city ).collect::<Vec<&str>>();
+/// assert_eq!( cities, vec!["Tokyo", "Barcelona", "Madrid"] );
+///
+/// ```
+///
+/// [`StructuralExt::hash_fields`]: ./trait.StructuralExt.html#method.hash_fields
+/// [`KeyedByRef`]: ./struct.KeyedByRef.html
+#[derive(Debug, Copy, Clone)]
+pub struct KeyedBy<T, P> {
+    value: T,
+    path: P,
+}
+
+impl<T, P> KeyedBy<T, P> {
+    /// Constructs a `KeyedBy`,using the fields that `path` refers to as the key.
+    #[inline(always)]
+    pub const fn new(value: T, path: P) -> Self {
+        Self { value, path }
+    }
+
+    /// Unwraps this into the wrapped value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Gets the path to the fields used as the key.
+    #[inline(always)]
+    pub fn path(&self) -> &P {
+        &self.path
+    }
+
+    /// Gets references to the fields used as the key.
+    #[inline(always)]
+    pub fn key<'a>(&'a self) -> RevGetMultiFieldOut<'a, P, T>
+    where
+        P: RevGetMultiField<'a, T> + Copy,
+    {
+        self.path.rev_get_multi_field(&self.value)
+    }
+
+    /// Gets a [`KeyedByRef`] that borrows the wrapped value.
+    ///
+    /// [`KeyedByRef`]: ./struct.KeyedByRef.html
+    #[inline(always)]
+    pub fn as_keyed_ref(&self) -> KeyedByRef<'_, T, P>
+    where
+        P: Copy,
+    {
+        KeyedByRef::new(&self.value, self.path)
+    }
+}
+
+impl<T, P> Deref for KeyedBy<T, P> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, P> AsRef<T> for KeyedBy<T, P> {
+    #[inline(always)]
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, P> Hash for KeyedBy<T, P>
+where
+    P: for<'a> RevGetMultiField<'a, T> + Copy,
+    for<'a> RevGetMultiFieldOut<'a, P, T>: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+impl<T, P> PartialEq for KeyedBy<T, P>
+where
+    P: for<'a> RevGetMultiField<'a, T> + Copy,
+    for<'a> RevGetMultiFieldOut<'a, P, T>: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<T, P> Eq for KeyedBy<T, P>
+where
+    P: for<'a> RevGetMultiField<'a, T> + Copy,
+    for<'a> RevGetMultiFieldOut<'a, P, T>: Eq,
+{
+}
+
+impl<T, P> PartialOrd for KeyedBy<T, P>
+where
+    P: for<'a> RevGetMultiField<'a, T> + Copy,
+    for<'a> RevGetMultiFieldOut<'a, P, T>: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.key().partial_cmp(&other.key())
+    }
+}
+
+impl<T, P> Ord for KeyedBy<T, P>
+where
+    P: for<'a> RevGetMultiField<'a, T> + Copy,
+    for<'a> RevGetMultiFieldOut<'a, P, T>: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The borrowed form of [`KeyedBy`],
+/// a wrapper around a reference that implements `Hash`,`PartialEq`,`Eq`,`PartialOrd`,and `Ord`
+/// by only using the fields that the `P` path refers to.
+///
+/// This wraps a `&'a T` (instead of a `T`) so that the trait impls can borrow the fields
+/// for the `'a` lifetime,
+/// which allows `T` to contain non-`'static` references.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{KeyedByRef, Structural, FP, fp};
+///
+/// use std::collections::{BTreeSet, HashSet};
+///
+/// #[derive(Structural, Debug, Clone)]
+/// #[struc(public)]
+/// struct Row<'a> {
+///     country: &'a str,
+///     city: &'a str,
+///     population: u32,
+/// }
+///
+/// let spain = String::from("Spain");
+///
+/// let rows = vec![
+///     Row{ country: &spain, city: "Madrid", population: 3_200_000 },
+///     Row{ country: &spain, city: "Madrid", population: 3_300_000 },
+///     Row{ country: "Japan", city: "Tokyo", population: 13_900_000 },
+///     Row{ country: &spain, city: "Barcelona", population: 1_600_000 },
+/// ];
+///
+/// // Deduplicating rows by their location
+/// let mut seen = HashSet::new();
+/// let deduped = rows.iter()
+///     .filter(|row| seen.insert(KeyedByRef::new(*row, fp!(country, city))) )
+///     .map(|row| row.population )
+///     .collect::<Vec<u32>>();
+///
+/// assert_eq!( deduped, vec![3_200_000, 13_900_000, 1_600_000] );
+///
+/// // Sorting rows by their location
+/// let sorted: BTreeSet<KeyedByRef<'_, Row<'_>, FP!(country, city)>> = rows.iter()
+///     .map(|row| KeyedByRef::new(row, fp!(country, city)) )
+///     .collect();
+///
+/// let cities = sorted.iter().map(|row| row.city ).collect::<Vec<_>>();
+/// assert_eq!( cities, vec!["Tokyo", "Barcelona", "Madrid"] );
+///
+/// ```
+///
+/// [`KeyedBy`]: ./struct.KeyedBy.html
+#[derive(Debug)]
+pub struct KeyedByRef<'a, T: ?Sized, P> {
+    value: &'a T,
+    path: P,
+}
+
+impl<'a, T: ?Sized, P> KeyedByRef<'a, T, P> {
+    /// Constructs a `KeyedBy`,using the fields that `path` refers to as the key.
+    #[inline(always)]
+    pub const fn new(value: &'a T, path: P) -> Self {
+        Self { value, path }
+    }
+
+    /// Unwraps this into the wrapped reference.
+    #[inline(always)]
+    pub fn into_inner(self) -> &'a T {
+        self.value
+    }
+
+    /// Gets the path to the fields used as the key.
+    #[inline(always)]
+    pub fn path(&self) -> &P {
+        &self.path
+    }
+
+    /// Gets references to the fields used as the key.
+    #[inline(always)]
+    pub fn key(&self) -> RevGetMultiFieldOut<'a, P, T>
+    where
+        P: RevGetMultiField<'a, T> + Copy,
+    {
+        self.path.rev_get_multi_field(self.value)
+    }
+}
+
+impl<'a, T: ?Sized, P: Clone> Clone for KeyedByRef<'a, T, P> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value,
+            path: self.path.clone(),
+        }
+    }
+}
+
+impl<'a, T: ?Sized, P: Copy> Copy for KeyedByRef<'a, T, P> {}
+
+impl<'a, T: ?Sized, P> Deref for KeyedByRef<'a, T, P> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<'a, T: ?Sized, P> AsRef<T> for KeyedByRef<'a, T, P> {
+    #[inline(always)]
+    fn as_ref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<'a, T: ?Sized, P> Hash for KeyedByRef<'a, T, P>
+where
+    P: RevGetMultiField<'a, T> + Copy,
+    RevGetMultiFieldOut<'a, P, T>: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+impl<'a, T: ?Sized, P> PartialEq for KeyedByRef<'a, T, P>
+where
+    P: RevGetMultiField<'a, T> + Copy,
+    RevGetMultiFieldOut<'a, P, T>: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<'a, T: ?Sized, P> Eq for KeyedByRef<'a, T, P>
+where
+    P: RevGetMultiField<'a, T> + Copy,
+    RevGetMultiFieldOut<'a, P, T>: Eq,
+{
+}
+
+impl<'a, T: ?Sized, P> PartialOrd for KeyedByRef<'a, T, P>
+where
+    P: RevGetMultiField<'a, T> + Copy,
+    RevGetMultiFieldOut<'a, P, T>: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.key().partial_cmp(&other.key())
+    }
+}
+
+impl<'a, T: ?Sized, P> Ord for KeyedByRef<'a, T, P>
+where
+    P: RevGetMultiField<'a, T> + Copy,
+    RevGetMultiFieldOut<'a, P, T>: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
@@ -623,7 +623,6 @@ struct Cents(u64);
 [`TryFromStructural`]: ./convert/trait.TryFromStructural.html
 
 */
-#![cfg_attr(feature = "nightly_impl_fields", feature(associated_type_bounds))]
 #![cfg_attr(feature = "nightly_specialization", feature(specialization))]
 #![cfg_attr(
     all(feature = "nightly_use_const_str", not(feature = "disable_const_str")),
@@ -675,29 +674,55 @@ pub use structural_derive::Structural;
 #[doc(hidden)]
 pub use structural_derive::{
     _FP_impl_, _FP_literal_, _TStr_ident_impl_, _TStr_impl_, _TStr_lit_impl_,
-    _field_path_aliases_impl, _impl_struct_impl, _switch_tstring_aliases, _tstring_aliases_impl,
-    structural_alias_impl,
+    _field_path_aliases_impl, _impl_struct_impl, _remote_structural_alias, _struc_format_impl,
+    _switch_tstring_aliases, _tstring_aliases_impl, structural_alias_impl,
 };
 
 #[macro_use]
 mod macros;
 
+pub mod builder;
 #[doc(hidden)]
 #[cfg(all(feature = "use_const_str", not(feature = "disable_const_str")))]
 pub mod const_generic_utils;
 pub mod convert;
+#[cfg(all(
+    feature = "alloc",
+    any(not(feature = "use_const_str"), feature = "disable_const_str")
+))]
+pub mod csv;
+pub mod diff;
 pub mod docs;
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+pub mod encoding;
 pub mod enums;
 pub mod field;
 mod field_cloner;
+pub mod formatting;
+pub mod iter_ext;
+mod keyed_by;
 #[doc(hidden)]
 pub mod msg;
 pub mod path;
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+pub mod record;
+#[cfg(feature = "alloc")]
+pub mod soa;
+#[cfg(all(
+    feature = "alloc",
+    any(not(feature = "use_const_str"), feature = "disable_const_str")
+))]
+pub mod str_map;
 pub mod structural_aliases;
 mod structural_ext;
 mod structural_trait;
+mod tracked;
 pub mod type_level;
+pub mod uninit_builder;
 pub mod utils;
+#[cfg(feature = "alloc")]
+pub mod validate;
+pub mod visit_fields;
 mod wrapper;
 
 #[doc(hidden)]
@@ -717,8 +742,13 @@ pub use crate::{
         IntoFieldMut, IntoVariantField, IntoVariantFieldMut,
     },
     field_cloner::FieldCloner,
+    iter_ext::{StructuralIterExt, StructuralSliceExt},
+    keyed_by::{KeyedBy, KeyedByRef},
     structural_ext::StructuralExt,
     structural_trait::Structural,
+    tracked::{DirtyFields, Tracked},
+    uninit_builder::UninitBuilder,
+    visit_fields::{FieldInfo, FieldVisitor, VisitFields},
     wrapper::StrucWrapper,
 };
 
@@ -748,25 +778,36 @@ pub mod pmr {
     pub use crate::convert::*;
     pub use crate::enums::variant_count::*;
     pub use crate::enums::*;
+    pub use crate::field::field_offset::next_field_offset;
     pub use crate::field::ownership::*;
     pub use crate::field::*;
+    pub use crate::formatting::IntoFormatArg;
     pub use crate::path::*;
     pub use crate::type_level::collection_traits::*;
     pub use crate::type_level::*;
-    pub use crate::utils::{RunOnDrop, _Structural_BorrowSelf, as_phantomdata};
+    pub use crate::utils::{
+        _Structural_BorrowSelf, _Structural_DelegatedFieldMut, _Structural_DelegatedIntoField,
+        _Structural_PinnedField, as_phantomdata, RunOnDrop,
+    };
     pub use core_extensions::{ConstDefault, MarkerType};
 
     pub use crate::std_::{
-        convert::Infallible,
+        clone::Clone,
+        convert::{From, Infallible},
+        default::Default,
+        fmt::Display,
         format_args,
-        marker::PhantomData,
+        marker::{PhantomData, Unpin},
         mem::{drop, forget},
+        ops::Drop,
         option::Option::{self, None, Some},
         ptr::{drop_in_place, NonNull},
+        result::Result::{self, Err, Ok},
+        str::FromStr,
     };
 
     #[cfg(feature = "alloc")]
-    pub use crate::alloc::boxed::Box;
+    pub use crate::alloc::{boxed::Box, vec::Vec};
 }
 
 /// Structural-deriving types used in examples,
@@ -4,6 +4,9 @@ mod delegate_structural;
 #[macro_use]
 mod drop_fields;
 
+#[macro_use]
+mod encoding;
+
 #[macro_use]
 mod enum_derivation;
 
@@ -25,6 +28,15 @@ mod list;
 #[macro_use]
 mod make_struct;
 
+#[macro_use]
+mod record;
+
+#[macro_use]
+mod remote_structural;
+
+#[macro_use]
+mod struc_format;
+
 #[macro_use]
 mod structural_alias;
 
@@ -40,6 +52,9 @@ mod tstr_macros;
 #[macro_use]
 mod type_level_internal;
 
+#[macro_use]
+mod visit_fields;
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! default_if {
@@ -2,7 +2,8 @@
 This macro allows delegating the implementation of the accessor traits.
 
 This macro delegates the implementation of those traits for all fields,
-it doesn't provide a way to do so for only a list of fields.
+[the `delegate_fields` form](#partial-delegation) of this macro
+delegates only a list of fields to each member of a struct.
 
 # Safety
 
@@ -356,6 +357,148 @@ unsafe_delegate_structural_with!{
 ```
 
 
+<span id="partial-delegation"></span>
+# Partial delegation
+
+The `delegate_fields` form of this macro delegates the
+[`GetField`] and [`GetFieldMut`] impls for the listed fields
+to a member of the struct,
+requiring the type of that member to implement those traits.
+
+This form doesn't implement the [`Structural`] trait,
+so that it can be combined with the `Structural` derive,
+or with other impls of the accessor traits for other fields.
+
+By-value access ([`IntoField`]) is delegated when the
+`IntoField(moved_bits = <start>..<end>)` argument is passed after the member type,
+in which case the fields moved out of the member are tracked with the
+`<start>..<end>` range of bits of the [`MovedOutFields`] of the type
+(with [`MovedOutFields::range`] and [`MovedOutFields::set_range`]).
+If the member needs more bits than that to track its moved out fields,
+moving out a field panics,leaving that field in the member to be dropped with it.
+
+Its safety requirements are the same as the ones for the rest of this macro,
+with the additional requirement that
+the members that fields are delegated to must not be accessible through
+`GetFieldMut` impls of the same type,
+since that would allow aliasing mutable references.
+
+When by-value access is delegated,the [`DropFields`] impl of the type must:
+
+- Call [`DropFields::pre_move`] on the member in its `pre_move` method.
+
+- Drop the member by calling [`DropFields::drop_fields`] on it,
+passing `moved.range(<start>, <end> - <start>)`,
+instead of dropping it with its destructor.
+
+- Not use the bits in the `<start>..<end>` range to track its own fields,
+nor use them for other members.
+
+[`GetField`]: ./field/trait.GetField.html
+[`GetFieldMut`]: ./field/trait.GetFieldMut.html
+[`IntoField`]: ./field/trait.IntoField.html
+[`Structural`]: ./trait.Structural.html
+[`DropFields`]: ./field/ownership/trait.DropFields.html
+[`DropFields::pre_move`]: ./field/ownership/trait.DropFields.html#tymethod.pre_move
+[`DropFields::drop_fields`]: ./field/ownership/trait.DropFields.html#tymethod.drop_fields
+[`MovedOutFields`]: ./field/ownership/struct.MovedOutFields.html
+[`MovedOutFields::range`]: ./field/ownership/struct.MovedOutFields.html#method.range
+[`MovedOutFields::set_range`]: ./field/ownership/struct.MovedOutFields.html#method.set_range
+
+### Example
+
+```rust
+use structural::{StructuralExt, Structural, fp, unsafe_delegate_structural_with};
+
+#[derive(Structural)]
+#[struc(public)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Structural)]
+#[struc(public)]
+struct Health {
+    hp: u32,
+    max_hp: u32,
+}
+
+struct Player {
+    name: String,
+    pos: Position,
+    health: Health,
+}
+
+unsafe_delegate_structural_with!{
+    impl[] Player
+    where[]
+
+    delegate_fields(fp!(x, y) => pos: Position);
+    delegate_fields(fp!(hp) => health: Health);
+}
+
+let mut player = Player{
+    name: "foo".to_string(),
+    pos: Position{ x: 1.0, y: 2.0 },
+    health: Health{ hp: 5, max_hp: 10 },
+};
+
+let (x, hp) = player.fields_mut(fp!(x, hp));
+*x += 10.0;
+*hp += 3;
+
+assert_eq!( player.fields(fp!(x, y, hp)), (&11.0, &2.0, &8) );
+assert_eq!( player.name, "foo" );
+assert_eq!( player.health.max_hp, 10 );
+
+```
+
+### By-value example
+
+```rust
+use structural::{
+    field::{DropFields, MovedOutFields},
+    StructuralExt, Structural, fp, unsafe_delegate_structural_with,
+};
+
+#[derive(Structural)]
+#[struc(public)]
+struct Names {
+    first: String,
+    last: String,
+}
+
+struct Person {
+    names: Names,
+    age: u32,
+}
+
+unsafe_delegate_structural_with!{
+    impl[] Person
+    where[]
+
+    delegate_fields(fp!(first, last) => names: Names, IntoField(moved_bits = 0..8));
+}
+
+unsafe impl DropFields for Person {
+    fn pre_move(&mut self) {
+        self.names.pre_move();
+    }
+
+    unsafe fn drop_fields(&mut self, moved: MovedOutFields) {
+        self.names.drop_fields(moved.range(0, 8));
+    }
+}
+
+let person = Person{
+    names: Names{ first: "foo".to_string(), last: "bar".to_string() },
+    age: 13,
+};
+assert_eq!( person.into_fields(fp!(last, first)), ("bar".to_string(), "foo".to_string()) );
+
+```
+
 */
 #[macro_export]
 macro_rules! unsafe_delegate_structural_with {
@@ -379,12 +522,180 @@ macro_rules! unsafe_delegate_structural_with {
 
             $($rest)*
         }
-    )
+    );
+    (
+        impl $impl_params:tt $self:ty
+        where $where_clause:tt
+
+        $(
+            delegate_fields(
+                fp!( $($fname:tt),* $(,)? ) => $member:tt : $member_ty:ty
+                $(, $($into_field:tt)* )?
+            );
+        )*
+    ) => (
+        $(
+            $crate::unsafe_delegate_structural_with_inner!{
+                @delegate_fields
+                impl $impl_params $self
+                where $where_clause
+                fields( $($fname)* ) => $member: $member_ty;
+                ( $( $($into_field)* )? )
+            }
+        )*
+    );
 }
 
 #[macro_export]
 #[doc(hidden)]
 macro_rules! unsafe_delegate_structural_with_inner {
+    (
+        @delegate_fields
+        impl $impl_params:tt $self:ty
+        where $where_clause:tt
+        fields( $($fname:tt)* ) => $member:tt : $member_ty:ty;
+        $into_field:tt
+    ) => (
+        $(
+            $crate::unsafe_delegate_structural_with_inner!{
+                @delegate_field
+                impl $impl_params $self
+                where $where_clause
+                $fname => $member: $member_ty;
+                $into_field
+            }
+        )*
+    );
+    (
+        @delegate_field
+        impl[$($impl_params:tt)*] $self:ty
+        where[$($where_clause:tt)*]
+        $fname:tt => $member:tt : $member_ty:ty;
+        ( IntoField( moved_bits = $start:literal .. $end:literal ) )
+    ) => (
+        $crate::unsafe_delegate_structural_with_inner!{
+            @delegate_field
+            impl[$($impl_params)*] $self
+            where[$($where_clause)*]
+            $fname => $member: $member_ty;
+            ()
+        }
+
+        unsafe impl<'__struc_delegated, $($impl_params)*>
+            $crate::IntoField<$crate::TS!($fname)>
+        for $self
+        where
+            $member_ty: $crate::GetField<$crate::TS!($fname)>,
+            $member_ty: $crate::pmr::_Structural_DelegatedIntoField<
+                '__struc_delegated,
+                $crate::TS!($fname),
+                Ty = $crate::GetFieldType<$member_ty, $crate::TS!($fname)>,
+            >,
+            Self: $crate::pmr::DropFields,
+            $($where_clause)*
+        {
+            #[inline(always)]
+            fn into_field_(self, fname: $crate::TS!($fname)) -> Self::Ty {
+                $crate::pmr::_Structural_DelegatedIntoField::into_field_(self.$member, fname)
+            }
+
+            #[inline(always)]
+            unsafe fn move_out_field_(
+                &mut self,
+                fname: $crate::TS!($fname),
+                moved: &mut $crate::pmr::MovedOutFields,
+            ) -> Self::Ty {
+                const __START: u8 = $start;
+                const __LEN: u8 = $end - $start;
+                let mut member_moved = moved.range(__START, __LEN);
+                let value = $crate::pmr::_Structural_DelegatedIntoField::move_out_field_(
+                    &mut self.$member,
+                    fname,
+                    &mut member_moved,
+                );
+                if !moved.set_range(__START, __LEN, member_moved) {
+                    // The field is still dropped by the member,
+                    // since it isn't marked as moved out.
+                    $crate::pmr::forget(value);
+                    panic!(
+                        "The fields moved out of the `{}` field can't be tracked with {} bits",
+                        stringify!($member),
+                        __LEN,
+                    );
+                }
+                value
+            }
+        }
+    );
+    (
+        @delegate_field
+        impl[$($impl_params:tt)*] $self:ty
+        where[$($where_clause:tt)*]
+        $fname:tt => $member:tt : $member_ty:ty;
+        ()
+    ) => (
+        impl<$($impl_params)*> $crate::FieldType<$crate::TS!($fname)> for $self
+        where
+            $member_ty: $crate::FieldType<$crate::TS!($fname)>,
+            $($where_clause)*
+        {
+            type Ty = $crate::GetFieldType<$member_ty, $crate::TS!($fname)>;
+        }
+
+        impl<$($impl_params)*> $crate::GetField<$crate::TS!($fname)> for $self
+        where
+            $member_ty: $crate::GetField<$crate::TS!($fname)>,
+            $($where_clause)*
+        {
+            #[inline(always)]
+            fn get_field_(&self, fname: $crate::TS!($fname)) -> &Self::Ty {
+                $crate::GetField::get_field_(&self.$member, fname)
+            }
+        }
+
+        unsafe impl<'__struc_delegated, $($impl_params)*>
+            $crate::GetFieldMut<$crate::TS!($fname)>
+        for $self
+        where
+            $member_ty: $crate::GetField<$crate::TS!($fname)>,
+            $member_ty: $crate::pmr::_Structural_DelegatedFieldMut<
+                '__struc_delegated,
+                $crate::TS!($fname),
+                Ty = $crate::GetFieldType<$member_ty, $crate::TS!($fname)>,
+            >,
+            $($where_clause)*
+        {
+            #[inline(always)]
+            fn get_field_mut_(&mut self, fname: $crate::TS!($fname)) -> &mut Self::Ty {
+                $crate::pmr::_Structural_DelegatedFieldMut::get_field_mut_(
+                    &mut self.$member,
+                    fname,
+                )
+            }
+
+            #[inline(always)]
+            unsafe fn get_field_raw_mut(
+                this: *mut (),
+                fname: $crate::TS!($fname),
+            ) -> *mut Self::Ty
+            where
+                Self: Sized
+            {
+                let member = &mut (*(this as *mut Self)).$member as *mut $member_ty;
+                <$member_ty as $crate::pmr::_Structural_DelegatedFieldMut<
+                    '__struc_delegated,
+                    $crate::TS!($fname),
+                >>::get_field_raw_mut(member as *mut (), fname)
+            }
+
+            #[inline(always)]
+            fn get_field_raw_mut_fn(
+                &self,
+            ) -> $crate::field::GetFieldRawMutFn<$crate::TS!($fname), Self::Ty> {
+                <Self as $crate::GetFieldMut<$crate::TS!($fname)>>::get_field_raw_mut
+            }
+        }
+    );
     (
         $( #[doc=$doc:expr] )*
         impl $impl_params:tt $self:ty
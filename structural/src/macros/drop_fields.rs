@@ -85,9 +85,10 @@ macro_rules! _private_impl_drop_fields_inner{
         where[$($where:tt)*]
         {
             not_public( $($drop_uncond:tt)* ),
+            delegated_to( $(($delegated_to:tt, $delegated_start:expr, $delegated_len:expr))* ),
             field_names($(
                 (
-                    $field_name:tt,
+                    $field_name:tt $(. $field_nested:tt)*,
                     $field_index:expr,
                 ),
             )*),
@@ -100,6 +101,9 @@ macro_rules! _private_impl_drop_fields_inner{
         {
             #[inline(always)]
             fn pre_move(&mut self){
+                $(
+                    $crate::pmr::DropFields::pre_move(&mut self.$delegated_to);
+                )*
                 $(
                     $pre_move_fn(self);
                 )?
@@ -122,12 +126,23 @@ macro_rules! _private_impl_drop_fields_inner{
                             ))*
                         }
 
+                        // The fields that delegate by-value access to some fields,
+                        // tracking the moved out fields with a range of bits in `moved`.
+                        $crate::reverse_code!{
+                            $((
+                                let _a=$crate::pmr::RunDropFields::new(
+                                    &mut this.$delegated_to,
+                                    moved.range($delegated_start, $delegated_len),
+                                );
+                            ))*
+                        }
+
                         $crate::reverse_code!{$((
                             let _a;
                             {
                                 const __DROP_BIT:FieldBit=FieldBit::new($field_index);
                                 if !moved.is_moved_out(__DROP_BIT) {
-                                    _a=RunDrop::new( &mut this.$field_name )
+                                    _a=RunDrop::new( &mut this.$field_name $(.$field_nested)* )
                                 }
                             }
                         ))*}
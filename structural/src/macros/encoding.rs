@@ -0,0 +1,106 @@
+/**
+Implements the [`Encode`] and [`Decode`] traits for a type with fields,
+encoding the fields that a path set refers to.
+
+This allows the type to be a nested field of the values
+encoded with the [`encoding`] module.
+
+The type must implement `FromStructural` to be decoded,
+which the `#[struc(from_structural)]` attribute of the `Structural` derive implements.
+
+# Syntax
+
+```text
+impl[ $( $generic_params )* ] $type
+$( where[ $( $where_predicates )* ] )?
+{
+    fields = $field_path_set
+}
+```
+
+# Example
+
+```rust
+use structural::{encoding, Structural, fp, impl_encoding};
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+struct Rgb<T> {
+    red: T,
+    green: T,
+    blue: T,
+}
+
+impl_encoding!{
+    impl[T] Rgb<T>
+    where[ T: encoding::Encode + encoding::Decode ]
+    {
+        fields = fp!(red, green, blue)
+    }
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+struct Pixel {
+    color: Rgb<u8>,
+    alpha: u8,
+}
+
+let pixel = Pixel{ color: Rgb{ red: 255, green: 128, blue: 0 }, alpha: 200 };
+
+let bytes = encoding::encode_to_vec(&pixel, fp!(alpha, color));
+assert_eq!( bytes, vec![200, 255, 128, 0] );
+
+assert_eq!( encoding::decode(&mut &bytes[..], fp!(alpha, color)), Ok(pixel) );
+
+```
+
+[`Encode`]: ./encoding/trait.Encode.html
+[`Decode`]: ./encoding/trait.Decode.html
+[`encoding`]: ./encoding/index.html
+
+*/
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+#[macro_export]
+macro_rules! impl_encoding {
+    (
+        impl[ $($params:tt)* ] $self_:ty
+        $( where[ $($where_preds:tt)* ] )?
+        {
+            fields = $path:expr $(,)?
+        }
+    ) => {
+        impl<$($params)*> $crate::encoding::Encode for $self_
+        where
+            $( $($where_preds)* )?
+        {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                $crate::encoding::EncodeFields::fields_encoded_len($path, self)
+            }
+
+            #[inline]
+            fn encode<__W>(
+                &self,
+                writer: &mut __W,
+            ) -> $crate::pmr::Result<(), $crate::encoding::EncodeError>
+            where
+                __W: ?Sized + $crate::encoding::WriteBytes,
+            {
+                $crate::encoding::EncodeFields::encode_fields($path, self, writer)
+            }
+        }
+
+        impl<$($params)*> $crate::encoding::Decode for $self_
+        where
+            $( $($where_preds)* )?
+        {
+            #[inline]
+            fn decode(
+                bytes: &mut &[u8],
+            ) -> $crate::pmr::Result<Self, $crate::encoding::DecodeError> {
+                $crate::encoding::DecodeFields::decode_fields($path, bytes)
+            }
+        }
+    };
+}
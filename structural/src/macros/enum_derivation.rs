@@ -97,7 +97,7 @@ macro_rules! _private_impl_getter_enum{
         )
         newtype(
             $field_name:tt : $field_ty:ty,
-            dropping $dropping:tt
+            dropping($field_var:ident, $field_index:expr)
             $(,$field_name_param:ty)? $( , )*
         )
     )=>{
@@ -111,6 +111,15 @@ macro_rules! _private_impl_getter_enum{
             type Ty=$crate::GetFieldType<$field_ty,__F>;
         }
 
+        impl<$($typarams)* __F>
+            $crate::field::FieldIndex< $crate::VariantField<$variant_name_str,__F> >
+        for $self_
+        where
+            $($where_)*
+        {
+            const INDEX: u8 = $field_index;
+        }
+
         unsafe impl<$($typarams)* __F,__Ty>
             $crate::pmr::GetVariantField<$variant_name_str,__F>
         for $self_
@@ -146,7 +155,7 @@ macro_rules! _private_impl_getter_enum{
         )
         regular(
             $field_name:tt : $field_ty:ty,
-            dropping $dropping:tt,
+            dropping($field_var:ident, $field_index:expr),
             $field_name_param:ty $( , )*
         )
     )=>{
@@ -162,6 +171,17 @@ macro_rules! _private_impl_getter_enum{
             type Ty=$field_ty;
         }
 
+        impl<$($typarams)*>
+            $crate::field::FieldIndex<
+                $crate::VariantField<$variant_name_str,$field_name_param>
+            >
+        for $self_
+        where
+            $($where_)*
+        {
+            const INDEX: u8 = $field_index;
+        }
+
         unsafe impl<$($typarams)*>
             $crate::pmr::GetVariantField<$variant_name_str,$field_name_param>
         for $self_
@@ -607,6 +627,7 @@ macro_rules! _private_impl_getters_for_derive_enum{
                 kind=$variant_kind:ident,
                 not_public( $(($priv_field:tt = $priv_field_var:ident))* ),
                 fields($( $field:tt )*)
+                $(, names($variant_str:expr, fields($($field_str:expr),* $(,)*)) )? $(,)*
             ))*
         }
 
@@ -665,6 +686,20 @@ macro_rules! _private_impl_getters_for_derive_enum{
         }
 
 
+        $crate::_private_impl_getters_for_derive_enum!{
+            @visit_fields
+            enum=$enum_
+            impl $typarams $self_
+            where $where_preds
+            {
+                $((
+                    $variant,
+                    fields($( $field )*)
+                    $(, $variant_str, field_names($($field_str),*) )?
+                ))*
+            }
+        }
+
         $(
             $crate::_private_impl_getters_for_derive_enum!{
                 @inner
@@ -675,6 +710,30 @@ macro_rules! _private_impl_getters_for_derive_enum{
             }
         )?
     };
+    // `VisitFields` is only implemented when the names of all the variants are passed.
+    (@visit_fields
+        enum=$enum_:ident
+        impl $typarams:tt $self_:ty
+        where $where_preds:tt
+        {
+            $((
+                $variant:ident,
+                fields $fields:tt,
+                $variant_str:expr,
+                field_names $field_names:tt
+            ))*
+        }
+    )=>{
+        $crate::_private_impl_visit_fields!{
+            enum=$enum_
+            impl $typarams $self_
+            where $where_preds
+            {
+                $(( $variant, $variant_str, fields $fields, field_names $field_names ))*
+            }
+        }
+    };
+    (@visit_fields $($anything:tt)* )=>{};
     (@inner
         impl[$($typarams:tt)*] $self_:ty
         where[$($where_:tt)*]
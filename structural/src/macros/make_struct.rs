@@ -161,3 +161,64 @@ macro_rules! make_struct {
         }
     });
 }
+
+/// Constructs a type from an array,
+/// with one field for each element of the array,
+/// using its [`FromStructural`] impl.
+///
+/// This is the reverse of [`StructuralExt::into_fields_array`].
+///
+/// The type is constructed from an anonymous struct with the passed fields
+/// (the same struct that [`make_struct`] would construct),
+/// so it must implement `FromStructural` for a struct with those fields.
+///
+/// # Syntax
+///
+/// ```text
+/// from_fields_array!( [field_name0, field_name1, field_name2] = array_expression )
+/// ```
+///
+/// The field names must be identifiers,
+/// the [`FromStructural`] impls for tuples and arrays can be used to construct
+/// types with numbered fields instead.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{StructuralExt, Structural, from_fields_array, fp};
+///
+/// #[derive(Structural, Debug, PartialEq)]
+/// #[struc(public, from_structural)]
+/// struct Rgb {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+/// }
+///
+/// fn inverted(color: &impl Rgb_SI) -> Rgb {
+///     let mut array = color.cloned_fields_array(fp!(r, g, b));
+///     for channel in array.iter_mut() {
+///         *channel = 255 - *channel;
+///     }
+///     from_fields_array!([r, g, b] = array)
+/// }
+///
+/// let color: Rgb = from_fields_array!([r, g, b] = [10, 20, 30]);
+/// assert_eq!(color, Rgb { r: 10, g: 20, b: 30 });
+///
+/// assert_eq!(inverted(&color), Rgb { r: 245, g: 235, b: 225 });
+///
+/// ```
+///
+/// [`FromStructural`]: ./convert/trait.FromStructural.html
+/// [`StructuralExt::into_fields_array`]: ./trait.StructuralExt.html#method.into_fields_array
+/// [`make_struct`]: ./macro.make_struct.html
+#[macro_export]
+macro_rules! from_fields_array {
+    ( [ $($field_name:ident),* $(,)? ] = $array:expr ) => ({
+        let [$($field_name),*] = $array;
+        $crate::convert::FromStructural::from_structural($crate::make_struct!{
+            $($field_name),*
+        })
+    });
+}
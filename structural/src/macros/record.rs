@@ -0,0 +1,124 @@
+/// The type of a [`Record`] with the passed field names and types.
+///
+/// # Syntax
+///
+/// ```text
+/// Record!{ field_name0: FieldType0, field_name1: FieldType1 }
+/// ```
+///
+/// The field names can be identifiers,integers,or string literals,
+/// the same as the [`TS`] macro takes.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{
+///     record::{Field, Record},
+///     Record, StructuralExt, TS, fp, record,
+/// };
+///
+/// struct Stage {
+///     context: Record!{ request_id: u64, "user name": String },
+/// }
+///
+/// let _: Record<(Field<TS!(request_id), u64>, Field<TS!("user name"), String>)> =
+///     Stage{ context: record!{ request_id: 3, "user name": "bob".to_string() } }.context;
+///
+/// fn context() -> Record!{ retries: u32 } {
+///     record!{ retries: 5 }
+/// }
+/// assert_eq!( context().into_field(fp!(retries)), 5 );
+///
+/// ```
+///
+/// [`Record`]: ./record/struct.Record.html
+/// [`TS`]: ./macro.TS.html
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+#[macro_export]
+macro_rules! Record {
+    ( $( $field_name:tt : $field_ty:ty ),* $(,)? ) => {
+        $crate::record::Record<(
+            $( $crate::record::Field<$crate::TS!($field_name), $field_ty>, )*
+        )>
+    };
+}
+
+/// Constructs a [`Record`],which implements all the accessor traits for its fields.
+///
+/// The type of the constructed record can be written with the [`Record`](./macro.Record.html)
+/// macro.
+///
+/// # Syntax
+///
+/// ```text
+/// record!{
+///     field_name0: value0,
+///     "field name1": value1,
+///     field_name2, // This initializes a `field_name2` field with the `field_name2` variable.
+/// }
+/// ```
+///
+/// The field names can be identifiers,integers,or string literals,
+/// the same as the [`TS`] macro takes.
+/// Repeated identifier field names are a compile-time error.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{StructuralExt, fp, record};
+///
+/// let name = "hello";
+/// let mut this = record!{ name, stamina: 100_u32, 0: 'a' };
+///
+/// *this.field_mut(fp!(stamina)) -= 10;
+/// assert_eq!( this.fields(fp!(name, stamina, 0)), (&"hello", &90, &'a') );
+///
+/// ```
+///
+/// [`Record`]: ./record/struct.Record.html
+/// [`TS`]: ./macro.TS.html
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+#[macro_export]
+macro_rules! record {
+    ( $( $field_name:tt $( : $field_value:expr )? ),* $(,)? ) => ({
+        $crate::_private_record_names_check!{ () $($field_name)* }
+
+        $crate::record::Record((
+            $(
+                $crate::record::Field::<$crate::TS!($field_name), _>::new(
+                    $crate::_private_record_value!($field_name $(: $field_value)?)
+                ),
+            )*
+        ))
+    });
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_record_value {
+    ($field_name:ident) => {
+        $field_name
+    };
+    ($field_name:tt : $field_value:expr) => {
+        $field_value
+    };
+}
+
+// Declares a struct with all the identifier field names,
+// so that repeated field names cause a compile-time error.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_record_names_check {
+    ( ($($idents:ident)*) ) => {
+        #[allow(dead_code, non_snake_case, non_camel_case_types)]
+        struct __RecordFieldNames {
+            $( $idents: (), )*
+        }
+    };
+    ( ($($idents:ident)*) $field_name:ident $($rem:tt)* ) => {
+        $crate::_private_record_names_check!{ ($($idents)* $field_name) $($rem)* }
+    };
+    ( ($($idents:ident)*) $field_name:tt $($rem:tt)* ) => {
+        $crate::_private_record_names_check!{ ($($idents)*) $($rem)* }
+    };
+}
@@ -0,0 +1,210 @@
+/**
+Declares a newtype wrapper around a struct with public fields defined in another crate,
+implementing the accessor traits for the fields of the wrapped struct.
+
+This is for types that can't use `#[derive(Structural)]`,
+because they're defined in a crate that doesn't use structural.
+If you can declare a struct mirroring the fields of the type,
+the [`#[struc(remote = "...")]`](./docs/structural_macro/index.html#strucremote--pathtotype)
+attribute of the `Structural` derive is an alternative to this macro.
+
+All the fields get shared,mutable,and by-value accessor impls.
+
+The listed fields must be all the fields of the wrapped struct,
+it's a compile-time error if a field is missing.
+
+The newtype implements `From` to convert from and into the wrapped struct.
+
+This also declares a `<newtype_name>_SI` trait,
+a [structural alias](./macro.structural_alias.html) for the accessor impls of the newtype,
+like the one that the `Structural` derive declares.
+
+# Syntax
+
+```text
+$( #[$attribute] )*
+$visibility struct $newtype_name $( [ $( $generic_param ),* ] )? ( $wrapped_type )
+$( where[ $( $where_predicates )* ] )?
+{
+    $( $field_name : $field_type ),*
+}
+```
+
+Generic parameters are lifetimes and type parameters without bounds,
+the bounds go in the `where[ ... ]` clause.
+
+For tuple structs the field names are the positions of the fields,
+eg: `0: u32, 1: String`.
+
+# Example
+
+```rust
+use structural::{fp, remote_structural, StructuralExt};
+
+// Pretend that this is a module in another crate
+mod other_crate {
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Point<T> {
+        pub x: T,
+        pub y: T,
+    }
+
+    pub struct Meters(pub f64);
+}
+
+use other_crate::{Meters, Point};
+
+remote_structural!{
+    /// A newtype wrapper around `other_crate::Point`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct PointS[T](Point<T>)
+    where[ T: Clone ]
+    {
+        x: T,
+        y: T,
+    }
+}
+
+let mut point = PointS(Point{ x: 3, y: 5 });
+
+assert_eq!( point.fields(fp!(x, y)), (&3, &5) );
+
+*point.field_mut(fp!(y)) = 8;
+assert_eq!( point.clone().into_field(fp!(y)), 8 );
+
+let point: Point<u32> = point.into();
+assert_eq!( point, Point{ x: 3, y: 8 } );
+
+fn sum<T>(this: &impl PointS_SI<T>) -> T
+where
+    T: Clone + std::ops::Add<Output = T>,
+{
+    let (x, y) = this.fields(fp!(x, y));
+    x.clone() + y.clone()
+}
+
+assert_eq!( sum(&PointS(Point{ x: 13, y: 21 })), 34 );
+
+
+remote_structural!{
+    pub struct MetersS(Meters) {
+        0: f64,
+    }
+}
+
+let meters = MetersS(Meters(2.5));
+assert_eq!( meters.field_(fp!(0)), &2.5 );
+assert_eq!( meters.into_field(fp!(0)), 2.5 );
+
+```
+
+*/
+#[macro_export]
+macro_rules! remote_structural {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $newtype:ident $([ $($params:tt),* $(,)? ])? ($remote:ty)
+        $( where[ $($where_preds:tt)* ] )?
+        {
+            $( $field:tt : $field_ty:ty ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        $vis struct $newtype<$($($params,)*)?>(pub $remote)
+        where $($($where_preds)*)?;
+
+        $crate::_remote_structural_alias!{
+            $vis trait $newtype<$($($params,)*)?>
+            where $($($where_preds)*)?
+            {
+                $(mut move $field: $field_ty,)*
+            }
+        }
+
+        const _: () = {
+            type __Remote<$($($params,)*)?> = $remote;
+
+            // Errors if the listed fields aren't all the fields of the wrapped struct.
+            #[allow(dead_code)]
+            fn __check_remote_fields<$($($params,)*)?>(this: $remote)
+            where $($($where_preds)*)?
+            {
+                let __Remote{ $($field: _,)* } = this;
+            }
+
+            impl<$($($params,)*)?> $crate::pmr::From<$remote> for $newtype<$($($params,)*)?>
+            where $($($where_preds)*)?
+            {
+                #[inline(always)]
+                fn from(this: $remote) -> Self {
+                    $newtype(this)
+                }
+            }
+
+            impl<$($($params,)*)?> $crate::pmr::From<$newtype<$($($params,)*)?>> for $remote
+            where $($($where_preds)*)?
+            {
+                #[inline(always)]
+                fn from(this: $newtype<$($($params,)*)?>) -> Self {
+                    this.0
+                }
+            }
+
+            $crate::remote_structural!{
+                @getters
+                impl[$($($params,)*)?] $newtype<$($($params,)*)?>
+                where[$($($where_preds)*)?]
+                index(0)
+                getters()
+                fields( $( $field : $field_ty, )* )
+            }
+        };
+    };
+    // Assigns an index to each field,by counting the fields before it.
+    (
+        @getters
+        impl $typarams:tt $self_:ty
+        where $where_preds:tt
+        index($($index:tt)*)
+        getters( $($getters:tt)* )
+        fields( $field:tt : $field_ty:ty, $($rem_fields:tt)* )
+    ) => {
+        $crate::remote_structural!{
+            @getters
+            impl $typarams $self_
+            where $where_preds
+            index($($index)* + 1)
+            getters(
+                $($getters)*
+                (
+                    IntoFieldMut<
+                        0 . $field : $field_ty,
+                        ($($index)*) as u8,
+                        $crate::TS!($field),
+                        stringify!($field),
+                    >
+                )
+            )
+            fields( $($rem_fields)* )
+        }
+    };
+    (
+        @getters
+        impl $typarams:tt $self_:ty
+        where $where_preds:tt
+        index $index:tt
+        getters( $($getters:tt)* )
+        fields()
+    ) => {
+        $crate::_private_impl_getters_for_derive_struct!{
+            impl $typarams $self_
+            where $where_preds
+            {
+                DropFields{ drop_fields={just_fields,} }
+
+                $($getters)*
+            }
+        }
+    };
+}
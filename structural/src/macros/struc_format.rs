@@ -0,0 +1,199 @@
+/**
+Formats a value into a `String`,
+interpolating fields into the format string with the same syntax as the [`fp`] macro.
+
+This requires the "alloc" feature (enabled by default).
+
+# Syntax
+
+```text
+struc_format!( $format_string:literal , $value:expr )
+```
+
+The format string uses the syntax of [`std::format`],
+except that the placeholders name field paths of `$value`
+instead of arguments,with this syntax:
+
+```text
+{ $field_path $( | $fallback )? $( : $format_spec )? }
+```
+
+Where:
+
+- `$field_path` is anything that can be passed to the [`fp`] macro,
+  including optional fields (eg: `foo?.bar`) and variant fields (eg: `::Foo.bar`).
+
+- `$fallback` is the text that's written when the field doesn't exist,
+  which can only happen for optional and variant fields.
+  It can't contain `:` or `}`,and it defaults to an empty string.
+
+- `$format_spec` is a regular format spec (eg: `?`,`>10`,`#x`).
+
+Every field is accessed with [`StructuralExt::field_`],
+which means that `$value` can be any type that implements the accessor traits,
+including `dyn` structural aliases.
+
+`$value` is only evaluated once,and is only borrowed.
+
+# Example
+
+```rust
+use structural::{struc_format, Structural};
+
+#[derive(Structural)]
+#[struc(public)]
+struct Person {
+    name: &'static str,
+    house: House,
+    pet: Option<Pet>,
+}
+
+#[derive(Structural)]
+#[struc(public)]
+struct House {
+    dim: Dimension,
+}
+
+#[derive(Structural)]
+#[struc(public)]
+struct Dimension {
+    width: u32,
+    length: u32,
+}
+
+#[derive(Structural)]
+#[struc(public)]
+struct Pet {
+    name: &'static str,
+}
+
+// `Person_SI` is the structural alias generated by the `Structural` derive
+fn describe(person: &dyn Person_SI) -> String {
+    struc_format!(
+        "{name} lives in a {house.dim.width}m wide house with {pet?.name|nobody}",
+        person,
+    )
+}
+
+let mut person = Person {
+    name: "Bob",
+    house: House{ dim: Dimension{ width: 8, length: 13 } },
+    pet: None,
+};
+
+assert_eq!(describe(&person), "Bob lives in a 8m wide house with nobody");
+
+person.pet = Some(Pet{ name: "Spot" });
+assert_eq!(describe(&person), "Bob lives in a 8m wide house with Spot");
+
+assert_eq!(
+    struc_format!("{name:?} {house.dim.length:>4} {{literal}}", person),
+    r#""Bob"   13 {literal}"#,
+);
+
+```
+
+[`fp`]: ./macro.fp.html
+[`std::format`]: https://doc.rust-lang.org/std/macro.format.html
+[`StructuralExt::field_`]: ./trait.StructuralExt.html#method.field_
+
+*/
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! struc_format {
+    ( $format_str:literal, $this:expr $(,)? ) => {
+        $crate::_struc_format_impl!( ($crate::alloc::format) () $format_str, $this )
+    };
+}
+
+/**
+Writes a value into a [`fmt::Write`] or [`io::Write`],
+interpolating fields into the format string with the same syntax as the [`fp`] macro.
+
+This evaluates to the return value of the `write_fmt` method of the destination.
+
+# Syntax
+
+```text
+struc_write!( $destination:expr , $format_string:literal , $value:expr )
+```
+
+The syntax of the format string is described in [the `struc_format` macro](./macro.struc_format.html).
+
+# Example
+
+```rust
+use structural::{struc_write, Structural};
+
+use std::fmt::{self, Display};
+
+#[derive(Structural)]
+struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+    #[struc(rename = "maybe_label")]
+    pub label: Option<&'static str>,
+}
+
+impl Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struc_write!(f, "{maybe_label?|unnamed}: {width}x{height}", self)
+    }
+}
+
+let rect = Rectangle{ width: 3, height: 5, label: None };
+assert_eq!( rect.to_string(), "unnamed: 3x5" );
+
+let rect = Rectangle{ width: 8, height: 13, label: Some("door") };
+assert_eq!( rect.to_string(), "door: 8x13" );
+
+```
+
+[`fp`]: ./macro.fp.html
+[`fmt::Write`]: https://doc.rust-lang.org/std/fmt/trait.Write.html
+[`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+
+*/
+#[macro_export]
+macro_rules! struc_write {
+    ( $dst:expr, $format_str:literal, $this:expr $(,)? ) => {
+        $crate::_struc_format_impl!( ($crate::std_::write) ($dst,) $format_str, $this )
+    };
+}
+
+/**
+Prints a value to the standard output,with a newline,
+interpolating fields into the format string with the same syntax as the [`fp`] macro.
+
+This requires the "std" feature (enabled by default).
+
+# Syntax
+
+```text
+struc_println!( $format_string:literal , $value:expr )
+```
+
+The syntax of the format string is described in [the `struc_format` macro](./macro.struc_format.html).
+
+# Example
+
+```rust
+use structural::{make_struct, struc_println};
+
+let point = make_struct!{ x: 3, y: 5 };
+
+// Prints `(3, 5)`
+struc_println!("({x}, {y})", point);
+
+```
+
+[`fp`]: ./macro.fp.html
+
+*/
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! struc_println {
+    ( $format_str:literal, $this:expr $(,)? ) => {
+        $crate::_struc_format_impl!( ($crate::std::println) () $format_str, $this )
+    };
+}
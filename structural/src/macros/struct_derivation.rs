@@ -24,14 +24,14 @@
 macro_rules! z_unsafe_impl_get_field_raw_mut {
     (
         $Self:ident,
-        field_tstr=$field_name:tt,
+        field_tstr=$field_name:tt $(. $field_nested:tt)*,
         name_generic=$name_param:ty,
     ) => {
         unsafe fn get_field_raw_mut(
             this: *mut (),
             _: $name_param,
         ) -> *mut $crate::GetFieldType<$Self, $name_param> {
-            &mut (*(this as *mut $Self)).$field_name
+            &mut (*(this as *mut $Self)).$field_name $(.$field_nested)*
                 as *mut $crate::GetFieldType<$Self, $name_param>
         }
 
@@ -51,7 +51,10 @@ macro_rules! _private_impl_getter{
     (
         $(unsafe)?
         impl[$($typarams:tt)*]
-            GetField <$field_name:tt : $field_ty:ty, $field_index:expr,$name_param:ty>
+            GetField <
+                $field_name:tt $(. $field_nested:tt)* : $field_ty:ty,
+                $field_index:expr,$name_param:ty
+            >
         for $self_:ty
         $( where[$($where_:tt)*] )?
     )=>{
@@ -65,18 +68,28 @@ macro_rules! _private_impl_getter{
         $( where $($where_)* )?
         {
             fn get_field_(&self,_:$name_param)->&Self::Ty{
-                &self.$field_name
+                &self.$field_name $(.$field_nested)*
             }
         }
+
+        impl<$($typarams)*> $crate::field::FieldIndex<$name_param> for $self_
+        $( where $($where_)* )?
+        {
+            const INDEX: u8 = $field_index;
+        }
     };
     (
         unsafe impl[$($typarams:tt)*]
-            GetFieldMut <$field_name:tt : $field_ty:ty, $field_index:expr,$name_param:ty>
+            GetFieldMut <
+                $field_name:tt $(. $field_nested:tt)* : $field_ty:ty,
+                $field_index:expr,$name_param:ty
+            >
         for $self_:ty
         $( where[$($where_:tt)*] )?
     )=>{
         $crate::_private_impl_getter!{
-            impl[$($typarams)*] GetField<$field_name:$field_ty, $field_index,$name_param>
+            impl[$($typarams)*]
+                GetField<$field_name $(.$field_nested)*:$field_ty, $field_index,$name_param>
             for $self_
             $( where[$($where_)*] )?
         }
@@ -85,19 +98,22 @@ macro_rules! _private_impl_getter{
         $( where $($where_)* )?
         {
             fn get_field_mut_(&mut self,_:$name_param)->&mut Self::Ty{
-                &mut self.$field_name
+                &mut self.$field_name $(.$field_nested)*
             }
 
             $crate::z_unsafe_impl_get_field_raw_mut!{
                 Self,
-                field_tstr=$field_name,
+                field_tstr=$field_name $(.$field_nested)*,
                 name_generic=$name_param,
             }
         }
     };
     (@just_into_field
         unsafe impl[$($typarams:tt)*]
-            IntoField <$field_name:tt : $field_ty:ty, $field_index:expr, $name_param:ty>
+            IntoField <
+                $field_name:tt $(. $field_nested:tt)* : $field_ty:ty,
+                $field_index:expr, $name_param:ty
+            >
         for $self_:ty
         $( where[$($where_:tt)*] )?
     )=>{
@@ -108,7 +124,7 @@ macro_rules! _private_impl_getter{
                 self,
                 _:$name_param,
             )->Self::Ty{
-                self.$field_name
+                self.$field_name $(.$field_nested)*
             }
 
             unsafe fn move_out_field_(
@@ -121,19 +137,22 @@ macro_rules! _private_impl_getter{
                     const BIT: FieldBit = FieldBit::new($field_index);
                     moved_fields.set_moved_out(BIT);
                 }
-                (&mut self.$field_name as *mut $field_ty).read()
+                (&mut self.$field_name $(.$field_nested)* as *mut $field_ty).read()
             }
         }
     };
     (
         unsafe impl[$($typarams:tt)*]
-            IntoField <$field_name:tt : $field_ty:ty, $field_index:expr, $name_param:ty>
+            IntoField <
+                $field_name:tt $(. $field_nested:tt)* : $field_ty:ty,
+                $field_index:expr, $name_param:ty
+            >
         for $self_:ty
         $( where[$($where_:tt)*] )?
     )=>{
         $crate::_private_impl_getter!{
             impl[$($typarams)*]
-                GetField<$field_name:$field_ty,$field_index,$name_param>
+                GetField<$field_name $(.$field_nested)*:$field_ty,$field_index,$name_param>
             for $self_
             $( where[$($where_)*] )?
         }
@@ -141,20 +160,23 @@ macro_rules! _private_impl_getter{
         $crate::_private_impl_getter!{
             @just_into_field
             unsafe impl[$($typarams)*]
-                IntoField<$field_name:$field_ty,$field_index,$name_param>
+                IntoField<$field_name $(.$field_nested)*:$field_ty,$field_index,$name_param>
             for $self_
             $( where[$($where_)*] )?
         }
     };
     (
         unsafe impl[$($typarams:tt)*]
-            IntoFieldMut <$field_name:tt : $field_ty:ty, $field_index:expr, $name_param:ty>
+            IntoFieldMut <
+                $field_name:tt $(. $field_nested:tt)* : $field_ty:ty,
+                $field_index:expr, $name_param:ty
+            >
         for $self_:ty
         $( where[$($where_:tt)*] )?
     )=>{
         $crate::_private_impl_getter!{
             unsafe impl[$($typarams)*]
-                GetFieldMut<$field_name:$field_ty,$field_index,$name_param>
+                GetFieldMut<$field_name $(.$field_nested)*:$field_ty,$field_index,$name_param>
             for $self_
             $( where[$($where_)*] )?
         }
@@ -162,7 +184,7 @@ macro_rules! _private_impl_getter{
         $crate::_private_impl_getter!{
             @just_into_field
             unsafe impl[$($typarams)*]
-                IntoField<$field_name:$field_ty,$field_index,$name_param>
+                IntoField<$field_name $(.$field_nested)*:$field_ty,$field_index,$name_param>
             for $self_
             $( where[$($where_)*] )?
         }
@@ -181,11 +203,12 @@ macro_rules! _private_impl_getters_for_derive_struct{
             DropFields{
                 drop_fields=$for_drop:tt
                 $(not_public($($drop_fields:tt)*))?
+                $(delegated_to($($delegated_to:tt)*))?
             }
 
             $((
                 $getter_trait:ident<
-                    $field_name:tt : $field_ty:ty,
+                    $field_name:tt $(. $field_nested:tt)* : $field_ty:ty,
                     $field_index:expr,
                     $name_param_ty:ty,
                     $name_param_str:expr,
@@ -210,10 +233,11 @@ macro_rules! _private_impl_getters_for_derive_struct{
             where $where_preds
             {
                 not_public( $($($drop_fields)*)? ),
+                delegated_to( $($($delegated_to)*)? ),
                 field_names(
                     $(
                         (
-                            $field_name,
+                            $field_name $(.$field_nested)*,
                             $field_index,
                         ),
                     )*
@@ -224,10 +248,30 @@ macro_rules! _private_impl_getters_for_derive_struct{
         $(
             $crate::_private_impl_getter!{
                 unsafe impl $typarams
-                    $getter_trait<$field_name : $field_ty,$field_index,$name_param_ty>
+                    $getter_trait<
+                        $field_name $(.$field_nested)* : $field_ty,
+                        $field_index,
+                        $name_param_ty
+                    >
                 for $self_
                 where $where_preds
             }
         )*
+
+        $crate::_private_impl_visit_fields!{
+            struct
+            impl $typarams $self_
+            where $where_preds
+            {
+                $((
+                    $getter_trait<
+                        $field_name $(.$field_nested)* : $field_ty,
+                        $field_index,
+                        $name_param_ty,
+                        $name_param_str,
+                    >
+                ))*
+            }
+        }
     }
 }
@@ -110,8 +110,7 @@ The methods and constants turn into defaulted methods and defaulted constants in
 - `i:impl Bar`:
     Corresponds to the `IntoFieldMut<FP!(i),Ty:Bar>` trait,
     allowing shared,mutable,and by value access to
-    a field that implements the Bar trait.<br>
-    This requires the `nightly_impl_fields` or `impl_fields` cargo feature.
+    a field that implements the Bar trait.
 
 ### Variants
 
@@ -283,9 +282,6 @@ structural_alias!{
 
 # impl Trait fields
 
-This requires the `nightly_impl_fields` cargo feature
-(or `impl_fields` if associated type bounds stabilized after the latest release).
-
 You can declare a field with `impl Bar` as its type to declare that the field
 implements Bar,without specifying a particular type.
 
@@ -295,14 +291,7 @@ Using `impl Trait` fields makes a `Foo` structural alias unusable as a `dyn Foo`
 
 This demonstrates using impl trait fields.
 
-*/
-#[cfg_attr(not(feature = "impl_fields"), doc = "```ignore")]
-#[cfg_attr(feature = "impl_fields", doc = "```rust")]
-/**
-// Remove this if associated type bounds (eg: `T: Iterator<Item: Debug>`)
-// work without it.
-#![feature(associated_type_bounds)]
-
+```rust
 use structural::{structural_alias,fp,make_struct,StructuralExt};
 
 structural_alias!{
@@ -0,0 +1,158 @@
+// Implements the VisitFields trait for a struct or enum
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_impl_visit_fields{
+    (
+        struct
+        impl[$($typarams:tt)*] $self_:ty
+        where[$($where_:tt)*]
+        {
+            $((
+                $getter_trait:ident<
+                    $field_name:tt $(. $field_nested:tt)* : $field_ty:ty,
+                    $field_index:expr,
+                    $name_param_ty:ty,
+                    $name_param_str:expr,
+                >
+            ))*
+        }
+    )=>{
+        impl<$($typarams)* __V> $crate::VisitFields<__V> for $self_
+        where
+            __V: ?Sized $( + $crate::FieldVisitor<$field_ty> )*,
+            $($where_)*
+        {
+            #[allow(unused_variables)]
+            fn visit_fields(&self, visitor: &mut __V) {
+                $(
+                    <__V as $crate::FieldVisitor<$field_ty>>::visit_field(
+                        visitor,
+                        $crate::_private_impl_visit_fields!(
+                            @info $getter_trait, None, $name_param_str
+                        ),
+                        &self.$field_name $(.$field_nested)*,
+                    );
+                )*
+            }
+
+            #[allow(unused_variables)]
+            fn visit_fields_mut(&mut self, visitor: &mut __V) {
+                $(
+                    $crate::_private_impl_visit_fields!{
+                        @visit_mut $getter_trait,
+                        visitor,
+                        $crate::_private_impl_visit_fields!(
+                            @info $getter_trait, None, $name_param_str
+                        ),
+                        self.$field_name $(.$field_nested)*,
+                        $field_ty,
+                    }
+                )*
+            }
+        }
+    };
+    (
+        enum=$enum_:ident
+        impl[$($typarams:tt)*] $self_:ty
+        where[$($where_:tt)*]
+        {
+            $((
+                $variant:ident,
+                $variant_str:expr,
+                fields($((
+                    $getter_trait:ident,
+                    $field_name:tt : $field_ty:ty,
+                    dropping($field_var:ident, $field_index:expr)
+                    $(,$field_name_param:ty)? $( , )*
+                ))*),
+                field_names($($field_str:expr),* $(,)*)
+            ))*
+        }
+    )=>{
+        impl<$($typarams)* __V> $crate::VisitFields<__V> for $self_
+        where
+            $($where_)*
+            __V: ?Sized $($( + $crate::FieldVisitor<$field_ty> )*)*,
+        {
+            #[allow(unused_variables)]
+            fn visit_fields(&self, visitor: &mut __V) {
+                match self {
+                    $(
+                        $enum_::$variant{$($field_name: $field_var,)* ..}=>{
+                            $(
+                                <__V as $crate::FieldVisitor<$field_ty>>::visit_field(
+                                    visitor,
+                                    $crate::_private_impl_visit_fields!(
+                                        @info $getter_trait, Some($variant_str), $field_str
+                                    ),
+                                    $field_var,
+                                );
+                            )*
+                        }
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _=>{}
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn visit_fields_mut(&mut self, visitor: &mut __V) {
+                match self {
+                    $(
+                        $enum_::$variant{$($field_name: $field_var,)* ..}=>{
+                            $(
+                                $crate::_private_impl_visit_fields!{
+                                    @visit_mut $getter_trait,
+                                    visitor,
+                                    $crate::_private_impl_visit_fields!(
+                                        @info $getter_trait, Some($variant_str), $field_str
+                                    ),
+                                    *$field_var,
+                                    $field_ty,
+                                }
+                            )*
+                        }
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _=>{}
+                }
+            }
+        }
+    };
+    (@info $getter_trait:ident, $variant:expr, $name:expr)=>{
+        $crate::visit_fields::FieldInfo{
+            variant: $variant,
+            name: $name,
+            access: $crate::_private_impl_visit_fields!(@access $getter_trait),
+        }
+    };
+    (@access GetField)=>{ $crate::visit_fields::FieldAccess::Shared };
+    (@access GetFieldMut)=>{ $crate::visit_fields::FieldAccess::Mutable };
+    (@access IntoField)=>{ $crate::visit_fields::FieldAccess::Value };
+    (@access IntoFieldMut)=>{ $crate::visit_fields::FieldAccess::MutValue };
+    (@access GetVariantField)=>{ $crate::visit_fields::FieldAccess::Shared };
+    (@access GetVariantFieldMut)=>{ $crate::visit_fields::FieldAccess::Mutable };
+    (@access IntoVariantField)=>{ $crate::visit_fields::FieldAccess::Value };
+    (@access IntoVariantFieldMut)=>{ $crate::visit_fields::FieldAccess::MutValue };
+    (@visit_mut GetField, $($rem:tt)*)=>{
+        $crate::_private_impl_visit_fields!{@visit_shared $($rem)*}
+    };
+    (@visit_mut IntoField, $($rem:tt)*)=>{
+        $crate::_private_impl_visit_fields!{@visit_shared $($rem)*}
+    };
+    (@visit_mut GetVariantField, $($rem:tt)*)=>{
+        $crate::_private_impl_visit_fields!{@visit_shared $($rem)*}
+    };
+    (@visit_mut IntoVariantField, $($rem:tt)*)=>{
+        $crate::_private_impl_visit_fields!{@visit_shared $($rem)*}
+    };
+    (@visit_mut $getter_trait:ident, $($rem:tt)*)=>{
+        $crate::_private_impl_visit_fields!{@visit_mutable $($rem)*}
+    };
+    (@visit_shared $visitor:ident, $info:expr, $place:expr, $field_ty:ty,)=>{
+        <__V as $crate::FieldVisitor<$field_ty>>::visit_field($visitor, $info, &$place);
+    };
+    (@visit_mutable $visitor:ident, $info:expr, $place:expr, $field_ty:ty,)=>{
+        <__V as $crate::FieldVisitor<$field_ty>>::visit_field_mut($visitor, $info, &mut $place);
+    };
+}
@@ -7,7 +7,8 @@ The re-exported items are all field-path related.
 #![allow(non_snake_case, non_camel_case_types)]
 
 use crate::type_level::collection_traits::{
-    Append, AppendOut, PushBack, PushBackOut, ToTList, ToTListOut, ToTString,
+    Append, AppendOut, Difference, DifferenceOut, Intersection, IntersectionOut, PushBack,
+    PushBackOut, ToTList, ToTListOut, ToTString, Union, UnionOut,
 };
 
 pub use crate::{field_path_aliases, fp, FP};
@@ -27,8 +28,12 @@ mod tests;
 
 pub mod array_paths;
 
+mod to_str;
+
 mod to_usize;
 
+mod tstr_cmp;
+
 include! { "./path/path_components.rs" }
 
 pub use crate::{
@@ -517,6 +522,113 @@ impl<T, U> FieldPathSet<T, U> {
     }
 }
 
+impl<T> FieldPathSet<T, UniquePaths> {
+    /// Constructs a new FieldPathSet with the field names in `_other`
+    /// that aren't in `self` appended at the end.
+    ///
+    /// `_other` can be a single field name (eg: `fp!(a)`),
+    /// or a set of field names (eg: `fp!(a, b)`).
+    ///
+    /// This only works with sets of field names (eg: `fp!(a, b)`),
+    /// not with sets of nested field paths (eg: `fp!(a.b, c)`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{FP, StructuralExt, fp, make_struct};
+    ///
+    /// let mut this = make_struct!{ a: 3, b: 5, c: 8 };
+    ///
+    /// let _: FP!(a, b, c) = fp!(a, b).union(fp!(b, c));
+    ///
+    /// // The returned set keeps the `UniquePaths` guarantee,
+    /// // so it can be passed to `fields_mut`.
+    /// let (a, b, c) = this.fields_mut(fp!(a, b).union(fp!(b, c)));
+    /// *a += 1;
+    /// *b += 1;
+    /// *c += 1;
+    /// assert_eq!( this.cloned_fields(fp!(a, b, c)), (4, 6, 9) );
+    ///
+    /// ```
+    #[inline(always)]
+    pub fn union<O, Out>(self, _other: O) -> FieldPathSet<Out, UniquePaths>
+    where
+        Self: Union<O, Output = FieldPathSet<Out, UniquePaths>>,
+        Out: ConstDefault,
+    {
+        // The field names in both sets are unique,
+        // and `Union` removes the field names that are in both sets.
+        FieldPathSet {
+            paths: ManuallyDrop::new(ConstDefault::DEFAULT),
+            uniqueness: PhantomData,
+        }
+    }
+
+    /// Constructs a new FieldPathSet with only the field names in `self`
+    /// that are also in `_other`.
+    ///
+    /// `_other` can be a single field name (eg: `fp!(a)`),
+    /// or a set of field names (eg: `fp!(a, b)`).
+    ///
+    /// This only works with sets of field names (eg: `fp!(a, b)`),
+    /// not with sets of nested field paths (eg: `fp!(a.b, c)`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{FP, fp};
+    ///
+    /// let _: FP!(b, c) = fp!(a, b, c).intersection(fp!(d, c, b));
+    ///
+    /// ```
+    #[inline(always)]
+    pub fn intersection<O, Out>(self, _other: O) -> FieldPathSet<Out, UniquePaths>
+    where
+        Self: Intersection<O, Output = FieldPathSet<Out, UniquePaths>>,
+        Out: ConstDefault,
+    {
+        // Any subset of a set of unique paths is also unique.
+        FieldPathSet {
+            paths: ManuallyDrop::new(ConstDefault::DEFAULT),
+            uniqueness: PhantomData,
+        }
+    }
+
+    /// Constructs a new FieldPathSet with the field names in `self`
+    /// that aren't in `_other`.
+    ///
+    /// `_other` can be a single field name (eg: `fp!(a)`),
+    /// or a set of field names (eg: `fp!(a, b)`).
+    ///
+    /// This only works with sets of field names (eg: `fp!(a, b)`),
+    /// not with sets of nested field paths (eg: `fp!(a.b, c)`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{FP, StructuralExt, fp, make_struct};
+    ///
+    /// let this = make_struct!{ a: 3, b: 5, c: 8 };
+    ///
+    /// let _: FP!(a, c) = fp!(a, b, c).minus(fp!(b, d));
+    ///
+    /// assert_eq!( this.into_fields(fp!(a, b, c).minus(fp!(b))), (3, 8) );
+    ///
+    /// ```
+    #[inline(always)]
+    pub fn minus<O, Out>(self, _other: O) -> FieldPathSet<Out, UniquePaths>
+    where
+        Self: Difference<O, Output = FieldPathSet<Out, UniquePaths>>,
+        Out: ConstDefault,
+    {
+        // Any subset of a set of unique paths is also unique.
+        FieldPathSet {
+            paths: ManuallyDrop::new(ConstDefault::DEFAULT),
+            uniqueness: PhantomData,
+        }
+    }
+}
+
 impl<T, U> ToTList for FieldPathSet<T, U>
 where
     T: ToTList,
@@ -545,6 +657,158 @@ where
     type Output = FieldPathSet<AppendOut<T, T2>, AliasedPaths>;
 }
 
+impl<T, T2> Union<FieldPathSet<T2, UniquePaths>> for FieldPathSet<T, UniquePaths>
+where
+    T: Union<T2>,
+{
+    type Output = FieldPathSet<UnionOut<T, T2>, UniquePaths>;
+}
+
+impl<T, S> Union<TStr<S>> for FieldPathSet<T, UniquePaths>
+where
+    T: Union<(TStr<S>,)>,
+{
+    type Output = FieldPathSet<UnionOut<T, (TStr<S>,)>, UniquePaths>;
+}
+
+impl<S, O> Union<O> for TStr<S>
+where
+    FieldPathSet<(Self,), UniquePaths>: Union<O>,
+{
+    type Output = UnionOut<FieldPathSet<(Self,), UniquePaths>, O>;
+}
+
+impl<T, T2> Intersection<FieldPathSet<T2, UniquePaths>> for FieldPathSet<T, UniquePaths>
+where
+    T: Intersection<T2>,
+{
+    type Output = FieldPathSet<IntersectionOut<T, T2>, UniquePaths>;
+}
+
+impl<T, S> Intersection<TStr<S>> for FieldPathSet<T, UniquePaths>
+where
+    T: Intersection<(TStr<S>,)>,
+{
+    type Output = FieldPathSet<IntersectionOut<T, (TStr<S>,)>, UniquePaths>;
+}
+
+impl<S, O> Intersection<O> for TStr<S>
+where
+    FieldPathSet<(Self,), UniquePaths>: Intersection<O>,
+{
+    type Output = IntersectionOut<FieldPathSet<(Self,), UniquePaths>, O>;
+}
+
+impl<T, T2> Difference<FieldPathSet<T2, UniquePaths>> for FieldPathSet<T, UniquePaths>
+where
+    T: Difference<T2>,
+{
+    type Output = FieldPathSet<DifferenceOut<T, T2>, UniquePaths>;
+}
+
+impl<T, S> Difference<TStr<S>> for FieldPathSet<T, UniquePaths>
+where
+    T: Difference<(TStr<S>,)>,
+{
+    type Output = FieldPathSet<DifferenceOut<T, (TStr<S>,)>, UniquePaths>;
+}
+
+impl<S, O> Difference<O> for TStr<S>
+where
+    FieldPathSet<(Self,), UniquePaths>: Difference<O>,
+{
+    type Output = DifferenceOut<FieldPathSet<(Self,), UniquePaths>, O>;
+}
+
+/// The `FieldPathSet` with the field names in `A`,
+/// followed by the field names in `B` that aren't in `A`,
+/// returned by [`FieldPathSet::union`].
+///
+/// `A` and `B` can be single field names (eg: `FP!(a)`),
+/// or sets of field names (eg: `FP!(a, b)`).
+///
+/// This only works with sets of field names (eg: `FP!(a, b)`),
+/// not with sets of nested field paths (eg: `FP!(a.b, c)`).
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{
+///     path::{PathSetDifference, PathSetIntersection, PathSetUnion},
+///     FP, StructuralExt, Structural, fp,
+/// };
+///
+/// #[derive(Structural)]
+/// #[struc(public)]
+/// struct Document {
+///     title: String,
+///     body: String,
+///     author: String,
+///     revision: u32,
+/// }
+///
+/// // These could be declared in different modules
+/// type TextFields = FP!(title, body);
+/// type MetaFields = FP!(author, revision, title);
+///
+/// type AllFields = PathSetUnion<TextFields, MetaFields>;
+/// type SharedFields = PathSetIntersection<TextFields, MetaFields>;
+/// type OnlyMeta = PathSetDifference<MetaFields, TextFields>;
+///
+/// let _: AllFields = fp!(title, body, author, revision);
+/// let _: SharedFields = fp!(title).into_set();
+/// let _: OnlyMeta = fp!(author, revision);
+///
+/// let mut doc = Document{
+///     title: "Notes".to_string(),
+///     body: "...".to_string(),
+///     author: "anon".to_string(),
+///     revision: 0,
+/// };
+///
+/// // The `UniquePaths` guarantee is kept,so the union can be used with `fields_mut`.
+/// let all: AllFields = fp!(title, body).union(fp!(author, revision, title));
+/// let (title, _, _, revision) = doc.fields_mut(all);
+/// title.push_str(" v2");
+/// *revision += 1;
+///
+/// assert_eq!( doc.fields(fp!(title, revision)), (&"Notes v2".to_string(), &1) );
+///
+/// ```
+///
+/// [`FieldPathSet::union`]: ../struct.FieldPathSet.html#method.union
+pub type PathSetUnion<A, B> = UnionOut<A, B>;
+
+/// The `FieldPathSet` with only the field names in `A` that are also in `B`,
+/// returned by [`FieldPathSet::intersection`].
+///
+/// `A` and `B` can be single field names (eg: `FP!(a)`),
+/// or sets of field names (eg: `FP!(a, b)`).
+///
+/// This only works with sets of field names (eg: `FP!(a, b)`),
+/// not with sets of nested field paths (eg: `FP!(a.b, c)`).
+///
+/// For an example,look at the docs for [`PathSetUnion`].
+///
+/// [`FieldPathSet::intersection`]: ../struct.FieldPathSet.html#method.intersection
+/// [`PathSetUnion`]: ./type.PathSetUnion.html
+pub type PathSetIntersection<A, B> = IntersectionOut<A, B>;
+
+/// The `FieldPathSet` with the field names in `A` that aren't in `B`,
+/// returned by [`FieldPathSet::minus`].
+///
+/// `A` and `B` can be single field names (eg: `FP!(a)`),
+/// or sets of field names (eg: `FP!(a, b)`).
+///
+/// This only works with sets of field names (eg: `FP!(a, b)`),
+/// not with sets of nested field paths (eg: `FP!(a.b, c)`).
+///
+/// For an example,look at the docs for [`PathSetUnion`].
+///
+/// [`FieldPathSet::minus`]: ../struct.FieldPathSet.html#method.minus
+/// [`PathSetUnion`]: ./type.PathSetUnion.html
+pub type PathSetDifference<A, B> = DifferenceOut<A, B>;
+
 impl_cmp_traits! {
     impl[T,U] FieldPathSet<T,U>
     where[]
@@ -142,3 +142,74 @@ fn fieldpaths_push_append() {
         let _: FieldPathSet<(Fp<A>, Fp<B>, Fp<C>, Fp<D>), AliasedPaths> = b.append(fps3);
     }
 }
+
+#[test]
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+fn fieldpaths_set_algebra() {
+    use crate::path::{PathSetDifference, PathSetIntersection, PathSetUnion};
+    use core_extensions::type_asserts::AssertEq;
+
+    type Set<T> = FieldPathSet<T, UniquePaths>;
+
+    let _: AssertEq<PathSetUnion<FP!(a, b), FP!(b, c)>, FP!(a, b, c)>;
+    let _: AssertEq<PathSetUnion<FP!(c, a), FP!(a, b, c)>, FP!(c, a, b)>;
+    let _: AssertEq<PathSetUnion<Set<()>, FP!(a, b)>, FP!(a, b)>;
+    let _: AssertEq<PathSetIntersection<FP!(a, b, c), FP!(c, a)>, FP!(a, c)>;
+    let _: AssertEq<PathSetIntersection<FP!(a, b), FP!(c, d)>, Set<()>>;
+    let _: AssertEq<PathSetDifference<FP!(a, b, c), FP!(b)>, FP!(a, c)>;
+    let _: AssertEq<PathSetUnion<FP!(a), FP!(b)>, FP!(a, b)>;
+    let _: AssertEq<PathSetIntersection<FP!(a), FP!(a, b)>, Set<(FP!(a),)>>;
+    let _: AssertEq<PathSetDifference<FP!(a, b), FP!(a, b)>, Set<()>>;
+
+    let _: FP!(a, b, c, d) = fp!(a, b).union(fp!(c, a, d));
+    let _: FP!(b, c) = fp!(a, b, c).intersection(fp!(c, b, d));
+    let _: Set<(FP!(a),)> = fp!(a, b, c).minus(fp!(c, b, d));
+    let _: FP!(a, c) = fp!(a, b, c).minus(fp!(b));
+    let _: FP!(foo, bar) = fp!(foo).into_set().union(fp!(bar).into_set());
+}
+
+#[test]
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+fn compare_tstrs() {
+    use crate::type_level::cmp::{CompareOut, TEqual, TGreater, TLess};
+    use core_extensions::type_asserts::AssertEq;
+
+    let _: AssertEq<CompareOut<TS!(""), TS!("")>, TEqual>;
+    let _: AssertEq<CompareOut<TS!(""), TS!(a)>, TLess>;
+    let _: AssertEq<CompareOut<TS!(a), TS!("")>, TGreater>;
+    let _: AssertEq<CompareOut<TS!(foo), TS!(foo)>, TEqual>;
+    let _: AssertEq<CompareOut<TS!(foo), TS!(fop)>, TLess>;
+    let _: AssertEq<CompareOut<TS!(foo), TS!(fo)>, TGreater>;
+    let _: AssertEq<CompareOut<TS!(Foo), TS!(foo)>, TLess>;
+    let _: AssertEq<CompareOut<TS!("\u{FF}"), TS!("\u{FE}")>, TGreater>;
+    let _: AssertEq<
+        CompareOut<TS!(abcdefghijklmnopqrstuvwxyz_01234), TS!(abcdefghijklmnopqrstuvwxyz_01234)>,
+        TEqual,
+    >;
+}
+
+#[test]
+fn tstr_to_str() {
+    use crate::type_level::to_value_traits::ToStr;
+
+    assert_eq!(<TS!("")>::NEW.to_str(), "");
+    assert_eq!(<TS!(0)>::NEW.to_str(), "0");
+    assert_eq!(<TS!(foo)>::NEW.to_str(), "foo");
+    assert_eq!(<TS!("hello world")>::NEW.to_str(), "hello world");
+    assert_eq!(<TS!("ñ\u{FF}")>::NEW.to_str(), "ñ\u{FF}");
+    assert_eq!(
+        <TS!(abcdefghijklmnopqrstuvwxyz_01234)>::NEW.to_str(),
+        "abcdefghijklmnopqrstuvwxyz_01234",
+    );
+}
+
+#[test]
+#[should_panic]
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+fn tstr_to_str_invalid_utf8() {
+    use crate::{type_level::to_value_traits::ToStr, __TStrPriv};
+
+    type Invalid = __TStrPriv<(crate::__a, crate::__0xFF)>;
+
+    Invalid::NEW.to_str();
+}
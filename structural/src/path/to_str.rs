@@ -0,0 +1,55 @@
+use crate::type_level::to_value_traits::ToStr;
+
+// macros can contain arbitrary syntax,
+// which allows this to be defined in this file even if Rust stops parsing `const IDENT:Foo`
+#[cfg(all(feature = "use_const_str", not(feature = "disable_const_str")))]
+macro_rules! declare_const_impls {
+    () => {
+        impl<const S: &'static str> ToStr for crate::__TStrPriv<S> {
+            const BYTES: &'static [u8] = S.as_bytes();
+
+            #[inline(always)]
+            fn to_str(&self) -> &'static str {
+                S
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "use_const_str", not(feature = "disable_const_str")))]
+declare_const_impls! {}
+
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+mod tstr_type_param {
+    use super::{super::tstr_cmp::ToUnsigned, ToStr};
+    use crate::{__TStrPriv, type_level::to_value_traits::ToUsize};
+
+    macro_rules! impl_to_str {
+        () => (
+            impl ToStr for __TStrPriv<()> {
+                const BYTES: &'static [u8] = &[];
+            }
+        );
+        ( $first:ident $($rem:ident)* ) => (
+            impl<$first, $($rem,)*> ToStr for __TStrPriv<($first, $($rem,)*)>
+            where
+                $first: ToUnsigned,
+                $first::Output: ToUsize,
+                $(
+                    $rem: ToUnsigned,
+                    $rem::Output: ToUsize,
+                )*
+            {
+                const BYTES: &'static [u8] = &[
+                    <$first::Output as ToUsize>::USIZE as u8,
+                    $( <$rem::Output as ToUsize>::USIZE as u8, )*
+                ];
+            }
+
+            impl_to_str!{ $($rem)* }
+        )
+    }
+
+    // `TStr`s of up to 32 bytes can be converted to `&'static str`.
+    impl_to_str! { P0 P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11 P12 P13 P14 P15 P16 P17 P18 P19 P20 P21 P22 P23 P24 P25 P26 P27 P28 P29 P30 P31 }
+}
@@ -0,0 +1,153 @@
+//! Type-level comparison of `TStr`s,used to look up the fields of a `Record` by name.
+
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+mod tstr_type_param {
+    use crate::{
+        __TStrPriv,
+        type_level::{
+            cmp::Compare,
+            integer::{Bit0 as O, Bit1 as I, Unsigned},
+            TList, TNil,
+        },
+    };
+
+    /// Converts a type-level byte to the `Unsigned` integer with the same value.
+    pub trait ToUnsigned {
+        type Output;
+    }
+
+    macro_rules! impl_to_unsigned {
+        ( $( $byte:ident = $($bit:ident)* , )* ) => (
+            $(
+                impl ToUnsigned for crate::$byte {
+                    type Output = Unsigned<($($bit,)*)>;
+                }
+            )*
+        )
+    }
+
+    /*
+    fn main() {
+        let entries = (0..=255u32)
+            .map(|b| {
+                let bits = (0..8).rev()
+                    .map(|i| if b & (1 << i) == 0 { "O" } else { "I" })
+                    .collect::<Vec<_>>();
+                format!("__0x{:02X}={},", b, bits.join(" "))
+            })
+            .collect::<Vec<_>>();
+        for chunk in entries.chunks(4) {
+            println!("{}", chunk.join(" "));
+        }
+    }
+    */
+
+    impl_to_unsigned! {
+        __0x00=O O O O O O O O, __0x01=O O O O O O O I, __0x02=O O O O O O I O, __0x03=O O O O O O I I,
+        __0x04=O O O O O I O O, __0x05=O O O O O I O I, __0x06=O O O O O I I O, __0x07=O O O O O I I I,
+        __0x08=O O O O I O O O, __0x09=O O O O I O O I, __0x0A=O O O O I O I O, __0x0B=O O O O I O I I,
+        __0x0C=O O O O I I O O, __0x0D=O O O O I I O I, __0x0E=O O O O I I I O, __0x0F=O O O O I I I I,
+        __0x10=O O O I O O O O, __0x11=O O O I O O O I, __0x12=O O O I O O I O, __0x13=O O O I O O I I,
+        __0x14=O O O I O I O O, __0x15=O O O I O I O I, __0x16=O O O I O I I O, __0x17=O O O I O I I I,
+        __0x18=O O O I I O O O, __0x19=O O O I I O O I, __0x1A=O O O I I O I O, __0x1B=O O O I I O I I,
+        __0x1C=O O O I I I O O, __0x1D=O O O I I I O I, __0x1E=O O O I I I I O, __0x1F=O O O I I I I I,
+        __0x20=O O I O O O O O, __0x21=O O I O O O O I, __0x22=O O I O O O I O, __0x23=O O I O O O I I,
+        __0x24=O O I O O I O O, __0x25=O O I O O I O I, __0x26=O O I O O I I O, __0x27=O O I O O I I I,
+        __0x28=O O I O I O O O, __0x29=O O I O I O O I, __0x2A=O O I O I O I O, __0x2B=O O I O I O I I,
+        __0x2C=O O I O I I O O, __0x2D=O O I O I I O I, __0x2E=O O I O I I I O, __0x2F=O O I O I I I I,
+        __0x30=O O I I O O O O, __0x31=O O I I O O O I, __0x32=O O I I O O I O, __0x33=O O I I O O I I,
+        __0x34=O O I I O I O O, __0x35=O O I I O I O I, __0x36=O O I I O I I O, __0x37=O O I I O I I I,
+        __0x38=O O I I I O O O, __0x39=O O I I I O O I, __0x3A=O O I I I O I O, __0x3B=O O I I I O I I,
+        __0x3C=O O I I I I O O, __0x3D=O O I I I I O I, __0x3E=O O I I I I I O, __0x3F=O O I I I I I I,
+        __0x40=O I O O O O O O, __0x41=O I O O O O O I, __0x42=O I O O O O I O, __0x43=O I O O O O I I,
+        __0x44=O I O O O I O O, __0x45=O I O O O I O I, __0x46=O I O O O I I O, __0x47=O I O O O I I I,
+        __0x48=O I O O I O O O, __0x49=O I O O I O O I, __0x4A=O I O O I O I O, __0x4B=O I O O I O I I,
+        __0x4C=O I O O I I O O, __0x4D=O I O O I I O I, __0x4E=O I O O I I I O, __0x4F=O I O O I I I I,
+        __0x50=O I O I O O O O, __0x51=O I O I O O O I, __0x52=O I O I O O I O, __0x53=O I O I O O I I,
+        __0x54=O I O I O I O O, __0x55=O I O I O I O I, __0x56=O I O I O I I O, __0x57=O I O I O I I I,
+        __0x58=O I O I I O O O, __0x59=O I O I I O O I, __0x5A=O I O I I O I O, __0x5B=O I O I I O I I,
+        __0x5C=O I O I I I O O, __0x5D=O I O I I I O I, __0x5E=O I O I I I I O, __0x5F=O I O I I I I I,
+        __0x60=O I I O O O O O, __0x61=O I I O O O O I, __0x62=O I I O O O I O, __0x63=O I I O O O I I,
+        __0x64=O I I O O I O O, __0x65=O I I O O I O I, __0x66=O I I O O I I O, __0x67=O I I O O I I I,
+        __0x68=O I I O I O O O, __0x69=O I I O I O O I, __0x6A=O I I O I O I O, __0x6B=O I I O I O I I,
+        __0x6C=O I I O I I O O, __0x6D=O I I O I I O I, __0x6E=O I I O I I I O, __0x6F=O I I O I I I I,
+        __0x70=O I I I O O O O, __0x71=O I I I O O O I, __0x72=O I I I O O I O, __0x73=O I I I O O I I,
+        __0x74=O I I I O I O O, __0x75=O I I I O I O I, __0x76=O I I I O I I O, __0x77=O I I I O I I I,
+        __0x78=O I I I I O O O, __0x79=O I I I I O O I, __0x7A=O I I I I O I O, __0x7B=O I I I I O I I,
+        __0x7C=O I I I I I O O, __0x7D=O I I I I I O I, __0x7E=O I I I I I I O, __0x7F=O I I I I I I I,
+        __0x80=I O O O O O O O, __0x81=I O O O O O O I, __0x82=I O O O O O I O, __0x83=I O O O O O I I,
+        __0x84=I O O O O I O O, __0x85=I O O O O I O I, __0x86=I O O O O I I O, __0x87=I O O O O I I I,
+        __0x88=I O O O I O O O, __0x89=I O O O I O O I, __0x8A=I O O O I O I O, __0x8B=I O O O I O I I,
+        __0x8C=I O O O I I O O, __0x8D=I O O O I I O I, __0x8E=I O O O I I I O, __0x8F=I O O O I I I I,
+        __0x90=I O O I O O O O, __0x91=I O O I O O O I, __0x92=I O O I O O I O, __0x93=I O O I O O I I,
+        __0x94=I O O I O I O O, __0x95=I O O I O I O I, __0x96=I O O I O I I O, __0x97=I O O I O I I I,
+        __0x98=I O O I I O O O, __0x99=I O O I I O O I, __0x9A=I O O I I O I O, __0x9B=I O O I I O I I,
+        __0x9C=I O O I I I O O, __0x9D=I O O I I I O I, __0x9E=I O O I I I I O, __0x9F=I O O I I I I I,
+        __0xA0=I O I O O O O O, __0xA1=I O I O O O O I, __0xA2=I O I O O O I O, __0xA3=I O I O O O I I,
+        __0xA4=I O I O O I O O, __0xA5=I O I O O I O I, __0xA6=I O I O O I I O, __0xA7=I O I O O I I I,
+        __0xA8=I O I O I O O O, __0xA9=I O I O I O O I, __0xAA=I O I O I O I O, __0xAB=I O I O I O I I,
+        __0xAC=I O I O I I O O, __0xAD=I O I O I I O I, __0xAE=I O I O I I I O, __0xAF=I O I O I I I I,
+        __0xB0=I O I I O O O O, __0xB1=I O I I O O O I, __0xB2=I O I I O O I O, __0xB3=I O I I O O I I,
+        __0xB4=I O I I O I O O, __0xB5=I O I I O I O I, __0xB6=I O I I O I I O, __0xB7=I O I I O I I I,
+        __0xB8=I O I I I O O O, __0xB9=I O I I I O O I, __0xBA=I O I I I O I O, __0xBB=I O I I I O I I,
+        __0xBC=I O I I I I O O, __0xBD=I O I I I I O I, __0xBE=I O I I I I I O, __0xBF=I O I I I I I I,
+        __0xC0=I I O O O O O O, __0xC1=I I O O O O O I, __0xC2=I I O O O O I O, __0xC3=I I O O O O I I,
+        __0xC4=I I O O O I O O, __0xC5=I I O O O I O I, __0xC6=I I O O O I I O, __0xC7=I I O O O I I I,
+        __0xC8=I I O O I O O O, __0xC9=I I O O I O O I, __0xCA=I I O O I O I O, __0xCB=I I O O I O I I,
+        __0xCC=I I O O I I O O, __0xCD=I I O O I I O I, __0xCE=I I O O I I I O, __0xCF=I I O O I I I I,
+        __0xD0=I I O I O O O O, __0xD1=I I O I O O O I, __0xD2=I I O I O O I O, __0xD3=I I O I O O I I,
+        __0xD4=I I O I O I O O, __0xD5=I I O I O I O I, __0xD6=I I O I O I I O, __0xD7=I I O I O I I I,
+        __0xD8=I I O I I O O O, __0xD9=I I O I I O O I, __0xDA=I I O I I O I O, __0xDB=I I O I I O I I,
+        __0xDC=I I O I I I O O, __0xDD=I I O I I I O I, __0xDE=I I O I I I I O, __0xDF=I I O I I I I I,
+        __0xE0=I I I O O O O O, __0xE1=I I I O O O O I, __0xE2=I I I O O O I O, __0xE3=I I I O O O I I,
+        __0xE4=I I I O O I O O, __0xE5=I I I O O I O I, __0xE6=I I I O O I I O, __0xE7=I I I O O I I I,
+        __0xE8=I I I O I O O O, __0xE9=I I I O I O O I, __0xEA=I I I O I O I O, __0xEB=I I I O I O I I,
+        __0xEC=I I I O I I O O, __0xED=I I I O I I O I, __0xEE=I I I O I I I O, __0xEF=I I I O I I I I,
+        __0xF0=I I I I O O O O, __0xF1=I I I I O O O I, __0xF2=I I I I O O I O, __0xF3=I I I I O O I I,
+        __0xF4=I I I I O I O O, __0xF5=I I I I O I O I, __0xF6=I I I I O I I O, __0xF7=I I I I O I I I,
+        __0xF8=I I I I I O O O, __0xF9=I I I I I O O I, __0xFA=I I I I I O I O, __0xFB=I I I I I O I I,
+        __0xFC=I I I I I I O O, __0xFD=I I I I I I O I, __0xFE=I I I I I I I O, __0xFF=I I I I I I I I,
+    }
+
+    /// Converts a tuple of type-level bytes to a `TList` of `Unsigned` integers.
+    pub trait BytesToTList {
+        type Output;
+    }
+
+    macro_rules! impl_bytes_to_tlist {
+        () => (
+            impl BytesToTList for () {
+                type Output = TNil;
+            }
+        );
+        ( $first:ident $($rem:ident)* ) => (
+            impl<$first, $($rem,)*> BytesToTList for ($first, $($rem,)*)
+            where
+                $first: ToUnsigned,
+                ($($rem,)*): BytesToTList,
+            {
+                type Output = TList<
+                    <$first as ToUnsigned>::Output,
+                    <($($rem,)*) as BytesToTList>::Output,
+                >;
+            }
+
+            impl_bytes_to_tlist!{ $($rem)* }
+        )
+    }
+
+    // `TStr`s of up to 32 bytes can be compared.
+    impl_bytes_to_tlist! { P0 P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11 P12 P13 P14 P15 P16 P17 P18 P19 P20 P21 P22 P23 P24 P25 P26 P27 P28 P29 P30 P31 }
+
+    impl<L, R> Compare<__TStrPriv<R>> for __TStrPriv<L>
+    where
+        L: BytesToTList,
+        R: BytesToTList,
+        L::Output: Compare<R::Output>,
+    {
+        type Output = <L::Output as Compare<R::Output>>::Output;
+    }
+}
+
+#[cfg(any(not(feature = "use_const_str"), feature = "disable_const_str"))]
+pub(super) use self::tstr_type_param::ToUnsigned;
@@ -0,0 +1,908 @@
+/*!
+A record type with nameable fields,which are keyed by [`TStr`] type-level strings.
+
+The [`Record`] type can be written with the [`Record`](../macro.Record.html) macro,
+and constructed with the [`record`](../macro.record.html) macro.
+
+Unlike the types constructed with the [`make_struct`] macro,
+records can be stored in struct fields,and returned from trait methods.
+
+This module is not available when the `use_const_str` feature is enabled.
+
+# Example
+
+```rust
+use structural::{
+    record::Record,
+    Record, StructuralExt, fp, record,
+};
+
+struct Request {
+    // The context that the middleware adds to the request.
+    context: Record!{ user_id: u64, admin: bool },
+}
+
+let mut req = Request{
+    context: record!{ user_id: 3, admin: false },
+};
+
+assert_eq!( req.context.fields(fp!(user_id, admin)), (&3, &false) );
+
+*req.context.field_mut(fp!(admin)) = true;
+assert_eq!( req.context.field_(fp!(admin)), &true );
+
+let Record((user_id, admin)) = req.context;
+assert_eq!( (user_id.value, admin.value), (3, true) );
+
+```
+
+[`TStr`]: ../struct.TStr.html
+[`Record`]: ./struct.Record.html
+[`make_struct`]: ../macro.make_struct.html
+
+*/
+
+#![allow(non_snake_case)]
+
+use crate::{
+    convert::FromStructural,
+    field::{
+        DropFields, FieldIndex, FieldType, GetField, GetFieldMut, GetFieldRawMutFn, IntoField,
+        MovedOutFields,
+    },
+    path::{FieldPathSet, LargePathSet},
+    pmr::FieldBit,
+    type_level::{
+        cmp::{Compare, CompareOut, IsNotEqual, TEqual, TGreater, TLess},
+        collection_traits::{
+            Flatten, FlattenOut, PushBack, PushBackOut, Remove, RemoveOut, Replace, ReplaceOut,
+            ToTList, ToTListOut, ToTuple, ToTupleOut,
+        },
+        integer::{U0, U1, U10, U11, U2, U3, U4, U5, U6, U7, U8, U9},
+        TList, TNil,
+    },
+    Structural, StructuralExt, TStr,
+};
+
+use core_extensions::ConstDefault;
+
+use std_::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// A record,a tuple of [`Field`]s which can be accessed by name with the accessor traits.
+///
+/// This type is usually written with the [`Record`](../macro.Record.html) macro,
+/// and constructed with the [`record`](../macro.record.html) macro.
+///
+/// # Implemented traits
+///
+/// `Record`s of up to 12 fields implement
+/// [`FieldType`],[`GetField`],[`GetFieldMut`],[`IntoField`],
+/// [`DropFields`],and [`FromStructural`].
+///
+/// Field names can be compared when they're up to 32 bytes long.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{
+///     record::{Field, Record},
+///     convert::FromStructural,
+///     Record, StructuralExt, Structural, TS, fp, record,
+/// };
+///
+/// #[derive(Structural, Debug, PartialEq)]
+/// #[struc(public, from_structural)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// let rec: Record<(Field<TS!(x), u32>, Field<TS!(y), u32>)> =
+///     Record((Field::new(3), Field::new(5)));
+///
+/// // This is the same type as the one above
+/// let same: Record!{ x: u32, y: u32 } = record!{ x: 3, y: 5 };
+/// assert_eq!(rec, same);
+///
+/// assert_eq!( rec.into_struc::<Point>(), Point{x: 3, y: 5} );
+///
+/// let from_point: Record!{ y: u32 } = Record::from_structural(Point{x: 8, y: 13});
+/// assert_eq!( from_point.into_field(fp!(y)), 13 );
+///
+/// ```
+///
+/// [`Field`]: ./struct.Field.html
+/// [`FieldType`]: ../field/trait.FieldType.html
+/// [`GetField`]: ../field/trait.GetField.html
+/// [`GetFieldMut`]: ../field/trait.GetFieldMut.html
+/// [`IntoField`]: ../field/trait.IntoField.html
+/// [`DropFields`]: ../field/trait.DropFields.html
+/// [`FromStructural`]: ../convert/trait.FromStructural.html
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Record<L>(pub L);
+
+/// The type of the `R` record after adding a field named `N` of type `V`,
+/// returned by [`Record::insert_field`].
+///
+/// [`Record::insert_field`]: ./struct.Record.html#method.insert_field
+pub type InsertFieldOut<R, N, V> = Record<PushBackOut<<R as IsRecord>::Fields, Field<N, V>>>;
+
+/// The type of the `R` record after removing the field named `N`,
+/// returned by [`Record::remove_field`].
+///
+/// [`Record::remove_field`]: ./struct.Record.html#method.remove_field
+pub type RemoveFieldOut<R, N> = ProjectListOut<R, RemoveOut<RecordNames<R>, N>>;
+
+/// The type of the `R` record after renaming the field named `Old` to `New`,
+/// returned by [`Record::rename_field`].
+///
+/// [`Record::rename_field`]: ./struct.Record.html#method.rename_field
+pub type RenameFieldOut<R, Old, New> =
+    Record<<<R as IsRecord>::Fields as RenameFields<ReplaceOut<RecordNames<R>, Old, New>>>::Output>;
+
+/// The type of the record with the fields of the `R` record that are named by the `P` path,
+/// in the order that they're listed in,
+/// returned by [`Record::project`].
+///
+/// `P` can be the type of a single field name,or of multiple field names,
+/// eg: `FP!(a)`,`FP!(a, b)`.
+///
+/// [`Record::project`]: ./struct.Record.html#method.project
+pub type ProjectOut<R, P> = ProjectListOut<R, <P as PathNames>::Names>;
+
+/// Gets a `TList` of the field names in a path that names fields of a `Record`.
+#[doc(hidden)]
+pub trait PathNames {
+    type Names;
+}
+
+impl<S> PathNames for TStr<S> {
+    type Names = TList![TStr<S>];
+}
+
+impl<P, U> PathNames for FieldPathSet<P, U>
+where
+    P: ToTList,
+{
+    type Names = ToTListOut<P>;
+}
+
+impl<P, U> PathNames for FieldPathSet<LargePathSet<P>, U>
+where
+    P: ToTList,
+    ToTListOut<P>: Flatten,
+{
+    type Names = FlattenOut<ToTListOut<P>>;
+}
+
+/// A `TList` of the field names in the `P` path.
+#[doc(hidden)]
+pub type PathNamesOut<P> = <P as PathNames>::Names;
+
+/// Does an operation on every field name in this `TList`,in order.
+///
+/// This is used to implement traits for path sets of any length,
+/// by getting the `TList` of their field names with `PathNames`.
+#[doc(hidden)]
+pub trait ForEachName<Op: ?Sized> {
+    fn for_each_name(op: &mut Op);
+}
+
+/// An operation done on each field name by `ForEachName`.
+#[doc(hidden)]
+pub trait NameOp<N> {
+    fn name_op(&mut self, name: N);
+}
+
+impl<Op: ?Sized> ForEachName<Op> for TNil {
+    #[inline(always)]
+    fn for_each_name(_: &mut Op) {}
+}
+
+impl<Op, N, Rem> ForEachName<Op> for TList<N, Rem>
+where
+    Op: ?Sized + NameOp<N>,
+    N: ConstDefault,
+    Rem: ForEachName<Op>,
+{
+    #[inline(always)]
+    fn for_each_name(op: &mut Op) {
+        op.name_op(N::DEFAULT);
+        Rem::for_each_name(op);
+    }
+}
+
+/// Counts the field names.
+impl<N> NameOp<N> for usize {
+    #[inline(always)]
+    fn name_op(&mut self, _: N) {
+        *self += 1;
+    }
+}
+
+/// Builds the fields of a `Record` with the field names in this `TList`,in order,
+/// with the types of the fields with those names in `T`,
+/// appending them to the `Acc` tuple of `Field`s.
+///
+/// This is used to implement traits for path sets of any length,
+/// by getting the `TList` of their field names with `PathNames`.
+#[doc(hidden)]
+pub trait BuildRecordFields<T, Acc, Op: ?Sized, E> {
+    type Fields;
+
+    fn build_record_fields(acc: Acc, op: &mut Op) -> Result<Self::Fields, E>;
+}
+
+/// Makes the value of the `N` field for `BuildRecordFields`.
+#[doc(hidden)]
+pub trait MakeField<N, Ty> {
+    type Error;
+
+    fn make_field(&mut self, name: N) -> Result<Ty, Self::Error>;
+}
+
+/// The `Record` built by `BuildRecordFields`,with the fields that `P` names.
+#[doc(hidden)]
+pub type BuiltRecord<T, P, Op, E> =
+    Record<<<P as PathNames>::Names as BuildRecordFields<T, (), Op, E>>::Fields>;
+
+impl<T, Acc, Op: ?Sized, E> BuildRecordFields<T, Acc, Op, E> for TNil {
+    type Fields = Acc;
+
+    #[inline(always)]
+    fn build_record_fields(acc: Acc, _: &mut Op) -> Result<Acc, E> {
+        Ok(acc)
+    }
+}
+
+impl<T, Acc, Op, E, N, Rem> BuildRecordFields<T, Acc, Op, E> for TList<N, Rem>
+where
+    T: FieldType<N>,
+    Op: ?Sized + MakeField<N, T::Ty, Error = E>,
+    N: ConstDefault,
+    Acc: RecordPush<Field<N, T::Ty>>,
+    Rem: BuildRecordFields<T, PushBackOut<Acc, Field<N, T::Ty>>, Op, E>,
+{
+    type Fields = Rem::Fields;
+
+    #[inline(always)]
+    fn build_record_fields(acc: Acc, op: &mut Op) -> Result<Self::Fields, E> {
+        let value = op.make_field(N::DEFAULT)?;
+        Rem::build_record_fields(acc.push_field(Field::new(value)), op)
+    }
+}
+
+/// The type of the record with the fields of the `R` record named in the `List` `TList`.
+#[doc(hidden)]
+pub type ProjectListOut<R, List> =
+    Record<ToTupleOut<<List as ProjectFields<<R as IsRecord>::Fields>>::Output>>;
+
+/// A `TList` of the field names of the `R` record.
+#[doc(hidden)]
+pub type RecordNames<R> = <<R as IsRecord>::Fields as RecordFields>::Names;
+
+/// Type-level operations that return a record with a different set of fields.
+///
+/// These methods return a different type than `Self`,
+/// which can be named with the [`InsertFieldOut`],[`RemoveFieldOut`],
+/// [`RenameFieldOut`],and [`ProjectOut`] type aliases.
+///
+/// The returned record can't have more than 12 fields,
+/// and the field names must all be different.
+///
+/// # Example
+///
+/// This example demonstrates a pipeline where each stage adds or strips context fields.
+///
+/// ```rust
+/// use structural::{
+///     record::{InsertFieldOut, RemoveFieldOut},
+///     Record, StructuralExt, TS, fp, record, ts,
+/// };
+///
+/// type Request = Record!{ path: &'static str, user: &'static str };
+///
+/// type Authenticated = InsertFieldOut<Request, TS!(is_admin), bool>;
+///
+/// fn authenticate(req: Request) -> Authenticated {
+///     let is_admin = *req.field_(fp!(user)) == "root";
+///     req.insert_field(ts!(is_admin), is_admin)
+/// }
+///
+/// fn anonymize(req: Authenticated) -> RemoveFieldOut<Authenticated, TS!(user)> {
+///     req.remove_field(ts!(user))
+/// }
+///
+/// let req = anonymize(authenticate(record!{ path: "/", user: "root" }));
+/// assert_eq!( req, record!{ path: "/", is_admin: true } );
+///
+/// let req = req.rename_field(ts!(path), ts!(route));
+/// assert_eq!( req.fields(fp!(route, is_admin)), (&"/", &true) );
+///
+/// assert_eq!( req.project(fp!(is_admin)), record!{ is_admin: true } );
+///
+/// ```
+///
+/// [`InsertFieldOut`]: ./type.InsertFieldOut.html
+/// [`RemoveFieldOut`]: ./type.RemoveFieldOut.html
+/// [`RenameFieldOut`]: ./type.RenameFieldOut.html
+/// [`ProjectOut`]: ./type.ProjectOut.html
+impl<L: RecordFields> Record<L> {
+    /// Adds a field named `name` with `value`,after all the other fields.
+    ///
+    /// This requires that the record doesn't already have a field named `name`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralExt, fp, record, ts};
+    ///
+    /// let this = record!{ a: 3 }.insert_field(ts!(b), "five");
+    ///
+    /// assert_eq!( this.fields(fp!(a, b)), (&3, &"five") );
+    /// ```
+    #[inline]
+    pub fn insert_field<N, V>(self, name: N, value: V) -> InsertFieldOut<Self, N, V>
+    where
+        L: RecordPush<Field<N, V>>,
+        L::Names: PushBack<N>,
+        PushBackOut<L::Names, N>: UniqueNames,
+    {
+        let _ = name;
+        Record(self.0.push_field(Field::new(value)))
+    }
+
+    /// Removes the field named `name`,dropping its value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{record, ts};
+    ///
+    /// let this = record!{ a: 3, b: "five", c: 'c' }.remove_field(ts!(b));
+    ///
+    /// assert_eq!( this, record!{ a: 3, c: 'c' } );
+    /// ```
+    #[inline]
+    pub fn remove_field<N>(self, name: N) -> RemoveFieldOut<Self, N>
+    where
+        L::Names: Remove<N>,
+        RemoveOut<L::Names, N>: ProjectFields<L>,
+        <RemoveOut<L::Names, N> as ProjectFields<L>>::Output: ToTuple,
+        RemoveFieldOut<Self, N>: FromStructural<Self>,
+    {
+        let _ = name;
+        FromStructural::from_structural(self)
+    }
+
+    /// Renames the field named `old` to `new`,keeping its position.
+    ///
+    /// This requires that the record doesn't already have a field named `new`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{record, ts};
+    ///
+    /// let this = record!{ a: 3, b: "five" }.rename_field(ts!(a), ts!(z));
+    ///
+    /// assert_eq!( this, record!{ z: 3, b: "five" } );
+    /// ```
+    #[inline]
+    pub fn rename_field<Old, New>(self, old: Old, new: New) -> RenameFieldOut<Self, Old, New>
+    where
+        L::Names: Replace<Old, New>,
+        ReplaceOut<L::Names, Old, New>: UniqueNames,
+        L: RenameFields<ReplaceOut<L::Names, Old, New>>,
+    {
+        let _ = (old, new);
+        Record(self.0.rename_fields())
+    }
+
+    /// Constructs a record with the fields named by `path`,
+    /// in the order that they're listed in,
+    /// dropping the other fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{fp, record};
+    ///
+    /// let this = record!{ a: 3, b: "five", c: 'c' }.project(fp!(c, a));
+    ///
+    /// assert_eq!( this, record!{ c: 'c', a: 3 } );
+    /// ```
+    #[inline]
+    pub fn project<P>(self, path: P) -> ProjectOut<Self, P>
+    where
+        P: PathNames,
+        P::Names: ProjectFields<L>,
+        <P::Names as ProjectFields<L>>::Output: ToTuple,
+        ProjectOut<Self, P>: FromStructural<Self>,
+    {
+        let _ = path;
+        FromStructural::from_structural(self)
+    }
+}
+
+/// A field of a [`Record`],named by the `N` [`TStr`].
+///
+/// [`Record`]: ./struct.Record.html
+/// [`TStr`]: ../struct.TStr.html
+#[repr(transparent)]
+pub struct Field<N, T> {
+    /// The value of the field.
+    pub value: T,
+    name: PhantomData<N>,
+}
+
+impl<N, T> Field<N, T> {
+    /// Constructs a `Field` with its value.
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Field {
+            value,
+            name: PhantomData,
+        }
+    }
+
+    /// Unwraps this into the value of the field.
+    #[inline(always)]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<N, T: Copy> Copy for Field<N, T> {}
+
+impl<N, T: Clone> Clone for Field<N, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Field::new(self.value.clone())
+    }
+}
+
+impl<N, T: Debug> Debug for Field<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.value, f)
+    }
+}
+
+impl<N, T: Default> Default for Field<N, T> {
+    #[inline]
+    fn default() -> Self {
+        Field::new(T::default())
+    }
+}
+
+impl<N, T: PartialEq> PartialEq for Field<N, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<N, T: Eq> Eq for Field<N, T> {}
+
+impl<N, T: PartialOrd> PartialOrd for Field<N, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<N, T: Ord> Ord for Field<N, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<N, T: Hash> Hash for Field<N, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Implemented by the tuples of `Field`s that `Record` wraps.
+#[doc(hidden)]
+pub trait RecordFields {
+    /// A `TList` of the names of all the fields.
+    type Names;
+
+    /// A `TList` of `(name, index)` pairs for all the fields.
+    type IndexedNames;
+}
+
+/// Adds a field after the fields of a `Record`.
+#[doc(hidden)]
+pub trait RecordPush<F>: PushBack<F> {
+    fn push_field(self, field: F) -> PushBackOut<Self, F>;
+}
+
+/// Replaces the names of all the fields of a `Record` with the ones in the `Names` `TList`.
+#[doc(hidden)]
+pub trait RenameFields<Names> {
+    type Output;
+
+    fn rename_fields(self) -> Self::Output;
+}
+
+/// Gets a `TList` of the fields in `L` with the names in this `TList`.
+#[doc(hidden)]
+pub trait ProjectFields<L> {
+    type Output;
+}
+
+impl<L> ProjectFields<L> for TNil {
+    type Output = TNil;
+}
+
+impl<L, N, Rem> ProjectFields<L> for TList<N, Rem>
+where
+    L: RecordField<N>,
+    Rem: ProjectFields<L>,
+{
+    type Output = TList<Field<N, L::Ty>, Rem::Output>;
+}
+
+/// Gets the tuple of fields that a `Record` wraps.
+#[doc(hidden)]
+pub trait IsRecord {
+    type Fields: RecordFields;
+}
+
+impl<L: RecordFields> IsRecord for Record<L> {
+    type Fields = L;
+}
+
+/// Gets the field at the `Index` position,
+/// where `Index` is a type-level `Unsigned` integer.
+///
+/// # Safety
+///
+/// `INDEX` must be the position of the field,
+/// and `elem_raw_mut` must return a pointer to that field.
+#[doc(hidden)]
+pub unsafe trait RecordElem<Index> {
+    type Ty;
+    const INDEX: u8;
+
+    fn elem(&self) -> &Self::Ty;
+
+    fn elem_mut(&mut self) -> &mut Self::Ty;
+
+    fn into_elem(self) -> Self::Ty;
+
+    /// # Safety
+    ///
+    /// `this` must point to a fully initialized instance of `Self`.
+    unsafe fn elem_raw_mut(this: *mut Self) -> *mut Self::Ty;
+}
+
+/// Finds the index of the `N` field in a `TList` of `(name, index)` pairs.
+#[doc(hidden)]
+pub trait FindIndex<N> {
+    type Index;
+}
+
+impl<N, Name, Index, Rem> FindIndex<N> for TList<(Name, Index), Rem>
+where
+    N: Compare<Name>,
+    CompareOut<N, Name>: FindIndexHelper<N, Index, Rem>,
+{
+    type Index = <CompareOut<N, Name> as FindIndexHelper<N, Index, Rem>>::Index;
+}
+
+#[doc(hidden)]
+pub trait FindIndexHelper<N, Index, Rem> {
+    type Index;
+}
+
+impl<N, Index, Rem> FindIndexHelper<N, Index, Rem> for TEqual {
+    type Index = Index;
+}
+
+impl<N, Index, Rem> FindIndexHelper<N, Index, Rem> for TLess
+where
+    Rem: FindIndex<N>,
+{
+    type Index = Rem::Index;
+}
+
+impl<N, Index, Rem> FindIndexHelper<N, Index, Rem> for TGreater
+where
+    Rem: FindIndex<N>,
+{
+    type Index = Rem::Index;
+}
+
+/// Gets the field named `N`.
+///
+/// # Safety
+///
+/// This has the same requirements as `RecordElem`.
+#[doc(hidden)]
+pub unsafe trait RecordField<N> {
+    type Ty;
+    const INDEX: u8;
+
+    fn field(&self) -> &Self::Ty;
+
+    fn field_mut(&mut self) -> &mut Self::Ty;
+
+    fn into_field(self) -> Self::Ty;
+
+    /// # Safety
+    ///
+    /// `this` must point to a fully initialized instance of `Self`.
+    unsafe fn field_raw_mut(this: *mut Self) -> *mut Self::Ty;
+}
+
+unsafe impl<L, N> RecordField<N> for L
+where
+    L: RecordFields,
+    L::IndexedNames: FindIndex<N>,
+    L: RecordElem<<L::IndexedNames as FindIndex<N>>::Index>,
+{
+    type Ty = <L as RecordElem<<L::IndexedNames as FindIndex<N>>::Index>>::Ty;
+    const INDEX: u8 = <L as RecordElem<<L::IndexedNames as FindIndex<N>>::Index>>::INDEX;
+
+    #[inline(always)]
+    fn field(&self) -> &Self::Ty {
+        self.elem()
+    }
+
+    #[inline(always)]
+    fn field_mut(&mut self) -> &mut Self::Ty {
+        self.elem_mut()
+    }
+
+    #[inline(always)]
+    fn into_field(self) -> Self::Ty {
+        self.into_elem()
+    }
+
+    #[inline(always)]
+    unsafe fn field_raw_mut(this: *mut Self) -> *mut Self::Ty {
+        <L as RecordElem<<L::IndexedNames as FindIndex<N>>::Index>>::elem_raw_mut(this)
+    }
+}
+
+/// A marker trait for `TList`s of names where no name is repeated.
+#[doc(hidden)]
+pub trait UniqueNames {}
+
+impl UniqueNames for TNil {}
+
+impl<N, Rem> UniqueNames for TList<N, Rem> where Rem: NotInList<N> + UniqueNames {}
+
+/// A marker trait for `TList`s of names that don't contain `N`.
+#[doc(hidden)]
+pub trait NotInList<N> {}
+
+impl<N> NotInList<N> for TNil {}
+
+impl<N, Name, Rem> NotInList<N> for TList<Name, Rem>
+where
+    N: Compare<Name>,
+    CompareOut<N, Name>: IsNotEqual,
+    Rem: NotInList<N>,
+{
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+impl<L> Structural for Record<L> {}
+
+impl<L, S> FieldType<TStr<S>> for Record<L>
+where
+    L: RecordField<TStr<S>>,
+{
+    type Ty = L::Ty;
+}
+
+impl<L, S> GetField<TStr<S>> for Record<L>
+where
+    L: RecordField<TStr<S>>,
+{
+    #[inline(always)]
+    fn get_field_(&self, _: TStr<S>) -> &Self::Ty {
+        self.0.field()
+    }
+}
+
+impl<L, S> FieldIndex<TStr<S>> for Record<L>
+where
+    L: RecordField<TStr<S>>,
+{
+    const INDEX: u8 = L::INDEX;
+}
+
+unsafe impl<L, S> GetFieldMut<TStr<S>> for Record<L>
+where
+    L: RecordField<TStr<S>>,
+{
+    #[inline(always)]
+    fn get_field_mut_(&mut self, _: TStr<S>) -> &mut Self::Ty {
+        self.0.field_mut()
+    }
+
+    #[inline(always)]
+    unsafe fn get_field_raw_mut(this: *mut (), _: TStr<S>) -> *mut Self::Ty {
+        // `Record` is a `#[repr(transparent)]` wrapper around `L`
+        L::field_raw_mut(this as *mut L)
+    }
+
+    #[inline(always)]
+    fn get_field_raw_mut_fn(&self) -> GetFieldRawMutFn<TStr<S>, Self::Ty> {
+        <Self as GetFieldMut<TStr<S>>>::get_field_raw_mut
+    }
+}
+
+unsafe impl<L, S> IntoField<TStr<S>> for Record<L>
+where
+    L: RecordField<TStr<S>> + DropFields,
+{
+    #[inline(always)]
+    fn into_field_(self, _: TStr<S>) -> Self::Ty {
+        self.0.into_field()
+    }
+
+    #[inline(always)]
+    unsafe fn move_out_field_(
+        &mut self,
+        _: TStr<S>,
+        moved_fields: &mut MovedOutFields,
+    ) -> Self::Ty {
+        moved_fields.set_moved_out(FieldBit::new(L::INDEX));
+        L::field_raw_mut(&mut self.0).read()
+    }
+}
+
+// The `FieldBit`s set in `IntoField::move_out_field_` are the same ones that
+// the `DropFields` impls for tuples use.
+unsafe impl<L> DropFields for Record<L>
+where
+    L: DropFields,
+{
+    #[inline(always)]
+    fn pre_move(&mut self) {
+        self.0.pre_move()
+    }
+
+    #[inline(always)]
+    unsafe fn drop_fields(&mut self, moved: MovedOutFields) {
+        self.0.drop_fields(moved)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_record_fields {
+    (
+        $([ $( ($index:tt, $name:ident, $ty:ident, $uint:ident, $new_name:ident) ),* ])*
+    ) => {
+        $(
+            impl<$($name, $ty,)*> RecordFields for ($(Field<$name, $ty>,)*) {
+                type Names = TList![$($name),*];
+                type IndexedNames = TList![$( ($name, $uint) ),*];
+            }
+
+            impl<$($name, $ty,)* F> RecordPush<F> for ($(Field<$name, $ty>,)*) {
+                #[inline]
+                fn push_field(self, field: F) -> PushBackOut<Self, F> {
+                    let ($($ty,)*) = self;
+                    ($($ty,)* field,)
+                }
+            }
+
+            impl<$($name, $ty, $new_name,)*> RenameFields<TList![$($new_name),*]>
+            for ($(Field<$name, $ty>,)*)
+            {
+                type Output = ($(Field<$new_name, $ty>,)*);
+
+                #[inline]
+                #[allow(clippy::unused_unit)]
+                fn rename_fields(self) -> Self::Output {
+                    let ($($ty,)*) = self;
+                    ($(Field::new($ty.value),)*)
+                }
+            }
+
+            impl_record_fields!{
+                @elems [$( ($name, $ty) ),*] $( ($index, $name, $ty, $uint) )*
+            }
+
+            z_impl_from_structural!{
+                impl[F, $($name, $ty,)*] FromStructural<F> for Record<($(Field<TStr<$name>, $ty>,)*)>
+                where[
+                    F: $( IntoField<TStr<$name>, Ty = $ty> + )*,
+                    TList![$(TStr<$name>),*]: UniqueNames,
+                ]
+                {
+                    fn from_structural(value){
+                        // The `UniqueNames` bound ensures that the field names are all unique.
+                        let path_set = unsafe{
+                            let x = LargePathSet(path_tuple!( $(TStr::<$name>::NEW,)* ));
+                            FieldPathSet::many(x).upgrade_unchecked()
+                        };
+                        let field_pat!($($ty,)*) = value.into_fields(path_set);
+                        Record(($(Field::new($ty),)*))
+                    }
+                }
+            }
+        )*
+    };
+    (@elems $params:tt $( ($index:tt, $name:ident, $ty:ident, $uint:ident) )* ) => {
+        $(
+            impl_record_fields!{ @elem $params ($index, $name, $ty, $uint) }
+        )*
+    };
+    (
+        @elem
+        [$( ($p_name:ident, $p_ty:ident) ),*]
+        ($index:tt, $name:ident, $ty:ident, $uint:ident)
+    ) => {
+        unsafe impl<$($p_name, $p_ty,)*> RecordElem<$uint> for ($(Field<$p_name, $p_ty>,)*) {
+            type Ty = $ty;
+            const INDEX: u8 = $index;
+
+            #[inline(always)]
+            fn elem(&self) -> &$ty {
+                &self.$index.value
+            }
+
+            #[inline(always)]
+            fn elem_mut(&mut self) -> &mut $ty {
+                &mut self.$index.value
+            }
+
+            #[inline(always)]
+            fn into_elem(self) -> $ty {
+                self.$index.value
+            }
+
+            #[inline(always)]
+            unsafe fn elem_raw_mut(this: *mut Self) -> *mut $ty {
+                &mut (*this).$index.value as *mut $ty
+            }
+        }
+    };
+}
+
+/*
+fn main() {
+    for len in 0..=12 {
+        let fields = (0..len)
+            .map(|i| format!("({0},N{0},T{0},U{0},M{0})", i))
+            .collect::<Vec<_>>();
+        println!("    [{}]", fields.join(","));
+    }
+}
+*/
+
+impl_record_fields! {
+    []
+    [(0,N0,T0,U0,M0)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2),(3,N3,T3,U3,M3)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2),(3,N3,T3,U3,M3),(4,N4,T4,U4,M4)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2),(3,N3,T3,U3,M3),(4,N4,T4,U4,M4),(5,N5,T5,U5,M5)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2),(3,N3,T3,U3,M3),(4,N4,T4,U4,M4),(5,N5,T5,U5,M5),(6,N6,T6,U6,M6)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2),(3,N3,T3,U3,M3),(4,N4,T4,U4,M4),(5,N5,T5,U5,M5),(6,N6,T6,U6,M6),(7,N7,T7,U7,M7)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2),(3,N3,T3,U3,M3),(4,N4,T4,U4,M4),(5,N5,T5,U5,M5),(6,N6,T6,U6,M6),(7,N7,T7,U7,M7),(8,N8,T8,U8,M8)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2),(3,N3,T3,U3,M3),(4,N4,T4,U4,M4),(5,N5,T5,U5,M5),(6,N6,T6,U6,M6),(7,N7,T7,U7,M7),(8,N8,T8,U8,M8),(9,N9,T9,U9,M9)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2),(3,N3,T3,U3,M3),(4,N4,T4,U4,M4),(5,N5,T5,U5,M5),(6,N6,T6,U6,M6),(7,N7,T7,U7,M7),(8,N8,T8,U8,M8),(9,N9,T9,U9,M9),(10,N10,T10,U10,M10)]
+    [(0,N0,T0,U0,M0),(1,N1,T1,U1,M1),(2,N2,T2,U2,M2),(3,N3,T3,U3,M3),(4,N4,T4,U4,M4),(5,N5,T5,U5,M5),(6,N6,T6,U6,M6),(7,N7,T7,U7,M7),(8,N8,T8,U8,M8),(9,N9,T9,U9,M9),(10,N10,T10,U10,M10),(11,N11,T11,U11,M11)]
+}
@@ -0,0 +1,583 @@
+/*!
+A struct-of-arrays container,storing each field of a type in its own `Vec`.
+
+[`SoaVec<T>`] stores the public fields of `T` in separate `Vec`s (the columns),
+which is more cache friendly than a `Vec<T>` when code only accesses a few fields at a time.
+
+The `#[struc(soa)]` attribute of the `Structural` derive implements [`SoaFields`],
+which is required to use a type in a [`SoaVec`].
+
+Elements are accessed through the [`SoaRef`] and [`SoaMut`] views,
+which implement the accessor traits for the fields of the element,
+so they can be passed to functions written against the structural alias of the type.
+Both views implement `IntoField` by cloning the field,
+since the element is still owned by the [`SoaVec`].
+
+This module requires the "alloc" feature.
+
+# Example
+
+```rust
+use structural::{
+    soa::{SoaVec, SoaRef},
+    Structural, StructuralExt, fp,
+};
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, soa)]
+struct Particle {
+    pos: f32,
+    vel: f32,
+    #[struc(rename = "kg")]
+    mass: u32,
+}
+
+// `Particle_SI` is the structural alias generated by the `Structural` derive.
+fn energy(this: &impl Particle_SI) -> f32 {
+    let (vel, mass) = this.fields(fp!(vel, kg));
+    *vel * *vel * (*mass as f32) / 2.0
+}
+
+let mut particles = SoaVec::new();
+particles.push(Particle{ pos: 0.0, vel: 2.0, mass: 3 });
+particles.push(Particle{ pos: 1.0, vel: 4.0, mass: 1 });
+
+{
+    let (positions, velocities) = particles.columns_mut(fp!(pos, vel));
+    for (pos, vel) in positions.iter_mut().zip(&*velocities) {
+        *pos += *vel;
+    }
+}
+assert_eq!(particles.column(fp!(pos)), &[2.0, 5.0]);
+assert_eq!(particles.column(fp!(kg)), &[3, 1]);
+
+let first: SoaRef<'_, Particle> = particles.get(0).unwrap();
+assert_eq!(first.fields(fp!(pos, vel, kg)), (&2.0, &2.0, &3));
+
+// `Particle_SI` requires mutable and by-value access to the fields,
+// `SoaMut` provides mutable access,and by-value access by cloning the fields.
+assert_eq!(energy(&particles.get_mut(0).unwrap()), 6.0);
+
+*particles.get_mut(1).unwrap().field_mut(fp!(vel)) = 0.0;
+assert_eq!(particles.column(fp!(vel)), &[2.0, 0.0]);
+
+```
+
+[`SoaVec<T>`]: ./struct.SoaVec.html
+[`SoaVec`]: ./struct.SoaVec.html
+[`SoaFields`]: ./trait.SoaFields.html
+[`SoaRef`]: ./struct.SoaRef.html
+[`SoaMut`]: ./struct.SoaMut.html
+
+*/
+
+use crate::{
+    field::{
+        DropFields, FieldType, GetField, GetFieldMut, GetFieldRawMutFn, IntoField, MovedOutFields,
+        RevGetMultiFieldMut, RevGetMultiFieldMutOut,
+    },
+    Structural, StructuralExt,
+};
+
+use alloc::vec::Vec;
+
+use std_::iter::FromIterator;
+
+/// A type that can be stored in a [`SoaVec`],with each public field in its own `Vec`.
+///
+/// This is implemented by the `#[struc(soa)]` attribute of the `Structural` derive,
+/// which declares a `<DerivingType>Columns` struct with a `Vec` for each public field,
+/// using the same (possibly renamed) field names.
+///
+/// [`SoaVec`]: ./struct.SoaVec.html
+pub trait SoaFields: Sized {
+    /// The columns,a struct with a `Vec` for each public field of `Self`,
+    /// which implements the accessor traits for those `Vec`s.
+    type Columns: Default;
+
+    /// Pushes the public fields of `self` into the columns,
+    /// dropping the private fields.
+    fn push_columns(self, columns: &mut Self::Columns);
+
+    /// Reserves capacity for at least `additional` more elements in every column.
+    fn reserve_columns(columns: &mut Self::Columns, additional: usize);
+
+    /// Shortens every column to `len` elements.
+    fn truncate_columns(columns: &mut Self::Columns, len: usize);
+}
+
+/// A struct-of-arrays container,storing each public field of `T` in its own `Vec`.
+///
+/// For an example,[look at the module-level documentation](./index.html).
+pub struct SoaVec<T: SoaFields> {
+    columns: T::Columns,
+    len: usize,
+}
+
+impl<T: SoaFields> SoaVec<T> {
+    /// Constructs an empty `SoaVec`.
+    #[inline]
+    pub fn new() -> Self {
+        SoaVec {
+            columns: T::Columns::default(),
+            len: 0,
+        }
+    }
+
+    /// Constructs an empty `SoaVec` with capacity for at least `capacity` elements
+    /// in every column.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut this = Self::new();
+        this.reserve(capacity);
+        this
+    }
+
+    /// The amount of elements.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends `value` to the end,
+    /// moving each public field into its column with `StructuralExt::into_fields`.
+    pub fn push(&mut self, value: T) {
+        value.push_columns(&mut self.columns);
+        self.len += 1;
+    }
+
+    /// Reserves capacity for at least `additional` more elements in every column.
+    pub fn reserve(&mut self, additional: usize) {
+        T::reserve_columns(&mut self.columns, additional);
+    }
+
+    /// Shortens the `SoaVec` to `len` elements,
+    /// doing nothing if `len` is greater than or equal to the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            T::truncate_columns(&mut self.columns, len);
+            self.len = len;
+        }
+    }
+
+    /// Removes all the elements.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Gets the column for the `path` field,with the value of the field for every element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{soa::SoaVec, Structural, fp};
+    ///
+    /// #[derive(Structural)]
+    /// #[struc(public, soa)]
+    /// struct Point(u32, u32);
+    ///
+    /// let soa = vec![Point(3, 5), Point(8, 13)].into_iter().collect::<SoaVec<_>>();
+    ///
+    /// assert_eq!( soa.column(fp!(0)), &[3, 8] );
+    /// assert_eq!( soa.column(fp!(1)), &[5, 13] );
+    ///
+    /// ```
+    #[inline]
+    pub fn column<P>(&self, path: P) -> &[T::Ty]
+    where
+        T: FieldType<P>,
+        T::Columns: GetField<P, Ty = Vec<T::Ty>>,
+    {
+        self.columns.get_field_(path)
+    }
+
+    /// Gets the column for the `path` field mutably,
+    /// with the value of the field for every element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{soa::SoaVec, Structural, fp};
+    ///
+    /// #[derive(Structural)]
+    /// #[struc(public, soa)]
+    /// struct Point{
+    ///     x: u32,
+    ///     y: u32,
+    /// }
+    ///
+    /// let mut soa = SoaVec::new();
+    /// soa.push(Point{ x: 3, y: 5 });
+    /// soa.push(Point{ x: 8, y: 13 });
+    ///
+    /// for x in soa.column_mut(fp!(x)) {
+    ///     *x *= 2;
+    /// }
+    ///
+    /// assert_eq!( soa.column(fp!(x)), &[6, 16] );
+    /// assert_eq!( soa.column(fp!(y)), &[5, 13] );
+    ///
+    /// ```
+    #[inline]
+    pub fn column_mut<P>(&mut self, path: P) -> &mut [T::Ty]
+    where
+        T: FieldType<P>,
+        T::Columns: GetFieldMut<P, Ty = Vec<T::Ty>>,
+    {
+        self.columns.get_field_mut_(path)
+    }
+
+    /// Gets the columns for the `paths` fields mutably,
+    /// as a tuple of mutable slices.
+    ///
+    /// For an example,[look at the module-level documentation](./index.html).
+    #[inline]
+    pub fn columns_mut<'a, P>(
+        &'a mut self,
+        paths: P,
+    ) -> IntoColumnSlicesOut<RevGetMultiFieldMutOut<'a, P, T::Columns>>
+    where
+        P: RevGetMultiFieldMut<'a, T::Columns>,
+        RevGetMultiFieldMutOut<'a, P, T::Columns>: IntoColumnSlices,
+    {
+        self.columns.fields_mut(paths).into_column_slices()
+    }
+
+    /// Gets a reference to all the columns.
+    #[inline]
+    pub fn columns(&self) -> &T::Columns {
+        &self.columns
+    }
+
+    /// Gets a view of the element at the `index` position,
+    /// returning None if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<SoaRef<'_, T>> {
+        if index < self.len {
+            Some(SoaRef {
+                columns: &self.columns,
+                index,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Gets a mutable view of the element at the `index` position,
+    /// returning None if `index` is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<SoaMut<'_, T>> {
+        if index < self.len {
+            Some(SoaMut {
+                columns: &mut self.columns,
+                index,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over views of all the elements.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = SoaRef<'_, T>> + '_ {
+        let columns = &self.columns;
+        (0..self.len).map(move |index| SoaRef { columns, index })
+    }
+}
+
+impl<T: SoaFields> Default for SoaVec<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: SoaFields> Extend<T> for SoaVec<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: SoaFields> FromIterator<T> for SoaVec<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A view of an element in a [`SoaVec`],
+/// which implements `GetField` for every public field of `T`,
+/// and `IntoField` for the fields that implement `Clone`,by cloning them.
+///
+/// [`SoaVec`]: ./struct.SoaVec.html
+pub struct SoaRef<'a, T: SoaFields> {
+    columns: &'a T::Columns,
+    index: usize,
+}
+
+impl<'a, T: SoaFields> SoaRef<'a, T> {
+    /// The position of the element in the `SoaVec`.
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl<'a, T: SoaFields> Copy for SoaRef<'a, T> {}
+
+impl<'a, T: SoaFields> Clone for SoaRef<'a, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: SoaFields> Structural for SoaRef<'a, T> {}
+
+impl<'a, T, P> FieldType<P> for SoaRef<'a, T>
+where
+    T: SoaFields + FieldType<P>,
+{
+    type Ty = T::Ty;
+}
+
+impl<'a, T, P> GetField<P> for SoaRef<'a, T>
+where
+    T: SoaFields + FieldType<P>,
+    T::Columns: GetField<P, Ty = Vec<T::Ty>>,
+{
+    #[inline(always)]
+    fn get_field_(&self, path: P) -> &T::Ty {
+        &self.columns.get_field_(path)[self.index]
+    }
+}
+
+unsafe impl<'a, T, P> IntoField<P> for SoaRef<'a, T>
+where
+    T: SoaFields + FieldType<P>,
+    T::Columns: GetField<P, Ty = Vec<T::Ty>>,
+    T::Ty: Clone,
+{
+    #[inline(always)]
+    fn into_field_(self, path: P) -> T::Ty {
+        self.get_field_(path).clone()
+    }
+
+    #[inline(always)]
+    unsafe fn move_out_field_(&mut self, path: P, _: &mut MovedOutFields) -> T::Ty {
+        self.get_field_(path).clone()
+    }
+}
+
+unsafe impl<'a, T: SoaFields> DropFields for SoaRef<'a, T> {
+    #[inline(always)]
+    fn pre_move(&mut self) {}
+
+    #[inline(always)]
+    unsafe fn drop_fields(&mut self, _: MovedOutFields) {
+        // The fields are cloned when moved out,so there's nothing to drop here.
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A mutable view of an element in a [`SoaVec`],
+/// which implements `GetField` and `GetFieldMut` for every public field of `T`,
+/// and `IntoField` for the fields that implement `Clone`,by cloning them.
+///
+/// [`SoaVec`]: ./struct.SoaVec.html
+pub struct SoaMut<'a, T: SoaFields> {
+    columns: &'a mut T::Columns,
+    index: usize,
+}
+
+impl<'a, T: SoaFields> SoaMut<'a, T> {
+    /// The position of the element in the `SoaVec`.
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Reborrows this as a `SoaRef`.
+    #[inline(always)]
+    pub fn as_ref(&self) -> SoaRef<'_, T> {
+        SoaRef {
+            columns: self.columns,
+            index: self.index,
+        }
+    }
+}
+
+impl<'a, T: SoaFields> Structural for SoaMut<'a, T> {}
+
+impl<'a, T, P> FieldType<P> for SoaMut<'a, T>
+where
+    T: SoaFields + FieldType<P>,
+{
+    type Ty = T::Ty;
+}
+
+impl<'a, T, P> GetField<P> for SoaMut<'a, T>
+where
+    T: SoaFields + FieldType<P>,
+    T::Columns: GetField<P, Ty = Vec<T::Ty>>,
+{
+    #[inline(always)]
+    fn get_field_(&self, path: P) -> &T::Ty {
+        &self.columns.get_field_(path)[self.index]
+    }
+}
+
+unsafe impl<'a, T, P> GetFieldMut<P> for SoaMut<'a, T>
+where
+    T: SoaFields + FieldType<P>,
+    T::Columns: GetFieldMut<P, Ty = Vec<T::Ty>>,
+{
+    #[inline(always)]
+    fn get_field_mut_(&mut self, path: P) -> &mut T::Ty {
+        &mut self.columns.get_field_mut_(path)[self.index]
+    }
+
+    unsafe fn get_field_raw_mut(this: *mut (), path: P) -> *mut T::Ty {
+        let this = this as *mut Self;
+        let index = (*this).index;
+        let columns: *mut T::Columns = &mut *(*this).columns;
+        let column = <T::Columns as GetFieldMut<P>>::get_field_raw_mut(columns as *mut (), path);
+        let column: &mut Vec<T::Ty> = &mut *column;
+        &mut column[index]
+    }
+
+    #[inline(always)]
+    fn get_field_raw_mut_fn(&self) -> GetFieldRawMutFn<P, T::Ty> {
+        <Self as GetFieldMut<P>>::get_field_raw_mut
+    }
+}
+
+unsafe impl<'a, T, P> IntoField<P> for SoaMut<'a, T>
+where
+    T: SoaFields + FieldType<P>,
+    T::Columns: GetField<P, Ty = Vec<T::Ty>>,
+    T::Ty: Clone,
+{
+    #[inline(always)]
+    fn into_field_(self, path: P) -> T::Ty {
+        self.get_field_(path).clone()
+    }
+
+    #[inline(always)]
+    unsafe fn move_out_field_(&mut self, path: P, _: &mut MovedOutFields) -> T::Ty {
+        self.get_field_(path).clone()
+    }
+}
+
+unsafe impl<'a, T: SoaFields> DropFields for SoaMut<'a, T> {
+    #[inline(always)]
+    fn pre_move(&mut self) {}
+
+    #[inline(always)]
+    unsafe fn drop_fields(&mut self, _: MovedOutFields) {
+        // The fields are cloned when moved out,so there's nothing to drop here.
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Converts the mutable references to the `Vec` columns of a [`SoaVec`] into mutable slices,
+/// so that their length can't be changed.
+///
+/// This is used by [`SoaVec::columns_mut`],
+/// and is implemented for `&mut Vec<T>` and tuples of types that implement this.
+///
+/// [`SoaVec`]: ./struct.SoaVec.html
+/// [`SoaVec::columns_mut`]: ./struct.SoaVec.html#method.columns_mut
+pub trait IntoColumnSlices {
+    /// The mutable slices this is converted into.
+    type Slices;
+
+    /// Performs the conversion.
+    fn into_column_slices(self) -> Self::Slices;
+}
+
+/// The type `This` is converted into when calling `.into_column_slices()`.
+pub type IntoColumnSlicesOut<This> = <This as IntoColumnSlices>::Slices;
+
+impl<'a, T> IntoColumnSlices for &'a mut Vec<T> {
+    type Slices = &'a mut [T];
+
+    #[inline(always)]
+    fn into_column_slices(self) -> Self::Slices {
+        self
+    }
+}
+
+macro_rules! column_slices_tuple {
+    (
+        $(( $(($elem:ident,$index:tt),)* ))*
+    ) => {
+        $(
+            impl<$($elem,)*> IntoColumnSlices for ($($elem,)*)
+            where
+                $($elem: IntoColumnSlices,)*
+            {
+                type Slices = (
+                    $(IntoColumnSlicesOut<$elem>,)*
+                );
+
+                #[inline(always)]
+                #[allow(clippy::unused_unit)]
+                fn into_column_slices(self) -> Self::Slices {
+                    (
+                        $(self.$index.into_column_slices(),)*
+                    )
+                }
+            }
+        )*
+    };
+}
+
+// Same arities as the `NormalizeFields` impls for tuples,
+// since `StructuralExt::fields_mut` returns nested tuples for more fields.
+column_slices_tuple! {
+    ( )
+    ( (C0,0), )
+    ( (C0,0), (C1,1), )
+    ( (C0,0), (C1,1), (C2,2), )
+    ( (C0,0), (C1,1), (C2,2), (C3,3), )
+    ( (C0,0), (C1,1), (C2,2), (C3,3), (C4,4), )
+    ( (C0,0), (C1,1), (C2,2), (C3,3), (C4,4), (C5,5), )
+    ( (C0,0), (C1,1), (C2,2), (C3,3), (C4,4), (C5,5), (C6,6), )
+    ( (C0,0), (C1,1), (C2,2), (C3,3), (C4,4), (C5,5), (C6,6), (C7,7), )
+    (
+        (C0,0), (C1,1), (C2,2), (C3,3), (C4,4), (C5,5), (C6,6), (C7,7),
+        (C8,8),
+    )
+    (
+        (C0,0), (C1,1), (C2,2), (C3,3), (C4,4), (C5,5), (C6,6), (C7,7),
+        (C8,8), (C9,9),
+    )
+    (
+        (C0,0), (C1,1), (C2,2), (C3,3), (C4,4), (C5,5), (C6,6), (C7,7),
+        (C8,8), (C9,9), (C10,10),
+    )
+    (
+        (C0,0), (C1,1), (C2,2), (C3,3), (C4,4), (C5,5), (C6,6), (C7,7),
+        (C8,8), (C9,9), (C10,10), (C11,11),
+    )
+}
@@ -0,0 +1,648 @@
+/*!
+Loading values from maps of string keys to string values,
+like environment variables or the entries of ini files.
+
+The [`from_str_map`] function parses the fields of a type with their `FromStr` impl,
+using the (possibly renamed) name of the field as the key,
+and then converts them into the type.
+Types with validators are validated after every field is loaded,
+including the fields with `init_*` attributes,
+returning their validation errors.
+
+The fields that are loaded are described by the [`StrMapFields`] trait,
+which is implemented with the `#[struc(from_str_map)]` attribute of the `Structural` derive
+(which also derives `FromStructural`),
+and for [`Record`]s,loading all of their fields.
+
+Fields that have an `init_*` attribute use it to initialize the field
+when their key is missing from the map.
+
+Fields with the `#[struc(str_map_nested)]` attribute are loaded
+like the values that [`from_str_map`] loads,
+with their name followed by two underscores as the prefix of the keys,
+eg: the `host` field of the `db` field is loaded from the `db__host` key.
+If they also have an `init_*` attribute,
+it's used when a key of the nested value is missing.
+
+At most 12 fields without an `init_*` attribute can be loaded,
+the most fields that a [`Record`] can have.
+
+This module requires the "alloc" feature,
+and is not available when the `use_const_str` feature is enabled.
+The [`from_env`] function and the [`StrMap`] impl for `HashMap` require the "std" feature.
+
+# Example
+
+```rust
+use structural::{
+    str_map::{self, StrMapError},
+    Structural,
+};
+
+use std::collections::HashMap;
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_str_map)]
+struct Config {
+    port: u16,
+    #[struc(rename = "log-level")]
+    log_level: String,
+    #[struc(init_with_lit = 4)]
+    threads: u32,
+    #[struc(str_map_nested)]
+    db: Database,
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_str_map)]
+struct Database {
+    host: String,
+    #[struc(init_with_lit = 5432)]
+    port: u16,
+}
+
+let mut map = HashMap::new();
+map.insert("port", "8080");
+map.insert("log-level", "debug");
+map.insert("db__host", "localhost");
+
+assert_eq!(
+    str_map::from_str_map::<Config, _>(&map),
+    Ok(Config{
+        port: 8080,
+        log_level: "debug".to_string(),
+        threads: 4,
+        db: Database{ host: "localhost".to_string(), port: 5432 },
+    }),
+);
+
+map.insert("db__port", "lots");
+assert_eq!(
+    str_map::from_str_map::<Config, _>(&map).unwrap_err().to_string(),
+    "could not parse \"lots\" for the `db__port` key: invalid digit found in string",
+);
+
+map.remove("db__host");
+assert_eq!(
+    str_map::from_str_map::<Config, _>(&map),
+    Err(StrMapError::Missing{ key: "db__host".to_string() }),
+);
+
+```
+
+[`from_str_map`]: ./fn.from_str_map.html
+[`StrMapFields`]: ./trait.StrMapFields.html
+[`StrMap`]: ./trait.StrMap.html
+[`from_env`]: ./fn.from_env.html
+[`Record`]: ../record/struct.Record.html
+
+*/
+
+use crate::{
+    alloc::{
+        borrow::{Cow, ToOwned},
+        collections::BTreeMap,
+        string::{String, ToString},
+    },
+    convert::FromStructural,
+    field::GetFieldMut,
+    record::{BuildRecordFields, ForEachName, MakeField, NameOp, Record, RecordFields},
+    type_level::{to_value_traits::ToStr, TNil},
+    validate::ValidationErrors,
+};
+
+use std_::{
+    borrow::Borrow,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    error::Error as StdError,
+    hash::{BuildHasher, Hash},
+};
+
+/// Describes the fields that [`from_str_map`] loads,
+/// each associated type is a `TList` of the names of the fields (as `TStr`s).
+///
+/// This is implemented with the `#[struc(from_str_map)]` attribute of the `Structural` derive,
+/// and for [`Record`]s,parsing all of their fields.
+///
+/// The fields in `Parsed` and `Nested` are converted into `Self` without validating it,
+/// the fields in `InitParsed` and `InitNested` are assigned if they're in the map,
+/// and then the value is validated with `validate_loaded`.
+///
+/// For an example,look at the [module-level documentation](./index.html).
+///
+/// [`from_str_map`]: ./fn.from_str_map.html
+/// [`Record`]: ../record/struct.Record.html
+pub trait StrMapFields {
+    /// The fields parsed with their `FromStr` impl.
+    type Parsed;
+
+    /// The fields loaded like the values that [`from_str_map`] loads,
+    /// with their name followed by two underscores as the prefix of the keys.
+    ///
+    /// [`from_str_map`]: ./fn.from_str_map.html
+    type Nested;
+
+    /// The fields parsed with their `FromStr` impl,when their key is in the map.
+    type InitParsed;
+
+    /// The fields loaded like `Nested` fields,when none of their keys is missing.
+    type InitNested;
+
+    /// Validates the value after the `InitParsed` and `InitNested` fields are assigned.
+    #[inline]
+    fn validate_loaded(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
+
+impl<L> StrMapFields for Record<L>
+where
+    L: RecordFields,
+{
+    type Parsed = L::Names;
+    type Nested = TNil;
+    type InitParsed = TNil;
+    type InitNested = TNil;
+}
+
+/// A map of string keys to string values,that [`from_str_map`] loads values from.
+///
+/// [`from_str_map`]: ./fn.from_str_map.html
+pub trait StrMap {
+    /// Gets the value for `key`,returning None if it's not in the map.
+    fn get_str(&self, key: &str) -> Option<Cow<'_, str>>;
+}
+
+/// Loads a `T` from `map`,
+/// parsing the fields that [`StrMapFields`] lists,
+/// converting them into a `T`,and then validating it.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{str_map, Record, Structural, StructuralExt, fp};
+///
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Structural, Debug, PartialEq)]
+/// #[struc(public, from_str_map)]
+/// struct Point(i32, i32);
+///
+/// let map = vec![("0", "3"), ("1", "-5")].into_iter().collect::<BTreeMap<_, _>>();
+///
+/// assert_eq!( str_map::from_str_map(&map), Ok(Point(3, -5)) );
+///
+/// let record: Record!{ 0: u8 } = str_map::from_str_map(&map).unwrap();
+/// assert_eq!( record.into_field(fp!(0)), 3 );
+///
+/// ```
+///
+/// [`StrMapFields`]: ./trait.StrMapFields.html
+#[inline]
+pub fn from_str_map<T, M>(map: &M) -> Result<T, StrMapError>
+where
+    T: LoadStrMap,
+    M: ?Sized + StrMap,
+{
+    T::load_str_map(&map, "")
+}
+
+/// Loads a `T` from `map`,with `prefix` prepended to every key.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{str_map, Structural};
+///
+/// use std::collections::HashMap;
+///
+/// #[derive(Structural, Debug, PartialEq)]
+/// #[struc(public, from_str_map)]
+/// struct Window {
+///     width: u32,
+///     #[struc(init_with_lit = 480)]
+///     height: u32,
+/// }
+///
+/// let mut map = HashMap::new();
+/// map.insert("window.width", "640");
+///
+/// assert_eq!(
+///     str_map::from_str_map_prefixed(&map, "window."),
+///     Ok(Window{ width: 640, height: 480 }),
+/// );
+///
+/// ```
+#[inline]
+pub fn from_str_map_prefixed<T, M>(map: &M, prefix: &str) -> Result<T, StrMapError>
+where
+    T: LoadStrMap,
+    M: ?Sized + StrMap,
+{
+    T::load_str_map(&map, prefix)
+}
+
+/// Loads a `T` from the environment variables,with `prefix` prepended to every key.
+///
+/// The keys are converted to ASCII uppercase,
+/// eg: the `port` field with the `APP_` prefix is loaded from the `APP_PORT` variable.
+///
+/// This requires the "std" feature.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{str_map, Structural};
+///
+/// #[derive(Structural, Debug, PartialEq)]
+/// #[struc(public, from_str_map)]
+/// struct Server {
+///     port: u16,
+///     #[struc(str_map_nested)]
+///     tls: Tls,
+/// }
+///
+/// #[derive(Structural, Debug, PartialEq)]
+/// #[struc(public, from_str_map)]
+/// struct Tls {
+///     #[struc(init_with_default)]
+///     enabled: bool,
+/// }
+///
+/// std::env::set_var("DOCTEST_FROM_ENV_PORT", "8080");
+/// std::env::set_var("DOCTEST_FROM_ENV_TLS__ENABLED", "true");
+///
+/// assert_eq!(
+///     str_map::from_env("doctest_from_env_"),
+///     Ok(Server{ port: 8080, tls: Tls{ enabled: true } }),
+/// );
+///
+/// ```
+#[cfg(feature = "std")]
+#[inline]
+pub fn from_env<T>(prefix: &str) -> Result<T, StrMapError>
+where
+    T: LoadStrMap,
+{
+    T::load_str_map(&EnvVars, prefix)
+}
+
+/// The environment variables,as a [`StrMap`] that converts keys to ASCII uppercase.
+///
+/// Variables that aren't valid unicode are treated as missing.
+///
+/// This requires the "std" feature.
+///
+/// [`StrMap`]: ./trait.StrMap.html
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EnvVars;
+
+#[cfg(feature = "std")]
+impl StrMap for EnvVars {
+    fn get_str(&self, key: &str) -> Option<Cow<'_, str>> {
+        std::env::var(key.to_ascii_uppercase()).ok().map(Cow::Owned)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> StrMap for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: AsRef<str>,
+    S: BuildHasher,
+{
+    #[inline]
+    fn get_str(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.get(key).map(|v| Cow::Borrowed(v.as_ref()))
+    }
+}
+
+impl<K, V> StrMap for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+    V: AsRef<str>,
+{
+    #[inline]
+    fn get_str(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.get(key).map(|v| Cow::Borrowed(v.as_ref()))
+    }
+}
+
+impl<M> StrMap for &M
+where
+    M: ?Sized + StrMap,
+{
+    #[inline]
+    fn get_str(&self, key: &str) -> Option<Cow<'_, str>> {
+        (**self).get_str(key)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The error returned when a value can't be loaded from a [`StrMap`].
+///
+/// [`StrMap`]: ./trait.StrMap.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StrMapError {
+    /// The key for a field without an `init_*` attribute is not in the map.
+    Missing {
+        /// The key,including the prefix.
+        key: String,
+    },
+    /// The value for a field could not be parsed with its `FromStr` impl.
+    Parse {
+        /// The key,including the prefix.
+        key: String,
+        /// The text that could not be parsed.
+        value: String,
+        /// The error message of the `FromStr::Err`.
+        message: String,
+    },
+    /// The loaded value failed validation,
+    /// for types that use the `#[struc(validate = "...")]` attributes.
+    Invalid(ValidationErrors),
+}
+
+impl StrMapError {
+    /// The key of the field that could not be loaded,
+    /// None for the `Invalid` variant.
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            StrMapError::Missing { key } | StrMapError::Parse { key, .. } => Some(key),
+            StrMapError::Invalid(_) => None,
+        }
+    }
+}
+
+impl Display for StrMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrMapError::Missing { key } => write!(f, "missing the `{}` key", key),
+            StrMapError::Parse {
+                key,
+                value,
+                message,
+            } => write!(
+                f,
+                "could not parse {:?} for the `{}` key: {}",
+                value, key, message,
+            ),
+            StrMapError::Invalid(errors) => Display::fmt(errors, f),
+        }
+    }
+}
+
+impl From<ValidationErrors> for StrMapError {
+    #[inline]
+    fn from(errors: ValidationErrors) -> Self {
+        StrMapError::Invalid(errors)
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for StrMapError {}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Loads a value from a [`StrMap`],with a prefix before every key.
+///
+/// This is implemented for every type that implements [`StrMapFields`],
+/// and can be converted with [`FromLoaded`] from a `Record` of its
+/// `Parsed` and `Nested` fields.
+///
+/// [`StrMap`]: ./trait.StrMap.html
+/// [`StrMapFields`]: ./trait.StrMapFields.html
+/// [`FromLoaded`]: ./trait.FromLoaded.html
+#[doc(hidden)]
+pub trait LoadStrMap: Sized {
+    fn load_str_map(map: &dyn StrMap, prefix: &str) -> Result<Self, StrMapError>;
+}
+
+/// Converts the `Record` of loaded fields into `Self` without validating it,
+/// since the fields with `init_*` attributes are assigned afterwards.
+///
+/// This is implemented by the `#[struc(from_str_map)]` attribute of the `Structural` derive,
+/// and for [`Record`]s.
+///
+/// [`Record`]: ../record/struct.Record.html
+#[doc(hidden)]
+pub trait FromLoaded<T>: Sized {
+    fn from_loaded(from: T) -> Self;
+}
+
+impl<L, T> FromLoaded<T> for Record<L>
+where
+    Self: FromStructural<T>,
+{
+    #[inline]
+    fn from_loaded(from: T) -> Self {
+        Self::from_structural(from)
+    }
+}
+
+/// The `Record` that the `Parsed` and `Nested` fields are loaded into,
+/// before converting it into `T`.
+#[doc(hidden)]
+pub type LoadedRecord<'a, T> = Record<
+    <<T as StrMapFields>::Nested as BuildRecordFields<
+        T,
+        ParsedFields<'a, T>,
+        NestedOp<'a>,
+        StrMapError,
+    >>::Fields,
+>;
+
+/// The tuple of the `Parsed` fields of `T`.
+#[doc(hidden)]
+pub type ParsedFields<'a, T> =
+    <<T as StrMapFields>::Parsed as BuildRecordFields<T, (), ParseOp<'a>, StrMapError>>::Fields;
+
+impl<T> LoadStrMap for T
+where
+    T: StrMapFields,
+    for<'a> T::Parsed: BuildRecordFields<T, (), ParseOp<'a>, StrMapError>,
+    for<'a> T::Nested: BuildRecordFields<T, ParsedFields<'a, T>, NestedOp<'a>, StrMapError>,
+    for<'a> T: FromLoaded<LoadedRecord<'a, T>>,
+    for<'a> T::InitParsed: ForEachName<InitParseOp<'a, T>>,
+    for<'a> T::InitNested: ForEachName<InitNestedOp<'a, T>>,
+{
+    fn load_str_map(map: &dyn StrMap, prefix: &str) -> Result<Self, StrMapError> {
+        let keys = Keys { map, prefix };
+        let fields = T::Parsed::build_record_fields((), &mut ParseOp(keys))?;
+        let fields = T::Nested::build_record_fields(fields, &mut NestedOp(keys))?;
+        let mut this = T::from_loaded(Record(fields));
+
+        let mut op = InitParseOp(InitOp::new(keys, &mut this));
+        T::InitParsed::for_each_name(&mut op);
+        let mut op = InitNestedOp(op.0);
+        T::InitNested::for_each_name(&mut op);
+        op.0.finish()?;
+
+        this.validate_loaded()?;
+        Ok(this)
+    }
+}
+
+/// The map and prefix that the fields are loaded from.
+#[derive(Copy, Clone)]
+struct Keys<'a> {
+    map: &'a dyn StrMap,
+    prefix: &'a str,
+}
+
+impl<'a> Keys<'a> {
+    fn key(self, name: &str) -> String {
+        self.prefix.to_owned() + name
+    }
+
+    /// Parses the value for the `name` field,returning None if its key is missing.
+    fn parse<T>(self, name: &str) -> Result<Option<T>, StrMapError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.map.get_str(&self.key(name)) {
+            Some(value) => match value.parse::<T>() {
+                Ok(x) => Ok(Some(x)),
+                Err(e) => Err(StrMapError::Parse {
+                    key: self.key(name),
+                    value: value.into_owned(),
+                    message: e.to_string(),
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Loads the nested value for the `name` field.
+    fn load_nested<T>(self, name: &str) -> Result<T, StrMapError>
+    where
+        T: LoadStrMap,
+    {
+        T::load_str_map(self.map, &(self.key(name) + "__"))
+    }
+}
+
+/// Parses each `Parsed` field,for the `LoadStrMap` impl.
+#[doc(hidden)]
+pub struct ParseOp<'a>(Keys<'a>);
+
+impl<'a, N, Ty> MakeField<N, Ty> for ParseOp<'a>
+where
+    N: ToStr,
+    Ty: FromStr,
+    Ty::Err: Display,
+{
+    type Error = StrMapError;
+
+    fn make_field(&mut self, name: N) -> Result<Ty, StrMapError> {
+        self.0
+            .parse(name.to_str())?
+            .ok_or_else(|| StrMapError::Missing {
+                key: self.0.key(name.to_str()),
+            })
+    }
+}
+
+/// Loads each `Nested` field,for the `LoadStrMap` impl.
+#[doc(hidden)]
+pub struct NestedOp<'a>(Keys<'a>);
+
+impl<'a, N, Ty> MakeField<N, Ty> for NestedOp<'a>
+where
+    N: ToStr,
+    Ty: LoadStrMap,
+{
+    type Error = StrMapError;
+
+    #[inline]
+    fn make_field(&mut self, name: N) -> Result<Ty, StrMapError> {
+        self.0.load_nested(name.to_str())
+    }
+}
+
+/// Assigns the fields with `init_*` attributes that are in the map,
+/// stopping at the first error.
+#[doc(hidden)]
+pub struct InitOp<'a, T> {
+    keys: Keys<'a>,
+    this: &'a mut T,
+    result: Result<(), StrMapError>,
+}
+
+impl<'a, T> InitOp<'a, T> {
+    fn new(keys: Keys<'a>, this: &'a mut T) -> Self {
+        Self {
+            keys,
+            this,
+            result: Ok(()),
+        }
+    }
+
+    /// Assigns the field if `loaded` found it in the map.
+    fn assign<N, F>(&mut self, name: N, loaded: F)
+    where
+        T: GetFieldMut<N>,
+        F: FnOnce(Keys<'a>) -> Result<Option<T::Ty>, StrMapError>,
+    {
+        if self.result.is_err() {
+            return;
+        }
+        match loaded(self.keys) {
+            Ok(Some(value)) => *self.this.get_field_mut_(name) = value,
+            Ok(None) => {}
+            Err(e) => self.result = Err(e),
+        }
+    }
+
+    /// Returns the first error that was found.
+    fn finish(self) -> Result<(), StrMapError> {
+        self.result
+    }
+}
+
+/// Assigns each `InitParsed` field,for the `LoadStrMap` impl.
+#[doc(hidden)]
+pub struct InitParseOp<'a, T>(InitOp<'a, T>);
+
+impl<'a, T, N> NameOp<N> for InitParseOp<'a, T>
+where
+    T: GetFieldMut<N>,
+    T::Ty: FromStr,
+    <T::Ty as FromStr>::Err: Display,
+    N: ToStr,
+{
+    fn name_op(&mut self, name: N) {
+        let name_str = name.to_str();
+        self.0.assign(name, |keys| keys.parse(name_str));
+    }
+}
+
+/// Assigns each `InitNested` field,for the `LoadStrMap` impl.
+#[doc(hidden)]
+pub struct InitNestedOp<'a, T>(InitOp<'a, T>);
+
+impl<'a, T, N> NameOp<N> for InitNestedOp<'a, T>
+where
+    T: GetFieldMut<N>,
+    T::Ty: LoadStrMap,
+    N: ToStr,
+{
+    fn name_op(&mut self, name: N) {
+        let name_str = name.to_str();
+        self.0
+            .assign(name, |keys| match keys.load_nested(name_str) {
+                Ok(x) => Ok(Some(x)),
+                Err(StrMapError::Missing { .. }) => Ok(None),
+                Err(e) => Err(e),
+            });
+    }
+}
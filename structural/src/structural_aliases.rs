@@ -87,4 +87,151 @@ structural_alias! {
         end: T,
     }
 
+    /// Structural alias for `core::ops::Bound`-like enums.
+    /// With shared,mutable,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="Bound_ESI"))]
+    pub trait Bound_SI<T>{
+        Included(T),
+        Excluded(T),
+        Unbounded,
+    }
+
+    /// Structural alias for `core::ops::Bound`-like enums. With shared,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="BoundMove_ESI"))]
+    pub trait BoundMove_SI<T>{
+        Included(move T),
+        Excluded(move T),
+        Unbounded,
+    }
+
+    /// Structural alias for `core::task::Poll`-like enums.
+    /// With shared,mutable,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="Poll_ESI"))]
+    pub trait Poll_SI<T>{
+        Ready(T),
+        Pending,
+    }
+
+    /// Structural alias for `core::task::Poll`-like enums. With shared,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="PollMove_ESI"))]
+    pub trait PollMove_SI<T>{
+        Ready(move T),
+        Pending,
+    }
+
+    /// Structural alias for `core::num::Wrapping`-like structs
+    ///
+    pub trait Wrapping_SI<T>{
+        0: T,
+    }
+
+    /// Structural alias for `core::num::Saturating`-like structs
+    ///
+    pub trait Saturating_SI<T>{
+        0: T,
+    }
+
+    /// Structural alias for `core::cmp::Reverse`-like structs
+    ///
+    pub trait Reverse_SI<T>{
+        0: T,
+    }
+
+    /// Structural alias for `core::num::NonZero*`-like structs,with only shared access.
+    ///
+    pub trait NonZero_SI<T>{
+        ref 0: T,
+    }
+
+    /// Structural alias for `core::cell::Cell`-like structs,with shared and by value access.
+    ///
+    /// `Cell` and `UnsafeCell` can be constructed from types implementing this
+    /// with `FromStructural`,
+    /// they don't implement it themselves because they can't implement `GetField` soundly,
+    /// their `0` field can only be accessed by value with `into_field`/`into_fields`.
+    ///
+    pub trait CellMove_SI<T>{
+        move 0: T,
+    }
+
+}
+
+#[cfg(rust_1_55)]
+structural_alias! {
+    /// Structural alias for `core::ops::ControlFlow`-like enums.
+    /// With shared,mutable,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="ControlFlow_ESI"))]
+    pub trait ControlFlow_SI<B,C>{
+        Continue(C),
+        Break(B),
+    }
+
+    /// Structural alias for `core::ops::ControlFlow`-like enums.
+    /// With shared,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="ControlFlowMove_ESI"))]
+    pub trait ControlFlowMove_SI<B,C>{
+        Continue(move C),
+        Break(move B),
+    }
+}
+
+#[cfg(feature = "alloc")]
+structural_alias! {
+    /// Structural alias for `alloc::borrow::Cow`-like enums.
+    /// With shared,mutable,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="Cow_ESI"))]
+    pub trait Cow_SI<'a,B>
+    where
+        B: 'a + ?Sized + crate::alloc::borrow::ToOwned,
+    {
+        Borrowed(&'a B),
+        Owned(B::Owned),
+    }
+
+    /// Structural alias for `alloc::borrow::Cow`-like enums. With shared,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="CowMove_ESI"))]
+    pub trait CowMove_SI<'a,B>
+    where
+        B: 'a + ?Sized + crate::alloc::borrow::ToOwned,
+    {
+        Borrowed(move &'a B),
+        Owned(move B::Owned),
+    }
+}
+
+#[cfg(feature = "std")]
+structural_alias! {
+    /// Structural alias for `std::collections::hash_map::Entry`-like enums.
+    /// With shared,mutable,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="HashMapEntry_ESI"))]
+    pub trait HashMapEntry_SI<'a,K,V>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        Occupied(crate::std::collections::hash_map::OccupiedEntry<'a,K,V>),
+        Vacant(crate::std::collections::hash_map::VacantEntry<'a,K,V>),
+    }
+
+    /// Structural alias for `std::collections::hash_map::Entry`-like enums.
+    /// With shared,and by value access.
+    ///
+    #[struc(and_exhaustive_enum(name="HashMapEntryMove_ESI"))]
+    pub trait HashMapEntryMove_SI<'a,K,V>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        Occupied(move crate::std::collections::hash_map::OccupiedEntry<'a,K,V>),
+        Vacant(move crate::std::collections::hash_map::VacantEntry<'a,K,V>),
+    }
 }
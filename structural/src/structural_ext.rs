@@ -2,6 +2,7 @@ use crate::{
     convert::{IntoStructural, TryFromError, TryIntoStructural},
     enums::IsVariant,
     field::{
+        pin_projection::{PinField, PinFieldOut, PinProjection, RevPinFieldsMut},
         NormalizeFields, NormalizeFieldsOut, RevGetFieldImpl, RevGetFieldMutImpl, RevGetMultiField,
         RevGetMultiFieldMut, RevGetMultiFieldMutOut, RevGetMultiFieldOut, RevIntoFieldImpl,
         RevIntoMultiField, RevIntoMultiFieldOut,
@@ -9,7 +10,12 @@ use crate::{
     path::IsTStr,
 };
 
-use core_extensions::collection_traits::{Cloned, ClonedOut};
+use core_extensions::collection_traits::{Cloned, ClonedOut, IntoArray};
+
+use std_::{
+    hash::{Hash, Hasher},
+    pin::Pin,
+};
 
 /// A trait defining the primary way to call methods from structural traits.
 ///
@@ -324,6 +330,158 @@ pub trait StructuralExt {
         path.rev_get_multi_field(self).cloned_()
     }
 
+    /// Gets references to multiple fields of the same type as an array,
+    /// determined by `path`.
+    ///
+    /// This is equivalent to `this.fields(path).into_array()`,
+    /// requiring all the fields to be the same type.
+    /// This supports up to 8 fields,
+    /// because larger path sets return nested tuples of fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use structural::{StructuralExt,Structural,fp};
+    ///
+    /// #[derive(Structural)]
+    /// #[struc(public)]
+    /// struct Rgb{
+    ///     r:u8,
+    ///     g:u8,
+    ///     b:u8,
+    /// }
+    ///
+    /// fn brightness(color:&impl Rgb_SI)->u32{
+    ///     color.fields_array(fp!(r,g,b)).iter().map(|&&x| u32::from(x) ).sum()
+    /// }
+    ///
+    /// let color=Rgb{ r:20, g:30, b:40 };
+    ///
+    /// assert_eq!( color.fields_array(fp!(r,g,b)), [&20, &30, &40] );
+    /// assert_eq!( color.fields_array(fp!(b,r)), [&40, &20] );
+    /// assert_eq!( brightness(&color), 90 );
+    ///
+    /// assert_eq!( (3,5,8,13).fields_array(fp!(0,2,3)), [&3, &8, &13] );
+    ///
+    /// ```
+    #[inline(always)]
+    fn fields_array<'a, P>(
+        &'a self,
+        path: P,
+    ) -> <RevGetMultiFieldOut<'a, P, Self> as IntoArray>::Array
+    where
+        P: RevGetMultiField<'a, Self>,
+        RevGetMultiFieldOut<'a, P, Self>: IntoArray,
+    {
+        path.rev_get_multi_field(self).into_array()
+    }
+
+    /// Gets clones of multiple fields of the same type as an array,
+    /// determined by `path`.
+    ///
+    /// This is equivalent to `this.cloned_fields(path).into_array()`,
+    /// requiring all the fields to be the same type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use structural::{StructuralExt,fp,make_struct};
+    ///
+    /// let this=make_struct!{ x:3.0, y:5.0, z:8.0 };
+    ///
+    /// assert_eq!( this.cloned_fields_array(fp!(x,y,z)), [3.0, 5.0, 8.0] );
+    /// assert_eq!( this.cloned_fields_array(fp!(z,x)), [8.0, 3.0] );
+    ///
+    /// ```
+    #[inline(always)]
+    fn cloned_fields_array<'a, P>(
+        &'a self,
+        path: P,
+    ) -> <ClonedOut<RevGetMultiFieldOut<'a, P, Self>> as IntoArray>::Array
+    where
+        P: RevGetMultiField<'a, Self>,
+        RevGetMultiFieldOut<'a, P, Self>: Cloned,
+        ClonedOut<RevGetMultiFieldOut<'a, P, Self>>: IntoArray,
+    {
+        path.rev_get_multi_field(self).cloned_().into_array()
+    }
+
+    /// Calls `f` with a reference to each of the fields in `path`,
+    /// which must all be the same type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use structural::{StructuralExt,fp,make_struct};
+    ///
+    /// let this=make_struct!{ x:3, y:5, z:8 };
+    ///
+    /// let mut list=Vec::new();
+    /// this.for_each_field(fp!(z,y,x), |&n| list.push(n) );
+    /// assert_eq!( list, vec![8, 5, 3] );
+    ///
+    /// ```
+    #[inline(always)]
+    fn for_each_field<'a, P, T, F>(&'a self, path: P, mut f: F)
+    where
+        P: RevGetMultiField<'a, Self>,
+        RevGetMultiFieldOut<'a, P, Self>: IntoArray,
+        <RevGetMultiFieldOut<'a, P, Self> as IntoArray>::Array: AsRef<[&'a T]>,
+        T: 'a + ?Sized,
+        F: FnMut(&'a T),
+    {
+        for &field in self.fields_array(path).as_ref() {
+            f(field);
+        }
+    }
+
+    /// Hashes multiple fields,determined by `path`,
+    /// feeding them to `hasher` as a tuple of the fields.
+    ///
+    /// For a wrapper that uses only some fields for its `Hash`/`Eq`/`Ord` impls
+    /// you can use [`KeyedBy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use structural::{StructuralExt,fp,make_struct};
+    ///
+    /// use std::{
+    ///     collections::hash_map::DefaultHasher,
+    ///     hash::{Hash, Hasher},
+    /// };
+    ///
+    /// fn hash_of(f: impl FnOnce(&mut DefaultHasher)) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     f(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let this = make_struct!{ name: "foo", id: 3_u32, cached: vec![0_u8; 4] };
+    /// let other = make_struct!{ name: "foo", id: 3_u32, cached: Vec::<u8>::new() };
+    ///
+    /// assert_eq!(
+    ///     hash_of(|h| this.hash_fields(fp!(name, id), h)),
+    ///     hash_of(|h| other.hash_fields(fp!(name, id), h)),
+    /// );
+    /// assert_eq!(
+    ///     hash_of(|h| this.hash_fields(fp!(name, id), h)),
+    ///     hash_of(|h| ("foo", 3_u32).hash(h)),
+    /// );
+    ///
+    /// ```
+    ///
+    /// [`KeyedBy`]: ./struct.KeyedBy.html
+    #[inline(always)]
+    fn hash_fields<'a, P, H>(&'a self, path: P, hasher: &mut H)
+    where
+        P: RevGetMultiField<'a, Self>,
+        RevGetMultiFieldOut<'a, P, Self>: Hash,
+        H: Hasher,
+    {
+        self.fields(path).hash(hasher)
+    }
+
     /// Gets a mutable reference to a field,determined by `path`.
     ///
     /// # Example
@@ -557,6 +715,176 @@ pub trait StructuralExt {
         path.rev_get_multi_field_mut(self)
     }
 
+    /// Gets mutable references to multiple fields of the same type as an array,
+    /// determined by `path`.
+    ///
+    /// This is equivalent to `this.fields_mut(path).into_array()`,
+    /// requiring all the fields to be the same type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use structural::{StructuralExt,Structural,fp};
+    ///
+    /// #[derive(Structural,Debug,PartialEq)]
+    /// #[struc(public)]
+    /// struct Vec3{
+    ///     x:f32,
+    ///     y:f32,
+    ///     z:f32,
+    /// }
+    ///
+    /// fn scale(this:&mut impl Vec3_SI, by:f32){
+    ///     for field in this.fields_array_mut(fp!(x,y,z)).iter_mut() {
+    ///         **field *= by;
+    ///     }
+    /// }
+    ///
+    /// let mut this=Vec3{ x:1.0, y:2.0, z:3.0 };
+    /// scale(&mut this, 2.0);
+    /// assert_eq!( this, Vec3{ x:2.0, y:4.0, z:6.0 } );
+    ///
+    /// let [x, y]=this.fields_array_mut(fp!(x,y));
+    /// std::mem::swap(x, y);
+    /// assert_eq!( this, Vec3{ x:4.0, y:2.0, z:6.0 } );
+    ///
+    /// ```
+    #[inline(always)]
+    fn fields_array_mut<'a, P>(
+        &'a mut self,
+        path: P,
+    ) -> <RevGetMultiFieldMutOut<'a, P, Self> as IntoArray>::Array
+    where
+        P: RevGetMultiFieldMut<'a, Self>,
+        RevGetMultiFieldMutOut<'a, P, Self>: IntoArray,
+    {
+        path.rev_get_multi_field_mut(self).into_array()
+    }
+
+    /// Projects a pinned mutable reference to the field that `path` refers to,
+    /// returning a `Pin<&mut Field>` for fields that are structurally pinned,
+    /// and a `&mut Field` for fields that aren't.
+    ///
+    /// `path` can only be a single field name (eg: `fp!(foo)`).
+    ///
+    /// For more details,look at the [`pin_projection`] module.
+    ///
+    /// [`pin_projection`]: ./field/pin_projection/index.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use structural::{StructuralExt,Structural,fp};
+    ///
+    /// use std::{marker::PhantomPinned, pin::Pin};
+    ///
+    /// #[derive(Structural)]
+    /// #[struc(public)]
+    /// struct Node{
+    ///     #[struc(pin)]
+    ///     pinned: PhantomPinned,
+    ///     value: u32,
+    /// }
+    ///
+    /// let mut node = Box::pin(Node{ pinned: PhantomPinned, value: 3 });
+    ///
+    /// let _: Pin<&mut PhantomPinned> = node.as_mut().pin_field_mut(fp!(pinned));
+    ///
+    /// *node.as_mut().pin_field_mut(fp!(value)) += 5;
+    /// assert_eq!( node.value, 8 );
+    ///
+    /// ```
+    #[inline(always)]
+    fn pin_field_mut<'a, N>(self: Pin<&'a mut Self>, path: N) -> PinFieldOut<'a, Self, N>
+    where
+        Self: PinField<N>,
+        Self::Ty: 'a,
+        Self::Pinning: PinProjection<'a, Self::Ty>,
+    {
+        unsafe {
+            let this = Pin::get_unchecked_mut(self);
+            <Self::Pinning as PinProjection<'a, Self::Ty>>::project(this.get_field_mut_(path))
+        }
+    }
+
+    /// Projects a pinned mutable reference to the fields that `path` refers to,
+    /// returning a `Pin<&mut Field>` for fields that are structurally pinned,
+    /// and a `&mut Field` for fields that aren't.
+    ///
+    /// `path` can only be a set of up to 8 field names (eg: `fp!(foo, bar)`).
+    ///
+    /// For more details,look at the [`pin_projection`] module.
+    ///
+    /// [`pin_projection`]: ./field/pin_projection/index.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use structural::{StructuralExt,Structural,fp};
+    ///
+    /// use std::{future::Future, pin::Pin};
+    ///
+    /// #[derive(Structural)]
+    /// #[struc(public)]
+    /// struct Join<A, B>{
+    ///     #[struc(pin)]
+    ///     left: A,
+    ///     #[struc(pin)]
+    ///     right: B,
+    ///     done: (bool, bool),
+    /// }
+    ///
+    /// fn parts<A, B>(
+    ///     this: Pin<&mut Join<A, B>>
+    /// ) -> (Pin<&mut A>, Pin<&mut B>, &mut (bool, bool)) {
+    ///     this.pin_fields_mut(fp!(left, right, done))
+    /// }
+    ///
+    /// let mut join = Box::pin(Join{ left: 3, right: "foo", done: (false, false) });
+    /// let (left, right, done) = parts(join.as_mut());
+    /// *left.get_mut() += 1;
+    /// *right.get_mut() = "bar";
+    /// done.0 = true;
+    ///
+    /// assert_eq!( (join.left, join.right, join.done), (4, "bar", (true, false)) );
+    ///
+    /// ```
+    #[inline(always)]
+    fn pin_fields_mut<'a, P>(self: Pin<&'a mut Self>, path: P) -> P::Fields
+    where
+        P: RevPinFieldsMut<'a, Self>,
+    {
+        path.rev_pin_fields_mut(self)
+    }
+
+    /// Calls `f` with a mutable reference to each of the fields in `path`,
+    /// which must all be the same type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use structural::{StructuralExt,fp,make_struct};
+    ///
+    /// let mut this=make_struct!{ x:3, y:5, z:8 };
+    ///
+    /// this.for_each_field_mut(fp!(x,z), |n| *n*=10 );
+    /// assert_eq!( this.cloned_fields_array(fp!(x,y,z)), [30, 5, 80] );
+    ///
+    /// ```
+    #[inline(always)]
+    fn for_each_field_mut<'a, P, T, F>(&'a mut self, path: P, mut f: F)
+    where
+        P: RevGetMultiFieldMut<'a, Self>,
+        RevGetMultiFieldMutOut<'a, P, Self>: IntoArray,
+        <RevGetMultiFieldMutOut<'a, P, Self> as IntoArray>::Array: AsMut<[&'a mut T]>,
+        T: 'a + ?Sized,
+        F: FnMut(&mut T),
+    {
+        for field in self.fields_array_mut(path).as_mut() {
+            f(field);
+        }
+    }
+
     /// Converts ´self´ into a field,determined by `path`.
     ///
     /// # Example
@@ -758,6 +1086,44 @@ pub trait StructuralExt {
         path.rev_into_multi_field(self)
     }
 
+    /// Converts `self` into multiple fields of the same type as an array,
+    /// determined by `path`.
+    ///
+    /// This is equivalent to `this.into_fields(path).into_array()`,
+    /// requiring all the fields to be the same type.
+    ///
+    /// To do the reverse,constructing a type from an array,
+    /// you can use the [`from_fields_array`] macro.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use structural::{StructuralExt,fp,make_struct};
+    ///
+    /// let this=make_struct!{
+    ///     first:"foo".to_string(),
+    ///     second:"bar".to_string(),
+    ///     third:"baz".to_string(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     this.into_fields_array(fp!(third,first)),
+    ///     ["baz".to_string(), "foo".to_string()],
+    /// );
+    ///
+    /// ```
+    ///
+    /// [`from_fields_array`]: ./macro.from_fields_array.html
+    #[inline(always)]
+    fn into_fields_array<P>(self, path: P) -> <RevIntoMultiFieldOut<P, Self> as IntoArray>::Array
+    where
+        P: RevIntoMultiField<Self>,
+        RevIntoMultiFieldOut<P, Self>: IntoArray,
+        Self: Sized,
+    {
+        path.rev_into_multi_field(self).into_array()
+    }
+
     /// Checks whether an enum is a particular variant.
     ///
     /// # Example
@@ -0,0 +1,422 @@
+use crate::{
+    enums::{IsVariant, VariantCount, VariantCountOut},
+    field::{
+        DropFields, FieldIndex, FieldType, GetField, GetFieldMut, GetFieldRawMutFn,
+        GetVFieldRawMutFn, GetVariantField, GetVariantFieldMut, IntoField, IntoVariantField,
+        MovedOutFields,
+    },
+    path::VariantField,
+    structural_trait::Structural,
+    TStr,
+};
+
+use std_::{ops::Deref, ptr::NonNull};
+
+/// A wrapper that tracks which fields of `T` were accessed mutably,
+/// setting a dirty flag for each one.
+///
+/// The fields are marked as dirty when they're accessed through the
+/// `GetFieldMut`/`GetVariantFieldMut` traits,including the `field_mut` and `fields_mut`
+/// `StructuralExt` methods.
+/// Accessing a nested field (eg: `fp!(a.b)`) marks the top-level field (`fp!(a)`) as dirty.
+///
+/// Fields can only be tracked if `T` implements [`FieldIndex`] for them,
+/// which the `Structural` derive does for struct and variant fields.
+/// Accessing a field of a variant that `T` isn't doesn't mark it as dirty.
+///
+/// This only implements `Deref` (not `DerefMut`),
+/// so that the wrapped value can't be mutated without tracking it.
+///
+/// # Example
+///
+/// This example shows how only the changed columns of a row can be written.
+///
+/// ```rust
+/// use structural::{Structural, StructuralExt, Tracked, fp};
+///
+/// #[derive(Structural, Debug, Clone, PartialEq)]
+/// #[struc(public)]
+/// struct User {
+///     id: u64,
+///     name: String,
+///     email: String,
+///     logins: u32,
+/// }
+///
+/// fn update_query(user: &Tracked<User>) -> String {
+///     let mut columns = Vec::new();
+///     if user.is_dirty(fp!(name)) {
+///         columns.push(format!("name = {:?}", user.name));
+///     }
+///     if user.is_dirty(fp!(email)) {
+///         columns.push(format!("email = {:?}", user.email));
+///     }
+///     if user.is_dirty(fp!(logins)) {
+///         columns.push(format!("logins = {}", user.logins));
+///     }
+///     format!("UPDATE users SET {} WHERE id = {}", columns.join(", "), user.id)
+/// }
+///
+/// let mut user = Tracked::new(User{
+///     id: 3,
+///     name: "bob".to_string(),
+///     email: "bob@example.com".to_string(),
+///     logins: 10,
+/// });
+///
+/// assert!( user.dirty_fields().is_empty() );
+///
+/// *user.field_mut(fp!(logins)) += 1;
+/// let (_, email) = user.fields_mut(fp!(id, email));
+/// email.push_str(".org");
+///
+/// assert_eq!( user.dirty_fields().len(), 3 );
+/// assert!( user.is_dirty(fp!(id)) );
+/// assert!( !user.is_dirty(fp!(name)) );
+/// assert_eq!(
+///     update_query(&user),
+///     r#"UPDATE users SET email = "bob@example.com.org", logins = 11 WHERE id = 3"#,
+/// );
+///
+/// user.clear();
+/// assert!( user.dirty_fields().is_empty() );
+///
+/// ```
+///
+/// [`FieldIndex`]: ./field/trait.FieldIndex.html
+#[derive(Debug, Default, Copy, Clone)]
+#[repr(C)]
+pub struct Tracked<T> {
+    // This must be the first field,so that a `*mut Tracked<T>` can be casted to `*mut T`.
+    value: T,
+    dirty: DirtyFields,
+}
+
+impl<T> Tracked<T> {
+    /// Constructs a `Tracked` with no dirty fields.
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Self {
+            value,
+            dirty: DirtyFields::new(),
+        }
+    }
+
+    /// Unwraps this into the wrapped value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Gets the set of dirty fields.
+    #[inline(always)]
+    pub const fn dirty_fields(&self) -> DirtyFields {
+        self.dirty
+    }
+
+    /// Whether the field that `path` refers to is dirty.
+    ///
+    /// `path` must be the path to a field of `T`,
+    /// or to a variant field of `T` (eg: `fp!(::Foo.bar)`).
+    /// Variant fields are only dirty if `T` is currently that variant.
+    #[inline(always)]
+    pub fn is_dirty<P>(&self, path: P) -> bool
+    where
+        P: DirtyIndex<T>,
+    {
+        match path.dirty_index(&self.value) {
+            Some(index) => self.dirty.contains_index(index),
+            None => false,
+        }
+    }
+
+    /// Marks all the fields as not dirty.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.dirty = DirtyFields::new();
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> AsRef<T> for Tracked<T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Gets the [`FieldIndex::INDEX`] of the field that `Self` refers to in `T`,
+/// returning `None` for fields of a variant that `T` isn't.
+///
+/// [`FieldIndex::INDEX`]: ./field/trait.FieldIndex.html#associatedconstant.INDEX
+#[doc(hidden)]
+pub trait DirtyIndex<T> {
+    fn dirty_index(self, this: &T) -> Option<u8>;
+}
+
+impl<T, S> DirtyIndex<T> for TStr<S>
+where
+    T: FieldIndex<TStr<S>>,
+{
+    #[inline(always)]
+    fn dirty_index(self, _: &T) -> Option<u8> {
+        Some(T::INDEX)
+    }
+}
+
+impl<T, V, F> DirtyIndex<T> for VariantField<TStr<V>, F>
+where
+    T: FieldIndex<Self> + IsVariant<TStr<V>>,
+{
+    #[inline(always)]
+    fn dirty_index(self, this: &T) -> Option<u8> {
+        if this.is_variant_(self.variant) {
+            Some(T::INDEX)
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The set of dirty fields in a [`Tracked`](./struct.Tracked.html),
+/// each one represented by its [`FieldIndex::INDEX`].
+///
+/// [`FieldIndex::INDEX`]: ./field/trait.FieldIndex.html#associatedconstant.INDEX
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DirtyFields(u64);
+
+impl DirtyFields {
+    /// Constructs a `DirtyFields` where no field is dirty.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        DirtyFields(0)
+    }
+
+    /// Marks the field at `index` as dirty.
+    #[inline(always)]
+    pub fn insert_index(&mut self, index: u8) {
+        self.0 |= 1 << index;
+    }
+
+    /// Whether the field at `index` is dirty.
+    #[inline(always)]
+    pub const fn contains_index(&self, index: u8) -> bool {
+        (self.0 >> index) & 1 != 0
+    }
+
+    /// Whether there are no dirty fields.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The amount of dirty fields.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Iterates over the indices of the dirty fields,in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use structural::{StructuralExt, Tracked, fp};
+    ///
+    /// let mut this = Tracked::new((3, 5, 8, 13));
+    /// *this.field_mut(fp!(3)) += 1;
+    /// *this.field_mut(fp!(1)) += 1;
+    ///
+    /// assert_eq!( this.dirty_fields().indices().collect::<Vec<u8>>(), vec![1, 3] );
+    ///
+    /// ```
+    pub fn indices(self) -> impl Iterator<Item = u8> {
+        (0..64).filter(move |&i| self.contains_index(i))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+impl<T> Structural for Tracked<T> where T: Structural {}
+
+impl<T, N> FieldType<N> for Tracked<T>
+where
+    T: FieldType<N>,
+{
+    type Ty = T::Ty;
+}
+
+impl<T, N> GetField<N> for Tracked<T>
+where
+    T: GetField<N>,
+{
+    #[inline(always)]
+    fn get_field_(&self, field_name: N) -> &Self::Ty {
+        self.value.get_field_(field_name)
+    }
+}
+
+impl<T, N> FieldIndex<N> for Tracked<T>
+where
+    T: FieldIndex<N>,
+{
+    const INDEX: u8 = T::INDEX;
+}
+
+unsafe impl<T, N> GetFieldMut<N> for Tracked<T>
+where
+    T: GetFieldMut<N> + FieldIndex<N>,
+{
+    #[inline(always)]
+    fn get_field_mut_(&mut self, field_name: N) -> &mut Self::Ty {
+        self.dirty.insert_index(T::INDEX);
+        self.value.get_field_mut_(field_name)
+    }
+
+    #[inline(always)]
+    unsafe fn get_field_raw_mut(this: *mut (), field_name: N) -> *mut Self::Ty {
+        let this = this as *mut Self;
+        // Only borrowing the `dirty` field,
+        // since pointers to the fields of `value` could be in use.
+        (*this).dirty.insert_index(T::INDEX);
+        // `value` is the first field of this `#[repr(C)]` struct
+        T::get_field_raw_mut(this as *mut (), field_name)
+    }
+
+    #[inline(always)]
+    fn get_field_raw_mut_fn(&self) -> GetFieldRawMutFn<N, Self::Ty> {
+        <Self as GetFieldMut<N>>::get_field_raw_mut
+    }
+}
+
+unsafe impl<T, N> IntoField<N> for Tracked<T>
+where
+    T: IntoField<N>,
+{
+    #[inline(always)]
+    fn into_field_(self, field_name: N) -> Self::Ty {
+        self.value.into_field_(field_name)
+    }
+
+    #[inline(always)]
+    unsafe fn move_out_field_(
+        &mut self,
+        field_name: N,
+        moved_fields: &mut MovedOutFields,
+    ) -> Self::Ty {
+        self.value.move_out_field_(field_name, moved_fields)
+    }
+}
+
+unsafe impl<T> VariantCount for Tracked<T>
+where
+    T: VariantCount,
+{
+    type Count = VariantCountOut<T>;
+}
+
+unsafe impl<T, V> IsVariant<TStr<V>> for Tracked<T>
+where
+    T: IsVariant<TStr<V>>,
+{
+    #[inline(always)]
+    fn is_variant_(&self, variant: TStr<V>) -> bool {
+        self.value.is_variant_(variant)
+    }
+}
+
+unsafe impl<T, V, F> GetVariantField<TStr<V>, F> for Tracked<T>
+where
+    T: GetVariantField<TStr<V>, F>,
+{
+    #[inline(always)]
+    fn get_vfield_(&self, variant: TStr<V>, field: F) -> Option<&Self::Ty> {
+        self.value.get_vfield_(variant, field)
+    }
+}
+
+unsafe impl<T, V, F> GetVariantFieldMut<TStr<V>, F> for Tracked<T>
+where
+    T: GetVariantFieldMut<TStr<V>, F> + FieldIndex<VariantField<TStr<V>, F>>,
+{
+    #[inline(always)]
+    fn get_vfield_mut_(&mut self, variant: TStr<V>, field: F) -> Option<&mut Self::Ty> {
+        let ret = self.value.get_vfield_mut_(variant, field);
+        if ret.is_some() {
+            self.dirty.insert_index(T::INDEX);
+        }
+        ret
+    }
+
+    #[inline(always)]
+    unsafe fn get_vfield_raw_mut_(
+        this: *mut (),
+        variant: TStr<V>,
+        field: F,
+    ) -> Option<NonNull<Self::Ty>> {
+        let this = this as *mut Self;
+        // `value` is the first field of this `#[repr(C)]` struct
+        let ret = T::get_vfield_raw_mut_(this as *mut (), variant, field);
+        if ret.is_some() {
+            // Only borrowing the `dirty` field,
+            // since pointers to the fields of `value` could be in use.
+            (*this).dirty.insert_index(T::INDEX);
+        }
+        ret
+    }
+
+    #[inline(always)]
+    fn get_vfield_raw_mut_fn(&self) -> GetVFieldRawMutFn<TStr<V>, F, Self::Ty> {
+        <Self as GetVariantFieldMut<TStr<V>, F>>::get_vfield_raw_mut_
+    }
+
+    #[inline(always)]
+    fn get_vfield_raw_mut_unchecked_fn(&self) -> GetFieldRawMutFn<F, Self::Ty> {
+        <Self as GetVariantFieldMut<TStr<V>, F>>::get_vfield_raw_mut_unchecked
+    }
+}
+
+unsafe impl<T, V, F> IntoVariantField<TStr<V>, F> for Tracked<T>
+where
+    T: IntoVariantField<TStr<V>, F>,
+{
+    #[inline(always)]
+    fn into_vfield_(self, variant: TStr<V>, field: F) -> Option<Self::Ty> {
+        self.value.into_vfield_(variant, field)
+    }
+
+    #[inline(always)]
+    unsafe fn move_out_vfield_(
+        &mut self,
+        variant: TStr<V>,
+        field: F,
+        moved_fields: &mut MovedOutFields,
+    ) -> Option<Self::Ty> {
+        self.value.move_out_vfield_(variant, field, moved_fields)
+    }
+}
+
+unsafe impl<T> DropFields for Tracked<T>
+where
+    T: DropFields,
+{
+    #[inline(always)]
+    fn pre_move(&mut self) {
+        self.value.pre_move();
+    }
+
+    #[inline(always)]
+    unsafe fn drop_fields(&mut self, moved: MovedOutFields) {
+        self.value.drop_fields(moved)
+    }
+}
@@ -41,9 +41,40 @@ impl ReverseOrdering_ for TGreater {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Used to compare collections lexicographically,
+/// evaluating to `Self` unless it's `TEqual`,
+/// in which case it compares `Left` and `Right`.
+pub trait ThenCompare<Left, Right> {
+    type Output;
+}
+
+impl<Left, Right> ThenCompare<Left, Right> for TLess {
+    type Output = TLess;
+}
+impl<Left, Right> ThenCompare<Left, Right> for TEqual
+where
+    Left: Compare<Right>,
+{
+    type Output = CompareOut<Left, Right>;
+}
+impl<Left, Right> ThenCompare<Left, Right> for TGreater {
+    type Output = TGreater;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A marker trait for the type-level `Ordering`s other than `TEqual`.
+pub trait IsNotEqual {}
+
+impl IsNotEqual for TLess {}
+impl IsNotEqual for TGreater {}
+
+////////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
-    use super::{ReverseOrdering, TEqual, TGreater, TLess};
+    use super::{ReverseOrdering, TEqual, TGreater, TLess, ThenCompare};
+    use crate::type_level::integer::{U0, U1};
     use core_extensions::type_asserts::AssertEq;
 
     #[test]
@@ -52,4 +83,14 @@ mod tests {
         let _: AssertEq<ReverseOrdering<TEqual>, TEqual>;
         let _: AssertEq<ReverseOrdering<TGreater>, TLess>;
     }
+
+    #[test]
+    fn then_compare() {
+        type Then<This, L, R> = <This as ThenCompare<L, R>>::Output;
+
+        let _: AssertEq<Then<TLess, TGreater, TLess>, TLess>;
+        let _: AssertEq<Then<TGreater, TLess, TGreater>, TGreater>;
+        let _: AssertEq<Then<TEqual, U0, U1>, TLess>;
+        let _: AssertEq<Then<TEqual, U1, U1>, TEqual>;
+    }
 }
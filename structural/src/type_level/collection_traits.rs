@@ -76,3 +76,79 @@ pub trait Flatten {
 
 /// FlattenOuts a collection of collection.
 pub type FlattenOut<This> = <This as Flatten>::Output;
+
+///////////////////////////////////////////////////////////
+
+/// Gets this collection type with the first element equal to `Elem` removed,
+/// comparing elements with the [`Compare`] trait.
+///
+/// This is only implemented when `Elem` is in the collection.
+///
+/// [`Compare`]: ../cmp/trait.Compare.html
+pub trait Remove<Elem> {
+    /// The return value of this trait.
+    type Output;
+}
+
+/// Gets the `This` collection type with the first element equal to `Elem` removed.
+pub type RemoveOut<This, Elem> = <This as Remove<Elem>>::Output;
+
+///////////////////////////////////////////////////////////
+
+/// Gets this collection type with the first element equal to `Old` replaced with `New`,
+/// comparing elements with the [`Compare`] trait.
+///
+/// This is only implemented when `Old` is in the collection.
+///
+/// [`Compare`]: ../cmp/trait.Compare.html
+pub trait Replace<Old, New> {
+    /// The return value of this trait.
+    type Output;
+}
+
+/// Gets the `This` collection type with the first element equal to `Old` replaced with `New`.
+pub type ReplaceOut<This, Old, New> = <This as Replace<Old, New>>::Output;
+
+///////////////////////////////////////////////////////////
+
+/// Gets this collection type with the elements of `Other` that aren't in it
+/// appended at the end,
+/// comparing elements with the [`Compare`] trait.
+///
+/// [`Compare`]: ../cmp/trait.Compare.html
+pub trait Union<Other> {
+    /// The return value of this trait.
+    type Output;
+}
+
+/// Gets the `This` collection type with the elements of `Other` that aren't in it
+/// appended at the end.
+pub type UnionOut<This, Other> = <This as Union<Other>>::Output;
+
+///////////////////////////////////////////////////////////
+
+/// Gets this collection type with only the elements that are also in `Other`,
+/// comparing elements with the [`Compare`] trait.
+///
+/// [`Compare`]: ../cmp/trait.Compare.html
+pub trait Intersection<Other> {
+    /// The return value of this trait.
+    type Output;
+}
+
+/// Gets the `This` collection type with only the elements that are also in `Other`.
+pub type IntersectionOut<This, Other> = <This as Intersection<Other>>::Output;
+
+///////////////////////////////////////////////////////////
+
+/// Gets this collection type without the elements that are in `Other`,
+/// comparing elements with the [`Compare`] trait.
+///
+/// [`Compare`]: ../cmp/trait.Compare.html
+pub trait Difference<Other> {
+    /// The return value of this trait.
+    type Output;
+}
+
+/// Gets the `This` collection type without the elements that are in `Other`.
+pub type DifferenceOut<This, Other> = <This as Difference<Other>>::Output;
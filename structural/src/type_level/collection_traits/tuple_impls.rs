@@ -1,4 +1,7 @@
-use super::{Append, Flatten, FlattenOut, ToTList, ToTListOut, ToTuple, ToTupleOut};
+use super::{
+    Append, Difference, Flatten, FlattenOut, Intersection, Remove, Replace, ToTList, ToTListOut,
+    ToTuple, ToTupleOut, Union,
+};
 
 macro_rules! tuple_impls {
     (with-idents;
@@ -37,6 +40,54 @@ macro_rules! tuple_impls {
                 type Output=ToTupleOut<AppendOuted>;
             }
 
+            impl<$($tparams,)* Elem,Removed> Remove<Elem> for ($($tparams,)*)
+            where
+                Self:ToTList,
+                ToTListOut<Self>:Remove<Elem,Output=Removed>,
+                Removed:ToTuple,
+            {
+                type Output=ToTupleOut<Removed>;
+            }
+
+            impl<$($tparams,)* Old,New,Replaced> Replace<Old,New> for ($($tparams,)*)
+            where
+                Self:ToTList,
+                ToTListOut<Self>:Replace<Old,New,Output=Replaced>,
+                Replaced:ToTuple,
+            {
+                type Output=ToTupleOut<Replaced>;
+            }
+
+            impl<$($tparams,)* Other,United> Union<Other> for ($($tparams,)*)
+            where
+                Self:ToTList,
+                Other:ToTList,
+                ToTListOut<Self>:Union<ToTListOut<Other>,Output=United>,
+                United:ToTuple,
+            {
+                type Output=ToTupleOut<United>;
+            }
+
+            impl<$($tparams,)* Other,Intersected> Intersection<Other> for ($($tparams,)*)
+            where
+                Self:ToTList,
+                Other:ToTList,
+                ToTListOut<Self>:Intersection<ToTListOut<Other>,Output=Intersected>,
+                Intersected:ToTuple,
+            {
+                type Output=ToTupleOut<Intersected>;
+            }
+
+            impl<$($tparams,)* Other,Subtracted> Difference<Other> for ($($tparams,)*)
+            where
+                Self:ToTList,
+                Other:ToTList,
+                ToTListOut<Self>:Difference<ToTListOut<Other>,Output=Subtracted>,
+                Subtracted:ToTuple,
+            {
+                type Output=ToTupleOut<Subtracted>;
+            }
+
         )*
     }
 }
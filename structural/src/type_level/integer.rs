@@ -201,6 +201,7 @@ impl<This, Other> CompareBit<TGreater, Other> for This {
 
 /*
 fn main() {
+    // `len` is 8 for the impl used to compare bytes.
     let len=6;
 
     print!("impl<");
@@ -237,6 +238,47 @@ where
     type Output = T5;
 }
 
+// Used to compare bytes.
+impl<
+        L0,
+        R0,
+        T0,
+        L1,
+        R1,
+        T1,
+        L2,
+        R2,
+        T2,
+        L3,
+        R3,
+        T3,
+        L4,
+        R4,
+        T4,
+        L5,
+        R5,
+        T5,
+        L6,
+        R6,
+        T6,
+        L7,
+        R7,
+        T7,
+    > Compare<Unsigned<(R0, R1, R2, R3, R4, R5, R6, R7)>>
+    for Unsigned<(L0, L1, L2, L3, L4, L5, L6, L7)>
+where
+    L0: CompareBit<TEqual, R0, Output = T0>,
+    L1: CompareBit<T0, R1, Output = T1>,
+    L2: CompareBit<T1, R2, Output = T2>,
+    L3: CompareBit<T2, R3, Output = T3>,
+    L4: CompareBit<T3, R4, Output = T4>,
+    L5: CompareBit<T4, R5, Output = T5>,
+    L6: CompareBit<T5, R6, Output = T6>,
+    L7: CompareBit<T6, R7, Output = T7>,
+{
+    type Output = T7;
+}
+
 /////////////////////////////////////////////////////////////////////
 
 impl<B5, B4, B3, B2, B1, B0> ToUsize for Unsigned<(B5, B4, B3, B2, B1, B0)>
@@ -257,3 +299,27 @@ where
             | (B0::VALUE as usize)
     };
 }
+
+// Used to get the value of bytes.
+impl<B7, B6, B5, B4, B3, B2, B1, B0> ToUsize for Unsigned<(B7, B6, B5, B4, B3, B2, B1, B0)>
+where
+    B7: IsBit,
+    B6: IsBit,
+    B5: IsBit,
+    B4: IsBit,
+    B3: IsBit,
+    B2: IsBit,
+    B1: IsBit,
+    B0: IsBit,
+{
+    const USIZE: usize = {
+        ((B7::VALUE as usize) << 7)
+            | ((B6::VALUE as usize) << 6)
+            | ((B5::VALUE as usize) << 5)
+            | ((B4::VALUE as usize) << 4)
+            | ((B3::VALUE as usize) << 3)
+            | ((B2::VALUE as usize) << 2)
+            | ((B1::VALUE as usize) << 1)
+            | (B0::VALUE as usize)
+    };
+}
@@ -7,8 +7,12 @@ use std_::{
     marker::PhantomData,
 };
 
-use crate::type_level::collection_traits::{
-    Append, AppendOut, Flatten, PushBack, PushBackOut, ToTList, ToTListOut,
+use crate::type_level::{
+    cmp::{Compare, CompareOut, TEqual, TGreater, TLess, ThenCompare},
+    collection_traits::{
+        Append, AppendOut, Difference, DifferenceOut, Flatten, Intersection, IntersectionOut,
+        PushBack, PushBackOut, Remove, RemoveOut, Replace, ReplaceOut, ToTList, ToTListOut, Union,
+    },
 };
 
 #[cfg(test)]
@@ -110,7 +114,8 @@ impl Flatten for TNil {
 }
 impl<Curr, Rem, Out> Flatten for TList<Curr, Rem>
 where
-    (): FlattenOutImpl<Rem, Curr, Output = Out>,
+    Curr: ToTList,
+    (): FlattenOutImpl<Rem, ToTListOut<Curr>, Output = Out>,
 {
     type Output = Out;
 }
@@ -142,3 +147,212 @@ where
 {
     type Output = TList<CurrI, Out>;
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+impl<Curr, Rem, Elem> Remove<Elem> for TList<Curr, Rem>
+where
+    Elem: Compare<Curr>,
+    CompareOut<Elem, Curr>: RemoveHelper<Curr, Rem, Elem>,
+{
+    type Output = <CompareOut<Elem, Curr> as RemoveHelper<Curr, Rem, Elem>>::Output;
+}
+
+#[doc(hidden)]
+pub trait RemoveHelper<Curr, Rem, Elem> {
+    type Output;
+}
+
+impl<Curr, Rem, Elem> RemoveHelper<Curr, Rem, Elem> for TEqual {
+    type Output = Rem;
+}
+
+impl<Curr, Rem, Elem> RemoveHelper<Curr, Rem, Elem> for TLess
+where
+    Rem: Remove<Elem>,
+{
+    type Output = TList<Curr, RemoveOut<Rem, Elem>>;
+}
+
+impl<Curr, Rem, Elem> RemoveHelper<Curr, Rem, Elem> for TGreater
+where
+    Rem: Remove<Elem>,
+{
+    type Output = TList<Curr, RemoveOut<Rem, Elem>>;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+impl<Curr, Rem, Old, New> Replace<Old, New> for TList<Curr, Rem>
+where
+    Old: Compare<Curr>,
+    CompareOut<Old, Curr>: ReplaceHelper<Curr, Rem, Old, New>,
+{
+    type Output = <CompareOut<Old, Curr> as ReplaceHelper<Curr, Rem, Old, New>>::Output;
+}
+
+#[doc(hidden)]
+pub trait ReplaceHelper<Curr, Rem, Old, New> {
+    type Output;
+}
+
+impl<Curr, Rem, Old, New> ReplaceHelper<Curr, Rem, Old, New> for TEqual {
+    type Output = TList<New, Rem>;
+}
+
+impl<Curr, Rem, Old, New> ReplaceHelper<Curr, Rem, Old, New> for TLess
+where
+    Rem: Replace<Old, New>,
+{
+    type Output = TList<Curr, ReplaceOut<Rem, Old, New>>;
+}
+
+impl<Curr, Rem, Old, New> ReplaceHelper<Curr, Rem, Old, New> for TGreater
+where
+    Rem: Replace<Old, New>,
+{
+    type Output = TList<Curr, ReplaceOut<Rem, Old, New>>;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Queries whether `Elem` is in this list,comparing elements with the `Compare` trait,
+/// evaluating to `InList` or `NotInList`.
+#[doc(hidden)]
+pub trait ListContains<Elem> {
+    type Output;
+}
+
+/// The `ListContains` result for elements that are in the list.
+#[doc(hidden)]
+pub struct InList;
+
+/// The `ListContains` result for elements that aren't in the list.
+#[doc(hidden)]
+pub struct NotInList;
+
+impl<Elem> ListContains<Elem> for TNil {
+    type Output = NotInList;
+}
+
+impl<Curr, Rem, Elem> ListContains<Elem> for TList<Curr, Rem>
+where
+    Elem: Compare<Curr>,
+    CompareOut<Elem, Curr>: ContainsHelper<Rem, Elem>,
+{
+    type Output = <CompareOut<Elem, Curr> as ContainsHelper<Rem, Elem>>::Output;
+}
+
+#[doc(hidden)]
+pub trait ContainsHelper<Rem, Elem> {
+    type Output;
+}
+
+impl<Rem, Elem> ContainsHelper<Rem, Elem> for TEqual {
+    type Output = InList;
+}
+
+impl<Rem, Elem> ContainsHelper<Rem, Elem> for TLess
+where
+    Rem: ListContains<Elem>,
+{
+    type Output = Rem::Output;
+}
+
+impl<Rem, Elem> ContainsHelper<Rem, Elem> for TGreater
+where
+    Rem: ListContains<Elem>,
+{
+    type Output = Rem::Output;
+}
+
+/// Evaluates to `TList<Curr, Rem>` if `Self` is `Wanted`,otherwise to `Rem`.
+#[doc(hidden)]
+pub trait KeepIf<Wanted, Curr, Rem> {
+    type Output;
+}
+
+impl<Curr, Rem> KeepIf<InList, Curr, Rem> for InList {
+    type Output = TList<Curr, Rem>;
+}
+
+impl<Curr, Rem> KeepIf<NotInList, Curr, Rem> for NotInList {
+    type Output = TList<Curr, Rem>;
+}
+
+impl<Curr, Rem> KeepIf<InList, Curr, Rem> for NotInList {
+    type Output = Rem;
+}
+
+impl<Curr, Rem> KeepIf<NotInList, Curr, Rem> for InList {
+    type Output = Rem;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+impl<Other> Intersection<Other> for TNil {
+    type Output = TNil;
+}
+
+impl<Curr, Rem, Other> Intersection<Other> for TList<Curr, Rem>
+where
+    Other: ListContains<Curr>,
+    Rem: Intersection<Other>,
+    Other::Output: KeepIf<InList, Curr, IntersectionOut<Rem, Other>>,
+{
+    type Output = <Other::Output as KeepIf<InList, Curr, IntersectionOut<Rem, Other>>>::Output;
+}
+
+impl<Other> Difference<Other> for TNil {
+    type Output = TNil;
+}
+
+impl<Curr, Rem, Other> Difference<Other> for TList<Curr, Rem>
+where
+    Other: ListContains<Curr>,
+    Rem: Difference<Other>,
+    Other::Output: KeepIf<NotInList, Curr, DifferenceOut<Rem, Other>>,
+{
+    type Output = <Other::Output as KeepIf<NotInList, Curr, DifferenceOut<Rem, Other>>>::Output;
+}
+
+impl<Other> Union<Other> for TNil
+where
+    Other: Difference<TNil>,
+    TNil: Append<DifferenceOut<Other, TNil>>,
+{
+    type Output = AppendOut<TNil, DifferenceOut<Other, TNil>>;
+}
+
+impl<Curr, Rem, Other> Union<Other> for TList<Curr, Rem>
+where
+    Other: Difference<Self>,
+    Self: Append<DifferenceOut<Other, Self>>,
+{
+    type Output = AppendOut<Self, DifferenceOut<Other, Self>>;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+// Compares the lists lexicographically,
+// like the `Ord` impls of slices do.
+
+impl Compare<TNil> for TNil {
+    type Output = TEqual;
+}
+
+impl<T, Rem> Compare<TList<T, Rem>> for TNil {
+    type Output = TLess;
+}
+
+impl<T, Rem> Compare<TNil> for TList<T, Rem> {
+    type Output = TGreater;
+}
+
+impl<T, Rem, T2, Rem2> Compare<TList<T2, Rem2>> for TList<T, Rem>
+where
+    T: Compare<T2>,
+    CompareOut<T, T2>: ThenCompare<Rem, Rem2>,
+{
+    type Output = <CompareOut<T, T2> as ThenCompare<Rem, Rem2>>::Output;
+}
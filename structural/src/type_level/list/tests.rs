@@ -1,4 +1,11 @@
-use crate::type_level::collection_traits::{AppendOut, FlattenOut, PushBackOut};
+use crate::type_level::{
+    cmp::{CompareOut, TEqual, TGreater, TLess},
+    collection_traits::{
+        AppendOut, DifferenceOut, FlattenOut, IntersectionOut, PushBackOut, RemoveOut, ReplaceOut,
+        UnionOut,
+    },
+    integer::{U0, U1, U2, U3},
+};
 
 use core_extensions::type_asserts::AssertEq;
 
@@ -41,6 +48,7 @@ fn flatten() {
     let _: AssertEq<FlattenOut<TList![TList![], TList![A]]>, TList![A]>;
     let _: AssertEq<FlattenOut<TList![TList![A], TList![B]]>, TList![A, B]>;
     let _: AssertEq<FlattenOut<TList![TList![A, B], TList![C]]>, TList![A, B, C]>;
+    let _: AssertEq<FlattenOut<TList![(A, B), (C,)]>, TList![A, B, C]>;
     let _: AssertEq<FlattenOut<TList![TList![A, B], TList![C, D]]>, TList![A, B, C, D]>;
     let _: AssertEq<FlattenOut<TList![TList![A, B], TList![], TList![C, D]]>, TList![A, B, C, D]>;
     let _: AssertEq<
@@ -48,3 +56,64 @@ fn flatten() {
         TList![A, B, C, D, E, F],
     >;
 }
+
+#[test]
+fn compare() {
+    let _: AssertEq<CompareOut<TList![], TList![]>, TEqual>;
+    let _: AssertEq<CompareOut<TList![], TList![U0]>, TLess>;
+    let _: AssertEq<CompareOut<TList![U0], TList![]>, TGreater>;
+    let _: AssertEq<CompareOut<TList![U0, U1], TList![U0, U1]>, TEqual>;
+    let _: AssertEq<CompareOut<TList![U0, U1], TList![U0, U2]>, TLess>;
+    let _: AssertEq<CompareOut<TList![U1, U0], TList![U0, U2]>, TGreater>;
+    let _: AssertEq<CompareOut<TList![U0, U1], TList![U0, U1, U2]>, TLess>;
+    let _: AssertEq<CompareOut<TList![U0, U2], TList![U0, U1, U2]>, TGreater>;
+}
+
+#[test]
+fn remove() {
+    let _: AssertEq<RemoveOut<TList![U0], U0>, TList![]>;
+    let _: AssertEq<RemoveOut<TList![U0, U1, U2], U0>, TList![U1, U2]>;
+    let _: AssertEq<RemoveOut<TList![U0, U1, U2], U1>, TList![U0, U2]>;
+    let _: AssertEq<RemoveOut<TList![U0, U1, U2], U2>, TList![U0, U1]>;
+    let _: AssertEq<RemoveOut<TList![U1, U0, U1], U1>, TList![U0, U1]>;
+    let _: AssertEq<RemoveOut<(U0, U1, U2), U1>, (U0, U2)>;
+}
+
+#[test]
+fn replace() {
+    let _: AssertEq<ReplaceOut<TList![U0], U0, U3>, TList![U3]>;
+    let _: AssertEq<ReplaceOut<TList![U0, U1, U2], U0, U3>, TList![U3, U1, U2]>;
+    let _: AssertEq<ReplaceOut<TList![U0, U1, U2], U1, U3>, TList![U0, U3, U2]>;
+    let _: AssertEq<ReplaceOut<TList![U0, U1, U2], U2, U3>, TList![U0, U1, U3]>;
+    let _: AssertEq<ReplaceOut<TList![U1, U0, U1], U1, U3>, TList![U3, U0, U1]>;
+    let _: AssertEq<ReplaceOut<(U0, U1, U2), U2, U3>, (U0, U1, U3)>;
+}
+
+#[test]
+fn union() {
+    let _: AssertEq<UnionOut<TList![], TList![]>, TList![]>;
+    let _: AssertEq<UnionOut<TList![], TList![U0, U1]>, TList![U0, U1]>;
+    let _: AssertEq<UnionOut<TList![U0, U1], TList![]>, TList![U0, U1]>;
+    let _: AssertEq<UnionOut<TList![U0, U1], TList![U1, U2]>, TList![U0, U1, U2]>;
+    let _: AssertEq<UnionOut<TList![U2, U0], TList![U0, U1, U2, U3]>, TList![U2, U0, U1, U3]>;
+    let _: AssertEq<UnionOut<TList![U0, U1], TList![U1, U0]>, TList![U0, U1]>;
+    let _: AssertEq<UnionOut<(U0, U1), (U1, U2)>, (U0, U1, U2)>;
+}
+
+#[test]
+fn intersection() {
+    let _: AssertEq<IntersectionOut<TList![], TList![U0]>, TList![]>;
+    let _: AssertEq<IntersectionOut<TList![U0], TList![]>, TList![]>;
+    let _: AssertEq<IntersectionOut<TList![U0, U1, U2], TList![U2, U0]>, TList![U0, U2]>;
+    let _: AssertEq<IntersectionOut<TList![U0, U1], TList![U2, U3]>, TList![]>;
+    let _: AssertEq<IntersectionOut<(U0, U1, U2), (U1, U3)>, (U1,)>;
+}
+
+#[test]
+fn difference() {
+    let _: AssertEq<DifferenceOut<TList![], TList![U0]>, TList![]>;
+    let _: AssertEq<DifferenceOut<TList![U0, U1], TList![]>, TList![U0, U1]>;
+    let _: AssertEq<DifferenceOut<TList![U0, U1, U2], TList![U2, U0]>, TList![U1]>;
+    let _: AssertEq<DifferenceOut<TList![U0, U1], TList![U0, U1]>, TList![]>;
+    let _: AssertEq<DifferenceOut<(U0, U1, U2), (U1, U3)>, (U0, U2)>;
+}
@@ -8,6 +8,40 @@ pub trait ToUsize {
     const USIZE: usize;
 }
 
+/// Converts this type-level string to a `&'static str`.
+///
+/// This is implemented for [`TStr`](../../struct.TStr.html)s of up to 32 bytes.
+///
+/// # Panics
+///
+/// `to_str` panics if `BYTES` isn't valid UTF-8,
+/// which can only happen with `TStr`s that weren't constructed with
+/// the macros from this crate (ie: with hand-written `TStr<__TS<(...)>>` types).
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{type_level::to_value_traits::ToStr, ts};
+///
+/// assert_eq!( ts!(foo).to_str(), "foo" );
+/// assert_eq!( ts!("hello world").to_str(), "hello world" );
+/// assert_eq!( ts!(10).to_str(), "10" );
+///
+/// ```
+pub trait ToStr {
+    /// The UTF-8 encoded string that this type represents.
+    const BYTES: &'static [u8];
+
+    /// Gets the string that this type represents.
+    #[inline]
+    fn to_str(&self) -> &'static str {
+        match std_::str::from_utf8(Self::BYTES) {
+            Ok(x) => x,
+            Err(e) => panic!("type-level string is not valid UTF-8: {}", e),
+        }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 mod sealed {
@@ -0,0 +1,283 @@
+/*!
+Field-by-field initialization of a `MaybeUninit<T>`,
+tracking which fields were initialized at the type level.
+
+The main item in this module is [`UninitBuilder`].
+
+[`UninitBuilder`]: ./struct.UninitBuilder.html
+*/
+
+use crate::{
+    field::{AllFields, GetFieldMut, GetFieldType},
+    type_level::{
+        collection_traits::{Remove, RemoveOut},
+        TList, TNil,
+    },
+};
+
+use core_extensions::ConstDefault;
+
+use std_::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+/// Initializes a `T` in place one field at a time,
+/// only allowing the value to be used once every field has been initialized.
+///
+/// `T` must implement [`AllFields`],
+/// which the `Structural` derive does for structs in which every field has
+/// mutable accessor impls (the default for public fields).
+///
+/// The builder borrows the `MaybeUninit<T>` that is initialized,
+/// so that the value isn't moved around while it's being built,
+/// and [`finish`] returns a mutable reference to the initialized value.
+/// Once [`finish`] is called,
+/// it's safe to call `MaybeUninit::assume_init` on the `MaybeUninit<T>`.
+///
+/// The `Remaining` type parameter is a type-level list of the names of the fields
+/// that haven't been initialized yet,
+/// while `Init` is a type-level list of the names of the initialized fields.
+///
+/// If the builder is dropped before calling [`finish`],
+/// only the fields that were initialized are dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{Structural, UninitBuilder, fp};
+///
+/// use std::mem::MaybeUninit;
+///
+/// #[derive(Structural, Debug, PartialEq)]
+/// #[struc(public)]
+/// struct Config {
+///     name: String,
+///     retries: u32,
+///     verbose: bool,
+/// }
+///
+/// let mut slot = MaybeUninit::<Config>::uninit();
+///
+/// let config: &mut Config = UninitBuilder::new(&mut slot)
+///     .init(fp!(verbose), true)
+///     .init(fp!(name), "server".to_string())
+///     .init(fp!(retries), 3)
+///     .finish();
+///
+/// config.retries += 1;
+///
+/// // Every field of `slot` was initialized by the builder.
+/// let config = unsafe{ slot.assume_init() };
+///
+/// assert_eq!(
+///     config,
+///     Config{ name: "server".to_string(), retries: 4, verbose: true },
+/// );
+///
+/// // Only the `name` field is dropped here.
+/// let mut slot = MaybeUninit::<Config>::uninit();
+/// let _ = UninitBuilder::new(&mut slot).init(fp!(name), "partial".to_string());
+///
+/// ```
+///
+/// ### Uninitialized fields
+///
+/// This doesn't compile because the `verbose` field isn't initialized:
+///
+/// ```compile_fail
+/// use structural::{Structural, UninitBuilder, fp};
+///
+/// use std::mem::MaybeUninit;
+///
+/// #[derive(Structural)]
+/// #[struc(public)]
+/// struct Config {
+///     name: String,
+///     retries: u32,
+///     verbose: bool,
+/// }
+///
+/// let mut slot = MaybeUninit::<Config>::uninit();
+/// let _ = UninitBuilder::new(&mut slot)
+///     .init(fp!(name), "server".to_string())
+///     .init(fp!(retries), 3)
+///     .finish();
+/// ```
+///
+/// ### Initializing a field twice
+///
+/// This doesn't compile because the `retries` field is initialized twice:
+///
+/// ```compile_fail
+/// use structural::{Structural, UninitBuilder, fp};
+///
+/// use std::mem::MaybeUninit;
+///
+/// #[derive(Structural)]
+/// #[struc(public)]
+/// struct Config {
+///     name: String,
+///     retries: u32,
+///     verbose: bool,
+/// }
+///
+/// let mut slot = MaybeUninit::<Config>::uninit();
+/// let _ = UninitBuilder::new(&mut slot)
+///     .init(fp!(retries), 3)
+///     .init(fp!(retries), 5);
+/// ```
+///
+/// [`AllFields`]: ./field/trait.AllFields.html
+/// [`finish`]: #method.finish
+pub struct UninitBuilder<'a, T, Remaining, Init>
+where
+    Init: DropInitFields<T>,
+{
+    value: &'a mut MaybeUninit<T>,
+    _marker: PhantomData<fn() -> (Remaining, Init)>,
+}
+
+impl<'a, T> UninitBuilder<'a, T, <T as AllFields>::Names, TNil>
+where
+    T: AllFields,
+{
+    /// Constructs an `UninitBuilder` that initializes `value`,
+    /// with no initialized fields.
+    ///
+    /// Any value that `value` already contains is overwritten without being dropped.
+    #[inline(always)]
+    pub fn new(value: &'a mut MaybeUninit<T>) -> Self {
+        Self {
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, Remaining, Init> UninitBuilder<'a, T, Remaining, Init>
+where
+    Init: DropInitFields<T>,
+{
+    /// Initializes the `name` field with `value`.
+    ///
+    /// This only compiles if the `name` field is not yet initialized.
+    #[inline]
+    pub fn init<N>(
+        self,
+        name: N,
+        value: GetFieldType<T, N>,
+    ) -> UninitBuilder<'a, T, RemoveOut<Remaining, N>, TList<N, Init>>
+    where
+        T: GetFieldMut<N>,
+        Remaining: Remove<N>,
+        N: ConstDefault,
+    {
+        unsafe {
+            let field = T::get_field_raw_mut(self.value.as_mut_ptr() as *mut (), name);
+            ptr::write(field, value);
+            UninitBuilder {
+                value: self.into_inner(),
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    /// Gets a raw pointer to the value being initialized.
+    ///
+    /// The fields in `Init` are initialized,all the other fields are uninitialized.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const T {
+        self.value.as_ptr()
+    }
+
+    /// Gets the borrowed `MaybeUninit<T>` without dropping the initialized fields.
+    #[inline(always)]
+    fn into_inner(self) -> &'a mut MaybeUninit<T> {
+        let this = ManuallyDrop::new(self);
+        unsafe { ptr::read(&this.value) }
+    }
+}
+
+impl<'a, T, Init> UninitBuilder<'a, T, TNil, Init>
+where
+    Init: DropInitFields<T>,
+{
+    /// Gets a mutable reference to the value,now that every field is initialized.
+    ///
+    /// The value isn't dropped by the builder after this is called,
+    /// it can be dropped by calling `MaybeUninit::assume_init`
+    /// (or `ptr::drop_in_place`) on the `MaybeUninit<T>`.
+    #[inline]
+    pub fn finish(self) -> &'a mut T {
+        unsafe { &mut *self.into_inner().as_mut_ptr() }
+    }
+}
+
+impl<'a, T, Remaining, Init> Drop for UninitBuilder<'a, T, Remaining, Init>
+where
+    Init: DropInitFields<T>,
+{
+    fn drop(&mut self) {
+        unsafe {
+            Init::drop_init_fields(self.value.as_mut_ptr());
+        }
+    }
+}
+
+impl<'a, T, Remaining, Init> Debug for UninitBuilder<'a, T, Remaining, Init>
+where
+    Init: DropInitFields<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UninitBuilder").finish()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Drops the fields of a `T` that a type-level list of field names refers to.
+///
+/// This is implemented for [`TNil`] and [`TList`]s of field names.
+///
+/// [`TNil`]: ../type_level/list/struct.TNil.html
+/// [`TList`]: ../type_level/list/struct.TList.html
+pub trait DropInitFields<T> {
+    /// Drops the fields in `Self`.
+    ///
+    /// # Safety
+    ///
+    /// `this` must point to a `T` in which the fields in `Self` are initialized,
+    /// and those fields must not be used after this is called.
+    unsafe fn drop_init_fields(this: *mut T);
+}
+
+impl<T> DropInitFields<T> for TNil {
+    #[inline(always)]
+    unsafe fn drop_init_fields(_: *mut T) {}
+}
+
+impl<T, N, Rem> DropInitFields<T> for TList<N, Rem>
+where
+    T: GetFieldMut<N>,
+    N: ConstDefault,
+    Rem: DropInitFields<T>,
+{
+    #[inline]
+    unsafe fn drop_init_fields(this: *mut T) {
+        // Dropping the rest of the fields even if dropping this one panics.
+        struct Guard<T, Rem: DropInitFields<T>>(*mut T, PhantomData<Rem>);
+
+        impl<T, Rem: DropInitFields<T>> Drop for Guard<T, Rem> {
+            fn drop(&mut self) {
+                unsafe { Rem::drop_init_fields(self.0) }
+            }
+        }
+
+        let guard = Guard::<T, Rem>(this, PhantomData);
+        ptr::drop_in_place(T::get_field_raw_mut(this as *mut (), N::DEFAULT));
+        drop(guard);
+    }
+}
@@ -123,6 +123,91 @@ where
     }
 }
 
+// Used in the `Unpin` impl generated by the `Structural` derive for types with
+// `#[struc(pin)]` fields.
+// The lifetime parameter prevents the bound from being a trivially false bound
+// when the type of a pinned field is a concrete type that doesn't implement `Unpin`.
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub struct _Structural_PinnedField<'a, T: ?Sized>(PhantomData<&'a ()>, T);
+
+// Used in the `GetFieldMut` impls generated by the `delegate_fields` form of
+// `unsafe_delegate_structural_with`,forwarding to the `GetFieldMut` impl of `Self`.
+// The lifetime parameter prevents the bound from being a trivially false bound
+// when the delegated-to type is a concrete type that doesn't implement `GetFieldMut`.
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub unsafe trait _Structural_DelegatedFieldMut<'a, N> {
+    type Ty;
+
+    fn get_field_mut_(&mut self, field_name: N) -> &mut Self::Ty;
+
+    unsafe fn get_field_raw_mut(ptr: *mut (), field_name: N) -> *mut Self::Ty
+    where
+        Self: Sized;
+}
+
+unsafe impl<'a, N, T> _Structural_DelegatedFieldMut<'a, N> for T
+where
+    T: ?Sized + crate::GetFieldMut<N>,
+{
+    type Ty = T::Ty;
+
+    #[inline(always)]
+    fn get_field_mut_(&mut self, field_name: N) -> &mut Self::Ty {
+        crate::GetFieldMut::get_field_mut_(self, field_name)
+    }
+
+    #[inline(always)]
+    unsafe fn get_field_raw_mut(ptr: *mut (), field_name: N) -> *mut Self::Ty
+    where
+        Self: Sized,
+    {
+        <T as crate::GetFieldMut<N>>::get_field_raw_mut(ptr, field_name)
+    }
+}
+
+// Used in the `IntoField` impls generated by the `delegate_fields` form of
+// `unsafe_delegate_structural_with`,forwarding to the `IntoField` impl of `Self`.
+// The lifetime parameter prevents the bound from being a trivially false bound
+// when the delegated-to type is a concrete type that doesn't implement `IntoField`.
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub unsafe trait _Structural_DelegatedIntoField<'a, N>: crate::field::DropFields {
+    type Ty;
+
+    fn into_field_(self, field_name: N) -> Self::Ty
+    where
+        Self: Sized;
+
+    unsafe fn move_out_field_(
+        &mut self,
+        field_name: N,
+        moved_fields: &mut crate::field::MovedOutFields,
+    ) -> Self::Ty;
+}
+
+unsafe impl<'a, N, T> _Structural_DelegatedIntoField<'a, N> for T
+where
+    T: crate::IntoField<N>,
+{
+    type Ty = T::Ty;
+
+    #[inline(always)]
+    fn into_field_(self, field_name: N) -> Self::Ty {
+        crate::IntoField::into_field_(self, field_name)
+    }
+
+    #[inline(always)]
+    unsafe fn move_out_field_(
+        &mut self,
+        field_name: N,
+        moved_fields: &mut crate::field::MovedOutFields,
+    ) -> Self::Ty {
+        crate::IntoField::move_out_field_(self, field_name, moved_fields)
+    }
+}
+
 /////////////////////////////////////////////////////////
 
 /// Takes the contents out of a `ManuallyDrop<T>`.
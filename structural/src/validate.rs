@@ -0,0 +1,374 @@
+/*!
+Validating the fields of structural types,collecting all the errors.
+
+The [`Validate`] trait validates a whole value,
+and the [`ValidateField`] trait validates an individual field,
+allowing a subset of the fields to be validated with [`Validate::validate_fields`].
+
+These traits are usually implemented with the `#[struc(validate = "...")]`
+attributes of the `Structural` derive,
+which also make the derived `TryFromStructural` impl
+(from the `#[struc(from_structural)]` attribute) validate the converted-from fields,
+and then the converted value with the container validator.
+
+This module requires the "alloc" feature.
+
+# Example
+
+```rust
+use structural::{
+    validate::{Validate, ValidationError, ValidationErrors},
+    Structural, StructuralExt, fp, make_struct,
+};
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+#[struc(validate = "check_bounds")]
+struct Shipment {
+    #[struc(validate = "non_empty")]
+    address: String,
+    #[struc(validate = "positive")]
+    weight_kg: u32,
+    max_weight_kg: u32,
+}
+
+fn non_empty(s: &str) -> Result<(), &'static str> {
+    if s.is_empty() { Err("must not be empty") } else { Ok(()) }
+}
+
+fn positive(n: &u32) -> Result<(), &'static str> {
+    if *n == 0 { Err("must be positive") } else { Ok(()) }
+}
+
+fn check_bounds(this: &Shipment) -> Result<(), String> {
+    if this.weight_kg > this.max_weight_kg {
+        Err(format!("{}kg is over the {}kg limit", this.weight_kg, this.max_weight_kg))
+    } else {
+        Ok(())
+    }
+}
+
+let shipment = Shipment{
+    address: String::new(),
+    weight_kg: 30,
+    max_weight_kg: 20,
+};
+
+let errors = shipment.validate().unwrap_err();
+assert_eq!(
+    errors.as_slice(),
+    &[
+        ValidationError::field("address", "must not be empty"),
+        ValidationError::container("30kg is over the 20kg limit"),
+    ][..],
+);
+assert_eq!(errors.field_names().collect::<Vec<_>>(), vec!["address"]);
+
+// Only validating the `weight_kg` field
+assert_eq!(shipment.validate_fields(fp!(weight_kg)), Ok(()));
+
+// Converting a value with invalid fields returns the errors,
+// as well as the converted-from value.
+let anon = make_struct!{
+    address: "".to_string(),
+    weight_kg: 0,
+    max_weight_kg: 10,
+};
+let err = anon.try_into_struc::<Shipment>().unwrap_err();
+assert_eq!(err.error.field_names().collect::<Vec<_>>(), vec!["address", "weight_kg"]);
+assert_eq!(err.from.fields(fp!(weight_kg, max_weight_kg)), (&0, &10));
+
+// The container validator runs on the converted value,
+// returning the converted-from value if it fails.
+let anon = make_struct!{
+    address: "Nowhere".to_string(),
+    weight_kg: 30,
+    max_weight_kg: 20,
+};
+let err = anon.try_into_struc::<Shipment>().unwrap_err();
+assert_eq!(err.error.as_slice(), &[ValidationError::container("30kg is over the 20kg limit")][..]);
+assert_eq!(err.from.fields(fp!(weight_kg, max_weight_kg)), (&30, &20));
+
+```
+
+*/
+
+use crate::{
+    alloc::{borrow::Cow, vec::Vec},
+    path::{FieldPathSet, TStr},
+};
+
+use std_::{
+    fmt::{self, Display},
+    slice,
+};
+
+#[cfg(feature = "std")]
+use std::error::Error as StdError;
+
+/// Validates all the fields of a value,as well as the value as a whole.
+///
+/// This is usually implemented with the `#[struc(validate = "...")]` attributes
+/// of the `Structural` derive.
+///
+/// For an example,look at the [module-level documentation](./index.html).
+pub trait Validate {
+    /// Validates this value,returning all the errors.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+
+    /// Validates only the fields that `path` refers to,returning all the errors.
+    ///
+    /// This does not run the validation for the value as a whole.
+    ///
+    /// `path` can be a single field name (eg: `fp!(foo)`),
+    /// or a set of up to 8 field names (eg: `fp!(foo, bar, baz)`).
+    #[inline]
+    fn validate_fields<P>(&self, path: P) -> Result<(), ValidationErrors>
+    where
+        Self: ValidateFields<P>,
+    {
+        let mut errors = ValidationErrors::new();
+        self.validate_fields_into(path, &mut errors);
+        errors.into_result()
+    }
+}
+
+/// Validates the `FieldName` field,pushing errors into a [`ValidationErrors`].
+///
+/// The `Structural` derive implements this for every field with accessor impls,
+/// doing nothing for the fields without a `#[struc(validate = "...")]` attribute.
+///
+/// [`ValidationErrors`]: ./struct.ValidationErrors.html
+pub trait ValidateField<FieldName> {
+    /// Validates the `FieldName` field,pushing the errors into `errors`.
+    fn validate_field(&self, field_name: FieldName, errors: &mut ValidationErrors);
+}
+
+/// Validates the fields that `Path` refers to,
+/// used by [`Validate::validate_fields`].
+///
+/// This is implemented for single field names and
+/// `FieldPathSet`s of up to 8 field names.
+///
+/// [`Validate::validate_fields`]: ./trait.Validate.html#method.validate_fields
+pub trait ValidateFields<Path> {
+    /// Validates the fields that `path` refers to,pushing the errors into `errors`.
+    fn validate_fields_into(&self, path: Path, errors: &mut ValidationErrors);
+}
+
+impl<This, T> ValidateFields<TStr<T>> for This
+where
+    This: ?Sized + ValidateField<TStr<T>>,
+{
+    #[inline]
+    fn validate_fields_into(&self, path: TStr<T>, errors: &mut ValidationErrors) {
+        self.validate_field(path, errors)
+    }
+}
+
+macro_rules! impl_validate_fields {
+    ( $( ($path:ident, $p:ident) )* ) => {
+        impl<This, U, $($path,)*> ValidateFields<FieldPathSet<($($path,)*), U>> for This
+        where
+            This: ?Sized $( + ValidateField<$path> )*,
+        {
+            #[inline]
+            #[allow(unused_variables)]
+            fn validate_fields_into(
+                &self,
+                path: FieldPathSet<($($path,)*), U>,
+                errors: &mut ValidationErrors,
+            ) {
+                let ($($p,)*) = path.into_paths();
+                $( self.validate_field($p, errors); )*
+            }
+        }
+    };
+}
+
+impl_validate_fields! {}
+impl_validate_fields! { (P0, p0) }
+impl_validate_fields! { (P0, p0) (P1, p1) }
+impl_validate_fields! { (P0, p0) (P1, p1) (P2, p2) }
+impl_validate_fields! { (P0, p0) (P1, p1) (P2, p2) (P3, p3) }
+impl_validate_fields! { (P0, p0) (P1, p1) (P2, p2) (P3, p3) (P4, p4) }
+impl_validate_fields! { (P0, p0) (P1, p1) (P2, p2) (P3, p3) (P4, p4) (P5, p5) }
+impl_validate_fields! { (P0, p0) (P1, p1) (P2, p2) (P3, p3) (P4, p4) (P5, p5) (P6, p6) }
+impl_validate_fields! {
+    (P0, p0) (P1, p1) (P2, p2) (P3, p3) (P4, p4) (P5, p5) (P6, p6) (P7, p7)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A validation error,for a field or for a value as a whole.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationError {
+    /// The name of the field that failed validation,
+    /// `None` if the value as a whole failed validation.
+    pub field: Option<&'static str>,
+    /// The error message.
+    pub message: Cow<'static, str>,
+}
+
+impl ValidationError {
+    /// Constructs an error for the `field` field.
+    #[inline]
+    pub fn field<M>(field: &'static str, message: M) -> Self
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        Self {
+            field: Some(field),
+            message: message.into(),
+        }
+    }
+
+    /// Constructs an error for the value as a whole.
+    #[inline]
+    pub fn container<M>(message: M) -> Self
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        Self {
+            field: None,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.field {
+            Some(field) => write!(f, "`{}`: {}", field, self.message),
+            None => Display::fmt(&self.message, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for ValidationError {}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// All the errors returned by validating a value,in the order that they were found.
+///
+/// This is the `TryFromStructural::Error` associated type of types that derive
+/// `Structural` with both the `#[struc(from_structural)]` and
+/// `#[struc(validate = "...")]` attributes.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationError>,
+}
+
+impl ValidationErrors {
+    /// Constructs an empty `ValidationErrors`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// Pushes an error.
+    #[inline]
+    pub fn push(&mut self, error: ValidationError) {
+        self.errors.push(error);
+    }
+
+    /// Pushes an error for the `field` field if `result` is an error.
+    ///
+    /// The derived `Validate` impls pass the return value of
+    /// field validator functions to this.
+    #[inline]
+    pub fn check_field<M>(&mut self, field: &'static str, result: Result<(), M>)
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        if let Err(message) = result {
+            self.push(ValidationError::field(field, message));
+        }
+    }
+
+    /// Pushes an error for the value as a whole if `result` is an error.
+    ///
+    /// The derived `Validate` impls pass the return value of
+    /// container validator functions to this.
+    #[inline]
+    pub fn check_container<M>(&mut self, result: Result<(), M>)
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        if let Err(message) = result {
+            self.push(ValidationError::container(message));
+        }
+    }
+
+    /// Whether there are no errors.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// The amount of errors.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Gets the errors as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// Iterates over the errors.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, ValidationError> {
+        self.errors.iter()
+    }
+
+    /// Iterates over the names of the fields that failed validation,
+    /// once per error.
+    pub fn field_names<'a>(&'a self) -> impl Iterator<Item = &'static str> + 'a {
+        self.errors.iter().filter_map(|e| e.field)
+    }
+
+    /// Converts this into a `Vec` of the errors.
+    #[inline]
+    pub fn into_vec(self) -> Vec<ValidationError> {
+        self.errors
+    }
+
+    /// Returns `Ok(())` if there are no errors,otherwise returns `Err(self)`.
+    #[inline]
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = &'a ValidationError;
+    type IntoIter = slice::Iter<'a, ValidationError>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i != 0 {
+                f.write_str("\n")?;
+            }
+            Display::fmt(error, f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl StdError for ValidationErrors {}
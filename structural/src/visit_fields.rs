@@ -0,0 +1,249 @@
+/*!
+Traits for visiting all the accessible fields of a type,
+passing each one of them to a [`FieldVisitor`].
+
+The [`Structural`] derive macro,tuples,arrays,and the types created with the
+[`make_struct`] macro implement [`VisitFields`].
+
+# Example
+
+This example shows how to write a function that prints every field of a type,
+by implementing [`FieldVisitor`] for any field type that implements `Debug`.
+
+```rust
+use structural::{FieldInfo, FieldVisitor, Structural, VisitFields, make_struct};
+
+use std::fmt::{Debug, Write};
+
+struct DebugVisitor(String);
+
+impl<T: ?Sized + Debug> FieldVisitor<T> for DebugVisitor {
+    fn visit_field(&mut self, field: FieldInfo, value: &T) {
+        if let Some(variant) = field.variant {
+            write!(self.0, "{}::", variant).unwrap();
+        }
+        write!(self.0, "{}={:?} ", field.name, value).unwrap();
+    }
+}
+
+fn debug_fields<T>(this: &T) -> String
+where
+    T: VisitFields<DebugVisitor>,
+{
+    let mut visitor = DebugVisitor(String::new());
+    this.visit_fields(&mut visitor);
+    visitor.0
+}
+
+#[derive(Structural)]
+#[struc(public)]
+struct Point {
+    x: u32,
+    #[struc(rename = "why")]
+    y: u32,
+}
+
+#[derive(Structural)]
+enum Shape {
+    Circle { radius: u32 },
+    Rectangle(u32, u32),
+}
+
+assert_eq!(debug_fields(&Point { x: 3, y: 5 }), "x=3 why=5 ");
+
+assert_eq!(debug_fields(&Shape::Circle { radius: 8 }), "Circle::radius=8 ");
+assert_eq!(debug_fields(&Shape::Rectangle(13, 21)), "Rectangle::0=13 Rectangle::1=21 ");
+
+assert_eq!(debug_fields(&(false, 'a')), "0=false 1='a' ");
+
+assert_eq!(debug_fields(&[3, 5]), "0=3 1=5 ");
+
+assert_eq!(debug_fields(&make_struct!{ foo: "hello" }), "foo=\"hello\" ");
+
+```
+
+[`Structural`]: ../docs/structural_macro/index.html
+[`make_struct`]: ../macro.make_struct.html
+[`FieldVisitor`]: ./trait.FieldVisitor.html
+[`VisitFields`]: ./trait.VisitFields.html
+
+*/
+
+/// The kind of access that a type provides to one of its fields.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FieldAccess {
+    /// Only shared access to the field,
+    /// implementing `GetField`/`GetVariantField`.
+    Shared,
+    /// Shared and mutable access to the field,
+    /// implementing `GetFieldMut`/`GetVariantFieldMut`.
+    Mutable,
+    /// Shared and by-value access to the field,
+    /// implementing `IntoField`/`IntoVariantField`.
+    Value,
+    /// Shared,mutable,and by-value access to the field,
+    /// implementing `IntoFieldMut`/`IntoVariantFieldMut`.
+    MutValue,
+}
+
+impl FieldAccess {
+    /// Whether the field can be accessed mutably.
+    #[inline]
+    pub fn is_mutable(self) -> bool {
+        match self {
+            FieldAccess::Mutable | FieldAccess::MutValue => true,
+            FieldAccess::Shared | FieldAccess::Value => false,
+        }
+    }
+
+    /// Whether the field can be accessed by value.
+    #[inline]
+    pub fn is_by_value(self) -> bool {
+        match self {
+            FieldAccess::Value | FieldAccess::MutValue => true,
+            FieldAccess::Shared | FieldAccess::Mutable => false,
+        }
+    }
+}
+
+/// Information about a field,passed to [`FieldVisitor`] methods.
+///
+/// [`FieldVisitor`]: ./trait.FieldVisitor.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct FieldInfo {
+    /// The name of the variant that the field is in,
+    /// `None` for fields of structs.
+    pub variant: Option<&'static str>,
+    /// The name of the field,
+    /// after being renamed with the `#[struc(rename = "...")]` attribute.
+    ///
+    /// For tuples and arrays,this is the index of the field.
+    pub name: &'static str,
+    /// The kind of access that the type provides to the field.
+    pub access: FieldAccess,
+}
+
+/// A visitor of fields of type `T`,passed to [`VisitFields`] methods.
+///
+/// To visit fields of any type,implement this trait generically over `T`,
+/// with whatever bounds the visitor requires of the fields.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{FieldInfo, FieldVisitor, VisitFields, make_struct};
+///
+/// /// Doubles the value of every mutably accessible field.
+/// struct Doubler;
+///
+/// impl FieldVisitor<u32> for Doubler {
+///     fn visit_field(&mut self, _: FieldInfo, _: &u32) {}
+///
+///     fn visit_field_mut(&mut self, _: FieldInfo, value: &mut u32) {
+///         *value *= 2;
+///     }
+/// }
+///
+/// let mut this = make_struct!{ foo: 3_u32, bar: 5_u32 };
+/// this.visit_fields_mut(&mut Doubler);
+/// assert_eq!((this.foo, this.bar), (6, 10));
+///
+/// let mut array = [8_u32, 13];
+/// array.visit_fields_mut(&mut Doubler);
+/// assert_eq!(array, [16, 26]);
+///
+/// ```
+///
+/// [`VisitFields`]: ./trait.VisitFields.html
+pub trait FieldVisitor<T: ?Sized> {
+    /// Visits a field that is accessed by reference.
+    fn visit_field(&mut self, field: FieldInfo, value: &T);
+
+    /// Visits a field that is accessed by mutable reference.
+    ///
+    /// This is only called by [`VisitFields::visit_fields_mut`]
+    /// for fields that can be accessed mutably,
+    /// and by default delegates to `visit_field`.
+    ///
+    /// [`VisitFields::visit_fields_mut`]:
+    /// ./trait.VisitFields.html#tymethod.visit_fields_mut
+    #[inline]
+    fn visit_field_mut(&mut self, field: FieldInfo, value: &mut T) {
+        self.visit_field(field, value)
+    }
+}
+
+impl<T, V> FieldVisitor<T> for &mut V
+where
+    T: ?Sized,
+    V: ?Sized + FieldVisitor<T>,
+{
+    #[inline]
+    fn visit_field(&mut self, field: FieldInfo, value: &T) {
+        (**self).visit_field(field, value)
+    }
+
+    #[inline]
+    fn visit_field_mut(&mut self, field: FieldInfo, value: &mut T) {
+        (**self).visit_field_mut(field, value)
+    }
+}
+
+/// Visits all the accessible fields of a value,in declaration order,
+/// passing them to a [`FieldVisitor`].
+///
+/// For enums,this only visits the fields of the current variant.
+///
+/// The `V` visitor must implement [`FieldVisitor`] for the type of every
+/// accessible field.
+///
+/// # Example
+///
+/// ```rust
+/// use structural::{FieldInfo, FieldVisitor, Structural, VisitFields};
+///
+/// /// Collects the names of the mutably accessible fields.
+/// #[derive(Default)]
+/// struct MutableFields(Vec<&'static str>);
+///
+/// impl<T: ?Sized> FieldVisitor<T> for MutableFields {
+///     fn visit_field(&mut self, field: FieldInfo, _: &T) {
+///         if field.access.is_mutable() {
+///             self.0.push(field.name);
+///         }
+///     }
+/// }
+///
+/// #[derive(Structural)]
+/// struct Hello {
+///     pub foo: u32,
+///     #[struc(access = "ref")]
+///     pub bar: String,
+///     pub baz: Vec<u8>,
+///     private: (),
+/// }
+///
+/// let this = Hello{foo: 0, bar: String::new(), baz: vec![], private: ()};
+///
+/// let mut visitor = MutableFields::default();
+/// this.visit_fields(&mut visitor);
+/// assert_eq!(visitor.0, vec!["foo", "baz"]);
+///
+/// ```
+///
+/// [`FieldVisitor`]: ./trait.FieldVisitor.html
+pub trait VisitFields<V: ?Sized> {
+    /// Passes a reference to each accessible field to `visitor.visit_field`.
+    fn visit_fields(&self, visitor: &mut V);
+
+    /// Passes a mutable reference to each mutably accessible field to
+    /// `visitor.visit_field_mut`,
+    /// and a reference to each of the other fields to `visitor.visit_field`.
+    fn visit_fields_mut(&mut self, visitor: &mut V);
+}
+
+/// The names of the fields of arrays,up to the largest array that implements `VisitFields`.
+pub(crate) static ARRAY_FIELD_NAMES: [&str; 32] = [
+    "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16",
+    "17", "18", "19", "20", "21", "22", "23", "24", "25", "26", "27", "28", "29", "30", "31",
+];
@@ -1,4 +1,3 @@
-#![cfg_attr(feature = "nightly_impl_fields", feature(associated_type_bounds))]
 #![cfg_attr(feature = "nightly_specialization", feature(specialization))]
 #![allow(non_camel_case_types)]
 // The associated constants from this crate use trait bounds,
@@ -26,20 +25,38 @@
 mod submodules {
     mod accessing_fields;
     mod accessing_many_fields;
+    mod builder;
+    mod csv;
     mod delegation;
+    mod diff;
+    mod encoding;
     mod enum_derive;
+    mod fields_array;
     mod field_cloner;
+    mod field_offset;
     mod from_structural;
     mod from_structural_derive;
     mod impl_struct;
     mod into_fields;
+    mod iter_ext;
+    mod keyed_by;
     mod make_struct;
     mod multi_fields;
     mod multi_nested_fields;
     mod optional_fields;
+    mod pin_projection;
+    mod record;
+    mod remote;
+    mod soa;
+    mod str_map;
     mod std_structural_aliases;
+    mod struc_format;
     mod structural_alias;
     mod structural_derive;
     mod structural_wrapper;
     mod switch;
+    mod tracked;
+    mod uninit_builder;
+    mod validate;
+    mod visit_fields;
 }
@@ -0,0 +1,116 @@
+use structural::{builder::BuilderField, fp, make_struct, ts, Structural, StructuralExt, FP, TS};
+
+use std::fmt::Debug;
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public, builder)]
+struct Request<'a, T: Clone> {
+    url: &'a str,
+    #[struc(rename = "request body")]
+    body: T,
+    #[struc(init_with_lit = 3)]
+    retries: u32,
+    #[struc(init_with_val = "vec![\"accept\"]")]
+    headers: Vec<&'a str>,
+    #[struc(private, init_with_fn = "next_id")]
+    id: u64,
+}
+
+fn next_id() -> u64 {
+    13
+}
+
+#[test]
+fn build_named() {
+    let req = RequestBuilder::new()
+        .field(ts!(url), "http://example.com")
+        .body(vec![3u8, 5])
+        .build();
+    assert_eq!(
+        req,
+        Request {
+            url: "http://example.com",
+            body: vec![3u8, 5],
+            retries: 3,
+            headers: vec!["accept"],
+            id: 13,
+        }
+    );
+
+    let req = RequestBuilder::default()
+        .retries(8)
+        .field(ts!("request body"), "hello")
+        .headers(vec![])
+        .url("foo")
+        .build();
+    assert_eq!(
+        req.fields(fp!(url, "request body", retries)),
+        (&"foo", &"hello", &8)
+    );
+    assert_eq!(req.headers, Vec::<&str>::new());
+}
+
+#[test]
+fn set_twice() {
+    let req = RequestBuilder::new()
+        .url("first")
+        .body(0)
+        .url("second")
+        .retries(1)
+        .retries(2)
+        .build();
+    assert_eq!((req.url, req.body, req.retries), ("second", 0, 2));
+}
+
+fn set_url<'a, B>(builder: B) -> B::Output
+where
+    B: BuilderField<FP!(url), Ty = &'a str>,
+{
+    builder.set_field(ts!(url), "generic")
+}
+
+#[test]
+fn generic_builder_field() {
+    let req = set_url(RequestBuilder::new()).body(()).build();
+    assert_eq!(req.url, "generic");
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(builder)]
+pub struct Pair<T>(
+    #[struc(public)] pub T,
+    #[struc(public)] pub T,
+    #[struc(init_with_default)] T,
+)
+where
+    T: Debug + Default;
+
+#[test]
+fn build_tuple() {
+    let pair = PairBuilder::new()
+        .field(ts!(1), "world")
+        .field(ts!(0), "hello")
+        .build();
+    assert_eq!(pair, Pair("hello", "world", ""));
+
+    let _: PairBuilder<u8, structural::type_level::TList<TS!(0), structural::type_level::TNil>> =
+        PairBuilder::new().field(ts!(0), 3);
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, builder, from_structural)]
+struct Both {
+    a: u32,
+    #[struc(init_with_lit = "b")]
+    b: &'static str,
+}
+
+#[test]
+fn with_from_structural() {
+    let both = BothBuilder::new().a(5).build();
+    assert_eq!(both, Both { a: 5, b: "b" });
+    assert_eq!(
+        make_struct! { a: 5 }.into_struc::<Both>(),
+        Both { a: 5, b: "b" }
+    );
+}
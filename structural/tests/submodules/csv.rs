@@ -0,0 +1,198 @@
+use structural::{
+    csv::{self, CsvError, CsvWriter},
+    fp, make_struct, Record, Structural, StructuralExt,
+};
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public, from_structural)]
+struct Employee {
+    name: String,
+    #[struc(rename = "years employed")]
+    years: u32,
+    salary: f64,
+    remote: bool,
+    team: char,
+    manager: u64,
+    floor: i8,
+    notes: String,
+}
+
+fn employee() -> Employee {
+    Employee {
+        name: "Alice \"Al\" Smith".to_string(),
+        years: 4,
+        salary: 5000.5,
+        remote: true,
+        team: 'b',
+        manager: 7,
+        floor: -1,
+        notes: "likes\r\ncommas, and quotes".to_string(),
+    }
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+struct Pair(u32, String);
+
+#[test]
+fn header_and_row() {
+    assert_eq!(csv::header(fp!(name)), "name");
+    assert_eq!(
+        csv::header(fp!(name, "years employed", salary)),
+        "name,years employed,salary",
+    );
+
+    let this = employee();
+    assert_eq!(csv::to_csv_row(&this, fp!(manager)), "7");
+    assert_eq!(
+        csv::to_csv_row(
+            &this,
+            fp!(name, "years employed", salary, remote, team, floor)
+        ),
+        "\"Alice \"\"Al\"\" Smith\",4,5000.5,true,b,-1",
+    );
+    assert_eq!(
+        csv::to_csv_row(&this, fp!(notes, floor)),
+        "\"likes\r\ncommas, and quotes\",-1",
+    );
+
+    assert_eq!(csv::to_csv_row(&Pair(3, "".into()), fp!(1, 0)), ",3");
+}
+
+#[test]
+fn round_trip() {
+    let this = employee();
+
+    let path = fp!(
+        name,
+        "years employed",
+        salary,
+        remote,
+        team,
+        manager,
+        floor,
+        notes,
+    );
+    let row = csv::to_csv_row(&this, path);
+    assert_eq!(csv::split_row(&row).unwrap().len(), 8);
+    assert_eq!(csv::from_csv_row(&row, path), Ok(this.clone()));
+
+    let parsed: Record! { name: String, notes: String } =
+        csv::from_csv_row(&csv::to_csv_row(&this, fp!(name, notes)), fp!(name, notes)).unwrap();
+    assert_eq!(
+        parsed.into_fields(fp!(name, notes)),
+        (this.name, this.notes)
+    );
+
+    let anon = make_struct! { text: "a,b", n: 3_u8 };
+    let parsed: Record! { text: String } =
+        csv::from_csv_row(&csv::to_csv_row(&anon, fp!(text)), fp!(text)).unwrap();
+    assert_eq!(parsed.into_field(fp!(text)), "a,b");
+
+    assert_eq!(
+        csv::from_csv_row(",3\n", fp!(1, 0)),
+        Ok(Pair(3, String::new()))
+    );
+}
+
+#[test]
+fn many_fields() {
+    let this = (
+        1_u8, 'b', 3_i16, "four", 5.5_f32, false, 7_u64, 'h', 9_i8, "ten", 11_u32,
+    );
+    let path = fp!(10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    assert_eq!(csv::header(path), "10,9,8,7,6,5,4,3,2,1,0");
+
+    let row = csv::to_csv_row(&this, path);
+    assert_eq!(row, "11,ten,9,h,7,false,5.5,four,3,b,1");
+
+    let parsed: (u32, String, i8, char, u64, bool, f32, String, i16, char, u8) =
+        csv::from_csv_row(&row, fp!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10)).unwrap();
+    assert_eq!(
+        parsed,
+        (
+            11,
+            "ten".into(),
+            9,
+            'h',
+            7,
+            false,
+            5.5,
+            "four".into(),
+            3,
+            'b',
+            1
+        )
+    );
+
+    assert_eq!(
+        csv::from_csv_row::<(u32, String, i8, char, u64, bool, f32, String, i16, char, u8), _>(
+            "11,ten,9,h,7,false,5.5,four,3,b",
+            fp!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10),
+        ),
+        Err(CsvError::FieldCount {
+            expected: 11,
+            found: 10
+        }),
+    );
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        csv::from_csv_row::<Pair, _>("3,a,b", fp!(0, 1)),
+        Err(CsvError::FieldCount {
+            expected: 2,
+            found: 3
+        }),
+    );
+    assert_eq!(
+        csv::from_csv_row::<Pair, _>("\"3,a", fp!(0, 1)),
+        Err(CsvError::UnclosedQuote { column: 0 }),
+    );
+    assert_eq!(
+        csv::from_csv_row::<Pair, _>("3,\"a\" ", fp!(0, 1)),
+        Err(CsvError::TextAfterQuote { column: 1 }),
+    );
+
+    let err = csv::from_csv_row::<Pair, _>("-3,a", fp!(0, 1)).unwrap_err();
+    match &err {
+        CsvError::Parse { field, value, .. } => assert_eq!((*field, &**value), ("0", "-3")),
+        _ => panic!("unexpected error: {:?}", err),
+    }
+    assert!(err
+        .to_string()
+        .starts_with("could not parse \"-3\" for the `0` field: "));
+}
+
+#[test]
+fn split_rows() {
+    assert_eq!(csv::split_row("").unwrap(), [""]);
+    assert_eq!(csv::split_row("\n").unwrap(), [""]);
+    assert_eq!(csv::split_row(",").unwrap(), ["", ""]);
+    assert_eq!(csv::split_row("\"\",\"\"\"\"").unwrap(), ["", "\""]);
+    assert_eq!(csv::split_row("a\"b,c").unwrap(), ["a\"b", "c"]);
+    assert_eq!(csv::split_row("a,\"b\r\nc\"\r\n").unwrap(), ["a", "b\r\nc"]);
+}
+
+#[test]
+fn visited_fields() {
+    let this = make_struct! {
+        id: 3,
+        label: "a,b",
+        ratio: 0.5,
+    };
+    assert_eq!(csv::header_of(&this), "id,label,ratio");
+    assert_eq!(csv::row_of(&this), "3,\"a,b\",0.5");
+
+    assert_eq!(csv::header_of(&(3, "b")), "0,1");
+    assert_eq!(csv::row_of(&(3, "b")), "3,b");
+
+    let mut writer = CsvWriter::new();
+    assert!(writer.is_empty());
+    writer.push_field("");
+    writer.push_field("");
+    assert_eq!(writer.len(), 2);
+    assert_eq!(writer.into_string(), ",");
+}
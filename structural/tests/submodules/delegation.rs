@@ -3,13 +3,14 @@ use structural::{
     for_examples::{EnumOptFlying, EnumOptFlying_SI},
     fp, structural_alias,
     structural_aliases::Array3,
+    test_utils::OrOnDrop,
     unsafe_delegate_structural_with, GetField, GetFieldMut, IntoField, Structural, StructuralExt,
 };
 
 // For test
 use structural::declare_querying_trait;
 
-use std::{fmt::Debug, marker::PhantomData, mem};
+use std::{cell::Cell, fmt::Debug, marker::PhantomData, mem};
 
 field_path_aliases! {
     mod paths{
@@ -340,3 +341,269 @@ fn delegate_cfg() {
         });
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public)]
+struct Position {
+    x: i32,
+    y: i32,
+    #[struc(access = "ref")]
+    z: i32,
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, no_trait)]
+struct Entity<T> {
+    id: u32,
+    #[struc(delegate_fields(x, y, z))]
+    pos: Position,
+    #[struc(delegate_fields(0, 2))]
+    extra: (T, T, T),
+}
+
+#[test]
+fn delegate_fields_derive() {
+    let mut this = Entity {
+        id: 3,
+        pos: Position { x: 5, y: 8, z: 13 },
+        extra: ("a", "b", "c"),
+    };
+
+    assert_eq!(
+        this.fields(fp!(id, x, y, z, 0, 2)),
+        (&3, &5, &8, &13, &"a", &"c")
+    );
+
+    let (id, x, y, extra_0) = this.fields_mut(fp!(id, x, y, 0));
+    *id += 10;
+    *x += 100;
+    *y += 1000;
+    *extra_0 = "foo";
+
+    assert_eq!(this.id, 13);
+    assert_eq!(
+        this.pos,
+        Position {
+            x: 105,
+            y: 1008,
+            z: 13
+        }
+    );
+    assert_eq!(this.extra, ("foo", "b", "c"));
+
+    assert_eq!(this.into_field(fp!(id)), 13);
+}
+
+structural_alias! {
+    trait Coords {
+        mut x: i32,
+        mut y: i32,
+        ref z: i32,
+    }
+}
+
+fn move_right<T: Coords>(this: &mut T) {
+    *this.field_mut(fp!(x)) += 1;
+}
+
+#[test]
+fn delegate_fields_through_alias() {
+    let mut this = Entity {
+        id: 0,
+        pos: Position { x: 0, y: 0, z: 0 },
+        extra: ((), (), ()),
+    };
+    move_right(&mut this);
+    move_right(&mut this.pos);
+    assert_eq!(this.pos.x, 2);
+}
+
+struct Manual<A, B> {
+    left: A,
+    right: B,
+    other: u32,
+}
+
+unsafe_delegate_structural_with! {
+    impl[A, B,] Manual<A, B>
+    where[ A: Clone, ]
+
+    delegate_fields(fp!(0, 1) => left: A);
+    delegate_fields(fp!(y, "z", ) => right: B);
+}
+
+#[test]
+fn delegate_fields_macro() {
+    let mut this = Manual {
+        left: (3, 5),
+        right: Position { x: 8, y: 13, z: 21 },
+        other: 34,
+    };
+
+    assert_eq!(this.fields(fp!(0, 1, y, z)), (&3, &5, &13, &21));
+
+    let (a, b, y) = this.fields_mut(fp!(0, 1, y));
+    mem::swap(a, b);
+    *y = 0;
+
+    assert_eq!(this.left, (5, 3));
+    assert_eq!(this.right, Position { x: 8, y: 0, z: 21 });
+    assert_eq!(this.other, 34);
+}
+
+#[derive(Structural, Debug)]
+#[struc(public)]
+struct Halves<T> {
+    a: T,
+    b: T,
+}
+
+#[derive(Structural, Debug)]
+#[struc(public)]
+struct Delegating<T> {
+    x: T,
+    #[struc(delegate_fields(a, b))]
+    halves: Halves<T>,
+    #[struc(delegate_fields(1))]
+    pair: (T, T),
+    #[struc(private)]
+    hidden: T,
+}
+
+fn delegating(bits: &Cell<u64>) -> Delegating<OrOnDrop<'_, u8>> {
+    Delegating {
+        x: OrOnDrop::new(0, bits, 1),
+        halves: Halves {
+            a: OrOnDrop::new(1, bits, 1 << 1),
+            b: OrOnDrop::new(2, bits, 1 << 2),
+        },
+        pair: (
+            OrOnDrop::new(3, bits, 1 << 3),
+            OrOnDrop::new(4, bits, 1 << 4),
+        ),
+        hidden: OrOnDrop::new(5, bits, 1 << 5),
+    }
+}
+
+#[test]
+fn delegate_fields_by_value() {
+    let bits = Cell::new(0);
+    {
+        let (b, x, p1) = delegating(&bits).into_fields(fp!(b, x, 1));
+        assert_eq!(bits.get(), 0b101010);
+        assert_eq!(b.into_inner(), 2);
+        assert_eq!(x.into_inner(), 0);
+        assert_eq!(p1.into_inner(), 4);
+    }
+    // `OrOnDrop` panics if it's dropped twice
+    assert_eq!(bits.get(), 0b111111);
+
+    bits.set(0);
+    {
+        let a = delegating(&bits).into_field(fp!(a));
+        assert_eq!(bits.get(), 0b111101);
+        assert_eq!(a.into_inner(), 1);
+    }
+    assert_eq!(bits.get(), 0b111111);
+}
+
+fn sum_delegated<T>(this: T) -> u32
+where
+    T: Delegating_SI<u32>,
+{
+    let (x, a, b) = this.fields(fp!(x, a, b));
+    let sum = x + a + b;
+    sum + this.into_field(fp!(1))
+}
+
+#[test]
+fn delegate_fields_through_si() {
+    let this = Delegating {
+        x: 1,
+        halves: Halves { a: 10, b: 100 },
+        pair: (0, 1000),
+        hidden: 0,
+    };
+    assert_eq!(sum_delegated(this), 1111);
+}
+
+struct ManualByValue<T> {
+    halves: Halves<T>,
+    other: u32,
+}
+
+unsafe_delegate_structural_with! {
+    impl[T,] ManualByValue<T>
+    where[]
+
+    delegate_fields(fp!(a) => halves: Halves<T>, IntoField(moved_bits = 8..10));
+}
+
+unsafe impl<T> structural::field::DropFields for ManualByValue<T> {
+    fn pre_move(&mut self) {
+        self.halves.pre_move();
+    }
+
+    unsafe fn drop_fields(&mut self, moved: structural::field::MovedOutFields) {
+        self.halves.drop_fields(moved.range(8, 2));
+    }
+}
+
+#[test]
+fn delegate_fields_macro_by_value() {
+    let bits = Cell::new(0);
+    {
+        let this = ManualByValue {
+            halves: Halves {
+                a: OrOnDrop::new(1, &bits, 1),
+                b: OrOnDrop::new(2, &bits, 1 << 1),
+            },
+            other: 3,
+        };
+        assert_eq!(this.other, 3);
+        let (a,) = this.into_fields(fp!(a));
+        assert_eq!(bits.get(), 0b10);
+        assert_eq!(a.into_inner(), 1);
+    }
+    assert_eq!(bits.get(), 0b11);
+}
+
+struct TooFewBits<T> {
+    halves: Halves<T>,
+}
+
+unsafe_delegate_structural_with! {
+    impl[T,] TooFewBits<T>
+    where[]
+
+    delegate_fields(fp!(a, b) => halves: Halves<T>, IntoField(moved_bits = 0..1));
+}
+
+unsafe impl<T> structural::field::DropFields for TooFewBits<T> {
+    fn pre_move(&mut self) {
+        self.halves.pre_move();
+    }
+
+    unsafe fn drop_fields(&mut self, moved: structural::field::MovedOutFields) {
+        self.halves.drop_fields(moved.range(0, 1));
+    }
+}
+
+#[test]
+fn delegate_fields_too_few_bits() {
+    let bits = Cell::new(0);
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let this = TooFewBits {
+            halves: Halves {
+                a: OrOnDrop::new(1, &bits, 1),
+                b: OrOnDrop::new(2, &bits, 1 << 1),
+            },
+        };
+        this.into_fields(fp!(a, b))
+    }));
+    assert!(res.is_err());
+    // `OrOnDrop` panics if it's dropped twice
+    assert_eq!(bits.get(), 0b11);
+}
@@ -0,0 +1,152 @@
+use structural::{
+    diff::{apply, diff, ChangeVisitor, FieldChange, Patch, PatchOf},
+    fp, make_struct, record, Structural, StructuralExt, FP,
+};
+
+use std::fmt::Debug;
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public)]
+struct Settings {
+    volume: u8,
+    muted: bool,
+    #[struc(rename = "output device")]
+    device: String,
+    balance: i8,
+    bass: i8,
+    treble: i8,
+    language: &'static str,
+    theme: &'static str,
+}
+
+fn settings() -> Settings {
+    Settings {
+        volume: 50,
+        muted: false,
+        device: "speakers".to_string(),
+        balance: 0,
+        bass: 1,
+        treble: -1,
+        language: "en",
+        theme: "light",
+    }
+}
+
+#[derive(Default)]
+struct Names(Vec<String>);
+
+impl<T: Debug> ChangeVisitor<T> for Names {
+    fn visit_change(&mut self, name: &'static str, change: &FieldChange<T>) {
+        self.0
+            .push(format!("{}={:?}->{:?}", name, change.old, change.new));
+    }
+}
+
+#[test]
+fn diff_fields() {
+    let old = settings();
+    let mut new = settings();
+    new.volume = 70;
+    new.device = "headphones".to_string();
+    new.theme = "dark";
+
+    let patch: PatchOf<FP!(volume, muted, "output device"), Settings, Settings> =
+        diff(&old, &new, fp!(volume, muted, "output device"));
+    assert_eq!(patch.len(), 2);
+    assert!(!patch.is_empty());
+    assert_eq!(
+        patch.changes,
+        (
+            Some(FieldChange { old: 50, new: 70 }),
+            None,
+            Some(FieldChange {
+                old: "speakers".to_string(),
+                new: "headphones".to_string(),
+            }),
+        )
+    );
+    assert_eq!(
+        format!("{:?}", patch),
+        r#"{"volume": FieldChange { old: 50, new: 70 }, "output device": FieldChange { old: "speakers", new: "headphones" }}"#,
+    );
+
+    let mut names = Names::default();
+    patch.visit_changes(&mut names);
+    assert_eq!(
+        names.0,
+        vec![
+            "volume=50->70".to_string(),
+            r#"output device="speakers"->"headphones""#.to_string(),
+        ]
+    );
+
+    let all = diff(
+        &old,
+        &new,
+        fp!(
+            volume,
+            muted,
+            "output device",
+            balance,
+            bass,
+            treble,
+            language,
+            theme
+        ),
+    );
+    assert_eq!(all.len(), 3);
+
+    let single = diff(&old, &new, fp!(theme));
+    assert_eq!(
+        single.changes,
+        (Some(FieldChange {
+            old: "light",
+            new: "dark"
+        }),)
+    );
+    assert_eq!(
+        format!("{:?}", single),
+        r#"{"theme": FieldChange { old: "light", new: "dark" }}"#
+    );
+
+    let unchanged = diff(&old, &new, fp!(muted, balance));
+    assert!(unchanged.is_empty());
+    assert_eq!(format!("{:?}", unchanged), "{}");
+}
+
+#[test]
+fn apply_patch() {
+    let old = settings();
+    let mut new = settings();
+    new.muted = true;
+    new.language = "es";
+
+    let patch = diff(&old, &new, fp!(muted, language, volume));
+
+    let mut copy = old.clone();
+    patch.clone().apply(&mut copy);
+    assert_eq!(copy, new);
+
+    // Applying the patch to types other than the compared ones.
+    let mut other = make_struct! {
+        volume: 0_u8,
+        muted: false,
+        language: "fr",
+        extra: (),
+    };
+    apply(&mut other, patch.clone());
+    assert_eq!(
+        other.fields(fp!(volume, muted, language)),
+        (&0, &true, &"es")
+    );
+
+    let mut rec = record! { language: "de", muted: false, volume: 100_u8 };
+    apply(&mut rec, patch);
+    assert_eq!(rec, record! { language: "es", muted: true, volume: 100 });
+
+    // Only the changed fields are written.
+    let mut tuple = (3, 5, 8);
+    let patch = diff(&(0, 1, 2), &(0, 10, 2), fp!(0, 1, 2));
+    Patch::apply(patch, &mut tuple);
+    assert_eq!(tuple, (3, 10, 8));
+}
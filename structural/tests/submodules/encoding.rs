@@ -0,0 +1,336 @@
+use structural::{
+    encoding::{self, Decode, DecodeError, Encode, EncodeError, SliceWriter},
+    fp, impl_encoding, make_struct, Structural,
+};
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public, from_structural)]
+struct Telemetry {
+    id: u32,
+    #[struc(rename = "temp")]
+    temperature: f64,
+    armed: bool,
+    mode: char,
+    offset: i64,
+    battery: Option<u8>,
+    location: Location,
+    #[struc(init_with_default)]
+    retries: u16,
+}
+
+#[derive(Structural, Debug, Copy, Clone, PartialEq)]
+#[struc(public, from_structural)]
+struct Location {
+    lat: f32,
+    lon: f32,
+    altitude: Option<i16>,
+}
+
+impl_encoding! {
+    impl[] Location {
+        fields = fp!(lat, lon, altitude)
+    }
+}
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public, from_structural)]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+impl_encoding! {
+    impl[T] Pair<T>
+    where[ T: Encode + Decode ]
+    {
+        fields = fp!(second, first)
+    }
+}
+
+fn telemetry() -> Telemetry {
+    Telemetry {
+        id: 0x0102_0304,
+        temperature: -1.5,
+        armed: true,
+        mode: 'é',
+        offset: -2,
+        battery: Some(90),
+        location: Location {
+            lat: 1.0,
+            lon: -2.0,
+            altitude: None,
+        },
+        retries: 3,
+    }
+}
+
+#[test]
+fn encode_primitives() {
+    let this = telemetry();
+
+    assert_eq!(
+        encoding::encode_to_vec(&this, fp!(id, armed, mode, battery)),
+        vec![4, 3, 2, 1, 1, 0xE9, 0, 0, 0, 1, 90],
+    );
+    assert_eq!(
+        encoding::encode_to_vec(&this, fp!(offset)),
+        vec![0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+    );
+    assert_eq!(
+        encoding::encode_to_vec(&this, fp!(temp)),
+        (-1.5f64).to_bits().to_le_bytes().to_vec(),
+    );
+    assert_eq!(
+        encoding::encode_to_vec(&(u128::max_value(), -1i128, ()), fp!(0, 2)),
+        vec![0xFF; 16],
+    );
+}
+
+#[test]
+fn encode_nested() {
+    let this = telemetry();
+    let mut expected = Vec::new();
+    expected.extend_from_slice(&1.0f32.to_bits().to_le_bytes());
+    expected.extend_from_slice(&(-2.0f32).to_bits().to_le_bytes());
+    expected.push(0);
+
+    assert_eq!(encoding::encode_to_vec(&this, fp!(location)), expected);
+    assert_eq!(encoding::encoded_len(&this, fp!(location)), 9);
+
+    let pair = Pair {
+        first: [1u8, 2],
+        second: [3u8, 4],
+    };
+    assert_eq!(encoding::encode_to_vec(&pair, fp!(first)), vec![1, 2]);
+    assert_eq!(
+        encoding::encode_to_vec(&make_struct!(pair: pair.clone()), fp!(pair)),
+        vec![3, 4, 1, 2],
+    );
+}
+
+#[test]
+fn encoded_len() {
+    let this = telemetry();
+    let path = fp!(id, temp, armed, mode, offset, battery, location);
+
+    assert_eq!(
+        encoding::encoded_len(&this, path),
+        4 + 8 + 1 + 4 + 8 + 2 + 9
+    );
+    assert_eq!(
+        encoding::encoded_len(&this, path),
+        encoding::encode_to_vec(&this, path).len(),
+    );
+    assert_eq!(encoding::encoded_len(&[0u32; 32], fp!(0)), 4);
+    assert_eq!([Some(0u32); 32].encoded_len(), 5 * 32);
+    assert_eq!([None::<u64>, Some(0)].encoded_len(), 1 + 9);
+}
+
+#[test]
+fn roundtrip() {
+    let this = telemetry();
+    let path = fp!(id, temp, armed, mode, offset, battery, location);
+
+    let bytes = encoding::encode_to_vec(&this, path);
+    let mut reader = &bytes[..];
+    let decoded: Telemetry = encoding::decode(&mut reader, path).unwrap();
+    assert!(reader.is_empty());
+    assert_eq!(
+        decoded,
+        Telemetry {
+            retries: 0,
+            ..this.clone()
+        }
+    );
+
+    // Decoding into a different type,with the fields in the same order.
+    let bytes = encoding::encode_to_vec(&this, fp!(location, id));
+    let decoded: (Location, u32) = encoding::decode(&mut &bytes[..], fp!(0, 1)).unwrap();
+    assert_eq!(decoded, (this.location, this.id));
+
+    // A single field
+    let bytes = encoding::encode_to_vec(&this, fp!(battery));
+    let decoded: (Option<u8>,) = encoding::decode(&mut &bytes[..], fp!(0)).unwrap();
+    assert_eq!(decoded, (Some(90),));
+
+    let pair = Pair {
+        first: ['a', 'b'],
+        second: ['c', 'd'],
+    };
+    let bytes = encoding::encode_to_vec(&make_struct!(pair: pair.clone()), fp!(pair));
+    assert_eq!(<Pair<[char; 2]>>::decode(&mut &bytes[..]), Ok(pair));
+}
+
+#[test]
+fn many_fields() {
+    let this = (
+        1_u8,
+        2_u16,
+        3_u32,
+        4_u64,
+        5_i8,
+        6_i16,
+        7_i32,
+        8_i64,
+        true,
+        'j',
+        Some(11_u8),
+    );
+    let path = fp!(10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    let bytes = encoding::encode_to_vec(&this, path);
+    assert_eq!(bytes.len(), encoding::encoded_len(&this, path));
+    assert_eq!(bytes.len(), 2 + 4 + 1 + 8 + 4 + 2 + 1 + 8 + 4 + 2 + 1);
+
+    let mut reader = &bytes[..];
+    let decoded: (Option<u8>, char, bool, i64, i32, i16, i8, u64, u32, u16, u8) =
+        encoding::decode(&mut reader, fp!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10)).unwrap();
+    assert!(reader.is_empty());
+    assert_eq!(decoded, (Some(11), 'j', true, 8, 7, 6, 5, 4, 3, 2, 1));
+
+    let mut buffer = [0u8; 16];
+    assert_eq!(
+        encoding::encode_to_slice(&this, path, &mut buffer),
+        Err(EncodeError::BufferFull {
+            needed: 37,
+            remaining: 16
+        }),
+    );
+}
+
+#[test]
+fn encode_to_slice() {
+    let this = telemetry();
+
+    let mut buffer = [0u8; 6];
+    assert_eq!(
+        encoding::encode_to_slice(&this, fp!(id, armed), &mut buffer),
+        Ok(5)
+    );
+    assert_eq!(buffer, [4, 3, 2, 1, 1, 0]);
+
+    let mut writer = SliceWriter::new(&mut buffer);
+    assert!(writer.is_empty());
+    encoding::encode(&this, fp!(armed, battery), &mut writer).unwrap();
+    assert_eq!(writer.len(), 3);
+    assert_eq!(writer.remaining(), 3);
+    assert_eq!(writer.written(), &[1, 1, 90][..]);
+
+    assert_eq!(
+        encoding::encode(&this, fp!(id), &mut writer),
+        Err(EncodeError::BufferFull {
+            needed: 4,
+            remaining: 3,
+        }),
+    );
+    assert_eq!(writer.written(), &[1, 1, 90][..]);
+
+    // Nothing is written when only some of the fields fit.
+    assert_eq!(
+        encoding::encode(&this, fp!(armed, battery, id), &mut writer),
+        Err(EncodeError::BufferFull {
+            needed: 7,
+            remaining: 3,
+        }),
+    );
+    assert_eq!(writer.into_written(), &mut [1, 1, 90][..]);
+
+    assert_eq!(
+        encoding::encode_to_slice(&this, fp!(offset), &mut buffer),
+        Err(EncodeError::BufferFull {
+            needed: 8,
+            remaining: 6,
+        }),
+    );
+}
+
+#[test]
+fn decode_errors() {
+    let decode_pair = |bytes: &[u8]| -> Result<(u8, bool), DecodeError> {
+        encoding::decode(&mut &bytes[..], fp!(0, 1))
+    };
+
+    assert_eq!(decode_pair(&[0, 1]), Ok((0, true)));
+
+    // The bytes are only advanced when all the fields are decoded.
+    let mut bytes = &[7, 2, 1][..];
+    assert_eq!(
+        encoding::decode::<(u8, bool), _>(&mut bytes, fp!(0, 1)),
+        Err(DecodeError::InvalidBool(2)),
+    );
+    assert_eq!(bytes, &[7, 2, 1][..]);
+    let mut bytes = &[7, 1, 2][..];
+    assert_eq!(
+        encoding::decode::<(u8, bool), _>(&mut bytes, fp!(0, 1)),
+        Ok((7, true)),
+    );
+    assert_eq!(bytes, &[2][..]);
+
+    let pair = Pair {
+        first: Some(3u8),
+        second: Some(5u8),
+    };
+    let bytes = encoding::encode_to_vec(&make_struct!(pair: pair), fp!(pair));
+    let mut truncated = &bytes[..bytes.len() - 1];
+    assert!(<Pair<Option<u8>>>::decode(&mut truncated).is_err());
+    assert_eq!(truncated, &bytes[..bytes.len() - 1]);
+    assert_eq!(decode_pair(&[0, 2]), Err(DecodeError::InvalidBool(2)));
+    assert_eq!(
+        decode_pair(&[0]),
+        Err(DecodeError::UnexpectedEnd {
+            needed: 1,
+            remaining: 0,
+        }),
+    );
+
+    assert_eq!(
+        u32::decode(&mut &[1, 2][..]),
+        Err(DecodeError::UnexpectedEnd {
+            needed: 4,
+            remaining: 2,
+        }),
+    );
+    assert_eq!(
+        char::decode(&mut &0xD800u32.to_le_bytes()[..]),
+        Err(DecodeError::InvalidChar(0xD800)),
+    );
+    assert_eq!(
+        <Option<u8>>::decode(&mut &[2, 0][..]),
+        Err(DecodeError::InvalidOptionTag(2)),
+    );
+    assert_eq!(<Option<u8>>::decode(&mut &[0][..]), Ok(None));
+    assert_eq!(<[u16; 2]>::decode(&mut &[1, 0, 2, 0][..]), Ok([1, 2]));
+    assert_eq!(<[u16; 0]>::decode(&mut &[][..]), Ok([]));
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        EncodeError::BufferFull {
+            needed: 4,
+            remaining: 1
+        }
+        .to_string(),
+        "could not write 4 bytes into a buffer with 1 bytes remaining",
+    );
+    assert_eq!(
+        DecodeError::UnexpectedEnd {
+            needed: 2,
+            remaining: 0
+        }
+        .to_string(),
+        "could not read 2 bytes with 0 bytes remaining",
+    );
+    assert_eq!(
+        DecodeError::InvalidBool(3).to_string(),
+        "3 is not a valid bool"
+    );
+    assert_eq!(
+        DecodeError::InvalidChar(0xD800).to_string(),
+        "0xd800 is not a valid char",
+    );
+    assert_eq!(
+        DecodeError::InvalidOptionTag(9).to_string(),
+        "9 is not a valid Option tag",
+    );
+}
@@ -0,0 +1,134 @@
+use structural::{
+    field::{field_ptr, field_ptr_mut, FieldOffset},
+    fp, Structural, FP,
+};
+
+use std::mem;
+
+#[repr(C)]
+#[derive(Structural, Debug, Copy, Clone, PartialEq)]
+#[struc(public)]
+struct Inner {
+    a: u8,
+    b: u64,
+    c: u16,
+}
+
+#[repr(C)]
+#[derive(Structural, Debug, Clone, PartialEq)]
+struct Outer<T> {
+    pub first: u8,
+    hidden: u32,
+    #[struc(rename = "renamed")]
+    pub inner: Inner,
+    pub generic: T,
+}
+
+#[repr(C)]
+#[derive(Structural)]
+#[struc(public)]
+struct Tuple(u16, Inner, ());
+
+macro_rules! offset_of {
+    ($ty:ty, $path:ty) => {
+        <$ty as FieldOffset<$path>>::OFFSET
+    };
+}
+
+/// Computes the offset of a field from a reference,by subtracting addresses.
+fn real_offset<T, F>(this: &T, field: &F) -> usize {
+    field as *const F as usize - this as *const T as usize
+}
+
+#[test]
+fn single_field_offsets() {
+    let inner = Inner { a: 3, b: 5, c: 8 };
+    assert_eq!(offset_of!(Inner, FP!(a)), real_offset(&inner, &inner.a));
+    assert_eq!(offset_of!(Inner, FP!(b)), real_offset(&inner, &inner.b));
+    assert_eq!(offset_of!(Inner, FP!(c)), real_offset(&inner, &inner.c));
+
+    let outer = Outer {
+        first: 13,
+        hidden: 21,
+        inner,
+        generic: [0u16; 3],
+    };
+    type OuterArr = Outer<[u16; 3]>;
+    assert_eq!(offset_of!(OuterArr, FP!(first)), 0);
+    assert_eq!(
+        offset_of!(OuterArr, FP!(renamed)),
+        real_offset(&outer, &outer.inner)
+    );
+    assert_eq!(
+        offset_of!(OuterArr, FP!(generic)),
+        real_offset(&outer, &outer.generic)
+    );
+    assert_eq!(
+        offset_of!(Outer<()>, FP!(generic)),
+        mem::size_of::<Outer<()>>()
+    );
+
+    let tuple = Tuple(0, inner, ());
+    assert_eq!(offset_of!(Tuple, FP!(0)), 0);
+    assert_eq!(offset_of!(Tuple, FP!(1)), real_offset(&tuple, &tuple.1));
+    assert_eq!(offset_of!(Tuple, FP!(2)), real_offset(&tuple, &tuple.2));
+}
+
+#[test]
+fn nested_field_offsets() {
+    let outer = Outer {
+        first: 13,
+        hidden: 21,
+        inner: Inner { a: 3, b: 5, c: 8 },
+        generic: Inner { a: 1, b: 2, c: 3 },
+    };
+    type OuterInner = Outer<Inner>;
+    assert_eq!(
+        offset_of!(OuterInner, FP!(renamed.b)),
+        real_offset(&outer, &outer.inner.b)
+    );
+    assert_eq!(
+        offset_of!(OuterInner, FP!(generic.c)),
+        real_offset(&outer, &outer.generic.c)
+    );
+
+    let outer = Outer {
+        first: 0,
+        hidden: 0,
+        inner: outer.inner,
+        generic: outer,
+    };
+    type Nested = Outer<Outer<Inner>>;
+    assert_eq!(
+        offset_of!(Nested, FP!(generic.generic.c)),
+        real_offset(&outer, &outer.generic.generic.c)
+    );
+    assert_eq!(
+        offset_of!(Nested, FP!(generic.renamed.a)),
+        real_offset(&outer, &outer.generic.inner.a)
+    );
+}
+
+#[test]
+fn field_pointers() {
+    let mut outer = Outer {
+        first: 13,
+        hidden: 21,
+        inner: Inner { a: 3, b: 5, c: 8 },
+        generic: "hello",
+    };
+
+    unsafe {
+        let ptr: *const Outer<&str> = &outer;
+        assert_eq!(*field_ptr(ptr, fp!(first)), 13);
+        assert_eq!(*field_ptr(ptr, fp!(renamed.b)), 5);
+        assert_eq!(*field_ptr(ptr, fp!(generic)), "hello");
+
+        let ptr: *mut Outer<&str> = &mut outer;
+        *field_ptr_mut(ptr, fp!(renamed.c)) = 34;
+        *field_ptr_mut(ptr, fp!(generic)) = "world";
+    }
+    assert_eq!(outer.inner, Inner { a: 3, b: 5, c: 34 });
+    assert_eq!(outer.generic, "world");
+    assert_eq!(outer.hidden, 21);
+}
@@ -0,0 +1,93 @@
+use structural::{
+    for_examples::Struct3, fp, from_fields_array, make_struct, Structural, StructuralExt,
+};
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+struct Vec3<T> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+#[test]
+fn struct_arrays() {
+    let mut this = Vec3 { x: 3, y: 5, z: 8 };
+
+    assert_eq!(this.fields_array(fp!(x, y, z)), [&3, &5, &8]);
+    assert_eq!(this.fields_array(fp!(z)), [&8]);
+    assert_eq!(this.cloned_fields_array(fp!(y, x)), [5, 3]);
+    assert_eq!(this.fields_array_mut(fp!(z, x)), [&mut 8, &mut 3]);
+
+    for field in this.fields_array_mut(fp!(x, y, z)).iter_mut() {
+        **field *= 2;
+    }
+    assert_eq!(this, Vec3 { x: 6, y: 10, z: 16 });
+
+    assert_eq!(this.into_fields_array(fp!(y, z)), [10, 16]);
+}
+
+#[test]
+fn std_arrays() {
+    let mut tuple = (0, 1, 2, 3, 4, 5, 6, 7);
+    let path = fp!(7, 6, 5, 4, 3, 2, 1, 0);
+
+    assert_eq!(tuple.fields_array(path), [&7, &6, &5, &4, &3, &2, &1, &0]);
+    assert_eq!(
+        tuple.fields_array_mut(path),
+        [&mut 7, &mut 6, &mut 5, &mut 4, &mut 3, &mut 2, &mut 1, &mut 0]
+    );
+    assert_eq!(tuple.into_fields_array(path), [7, 6, 5, 4, 3, 2, 1, 0]);
+
+    let array = ["a".to_string(), "b".to_string(), "c".to_string()];
+    assert_eq!(array.fields_array(fp!(2, 0)), [&"c", &"a"]);
+    assert_eq!(
+        array.into_fields_array(fp!(1, 2)),
+        ["b".to_string(), "c".to_string()]
+    );
+
+    // Fields of different types in the same struct
+    let this = Struct3 {
+        foo: Some(3),
+        bar: 5,
+        baz: 8,
+    };
+    assert_eq!(this.fields_array(fp!(bar, baz)), [&5, &8]);
+}
+
+#[test]
+fn for_each() {
+    let mut this = make_struct! { x: 3, y: 5, z: 8 };
+
+    let mut list = Vec::new();
+    this.for_each_field(fp!(x, y, z), |&n| list.push(n));
+    assert_eq!(list, vec![3, 5, 8]);
+
+    this.for_each_field_mut(fp!(z, y), |n| *n += 100);
+    assert_eq!(this.cloned_fields_array(fp!(x, y, z)), [3, 105, 108]);
+
+    let mut list = Vec::new();
+    [13, 21].for_each_field(fp!(1, 0), |&n| list.push(n));
+    assert_eq!(list, vec![21, 13]);
+}
+
+#[test]
+fn from_array() {
+    let this: Vec3<u32> = from_fields_array!([x, y, z] = [3, 5, 8]);
+    assert_eq!(this, Vec3 { x: 3, y: 5, z: 8 });
+
+    let strings = ["a".to_string(), "b".to_string(), "c".to_string()];
+    let this: Vec3<String> = from_fields_array!([z, x, y,] = strings);
+    assert_eq!(
+        this,
+        Vec3 {
+            x: "b".to_string(),
+            y: "c".to_string(),
+            z: "a".to_string(),
+        }
+    );
+
+    let reversed: Vec3<String> =
+        from_fields_array!([z, y, x] = this.into_fields_array(fp!(x, y, z)));
+    assert_eq!(reversed.cloned_fields_array(fp!(x, y, z)), ["a", "c", "b"]);
+}
@@ -0,0 +1,152 @@
+use structural::{fp, Structural, StructuralIterExt, StructuralSliceExt};
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public)]
+struct Order {
+    customer: &'static str,
+    item: Item,
+    quantity: u32,
+}
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public)]
+struct Item {
+    name: &'static str,
+    cents: u64,
+}
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+enum Payment {
+    Card { last_digits: u16, cents: u64 },
+    Cash { cents: u64 },
+}
+
+fn order(customer: &'static str, name: &'static str, cents: u64, quantity: u32) -> Order {
+    Order {
+        customer,
+        item: Item { name, cents },
+        quantity,
+    }
+}
+
+fn orders() -> Vec<Order> {
+    vec![
+        order("ann", "pen", 150, 3),
+        order("bob", "ink", 900, 1),
+        order("ann", "pad", 400, 2),
+        order("cid", "pen", 150, 5),
+    ]
+}
+
+#[test]
+fn pluck() {
+    let orders = orders();
+
+    let names = orders.iter().pluck(fp!(item.name)).collect::<Vec<_>>();
+    assert_eq!(names, vec![&"pen", &"ink", &"pad", &"pen"]);
+
+    let mut iter = orders.iter().pluck_cloned(fp!(quantity));
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.collect::<Vec<u32>>(), vec![1, 2]);
+
+    let payments = vec![
+        Payment::Card {
+            last_digits: 1234,
+            cents: 500,
+        },
+        Payment::Cash { cents: 300 },
+    ];
+    assert_eq!(
+        payments
+            .iter()
+            .pluck_cloned(fp!(::Card.last_digits))
+            .collect::<Vec<_>>(),
+        vec![Some(1234), None],
+    );
+}
+
+#[test]
+fn pluck_mut() {
+    let mut orders = orders();
+
+    for cents in orders.iter_mut().rev().pluck_mut(fp!(item.cents)).skip(1) {
+        *cents += 1;
+    }
+    assert_eq!(
+        orders
+            .iter()
+            .pluck_cloned(fp!(item.cents))
+            .collect::<Vec<_>>(),
+        vec![151, 901, 401, 150],
+    );
+
+    let mut payments = vec![Payment::Cash { cents: 300 }, Payment::Cash { cents: 200 }];
+    for cents in payments.iter_mut().pluck_mut(fp!(::Cash.cents)) {
+        *cents.unwrap() *= 2;
+    }
+    assert_eq!(
+        payments,
+        vec![Payment::Cash { cents: 600 }, Payment::Cash { cents: 400 }],
+    );
+}
+
+#[test]
+fn aggregates() {
+    let orders = orders();
+
+    assert_eq!(orders.iter().sum_field::<_, _, u32>(fp!(quantity)), 11);
+    assert_eq!(orders[..0].iter().sum_field::<_, _, u32>(fp!(quantity)), 0);
+
+    assert_eq!(
+        orders.iter().max_by_field(fp!(item.cents)),
+        Some(&orders[1])
+    );
+    // Returns the last element out of the equally maximum ones.
+    assert_eq!(orders.iter().max_by_field(fp!(customer)), Some(&orders[3]));
+    assert_eq!(orders[..0].iter().max_by_field(fp!(quantity)), None);
+
+    let groups = orders.iter().group_by_field(fp!(item.name));
+    assert_eq!(
+        groups.keys().cloned().collect::<Vec<_>>(),
+        vec!["ink", "pad", "pen"]
+    );
+    assert_eq!(groups["ink"], vec![&orders[1]]);
+    assert_eq!(groups["pad"], vec![&orders[2]]);
+    assert_eq!(groups["pen"], vec![&orders[0], &orders[3]]);
+}
+
+#[test]
+fn slice_methods() {
+    let mut list = orders();
+
+    list.sort_by_fields(fp!(item.cents, customer));
+    assert_eq!(
+        list.iter()
+            .map(|x| (x.customer, x.item.name))
+            .collect::<Vec<_>>(),
+        vec![
+            ("ann", "pen"),
+            ("cid", "pen"),
+            ("ann", "pad"),
+            ("bob", "ink")
+        ],
+    );
+
+    // Checking that the sort is stable
+    list.sort_by_fields(fp!(item.name));
+    assert_eq!(
+        list.iter().pluck_cloned(fp!(customer)).collect::<Vec<_>>(),
+        vec!["bob", "ann", "ann", "cid"],
+    );
+
+    assert_eq!(list.binary_search_by_field(fp!(item.name), &"ink"), Ok(0));
+    assert_eq!(list.binary_search_by_field(fp!(item.name), &"pad"), Ok(1));
+    assert_eq!(list.binary_search_by_field(fp!(item.name), &"aaa"), Err(0));
+    assert_eq!(list.binary_search_by_field(fp!(item.name), &"zzz"), Err(4));
+
+    let mut tuples = vec![(3, 'b'), (1, 'c'), (2, 'a')];
+    tuples.sort_by_fields(fp!(1));
+    assert_eq!(tuples, vec![(2, 'a'), (3, 'b'), (1, 'c')]);
+}
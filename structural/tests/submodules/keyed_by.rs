@@ -0,0 +1,183 @@
+use structural::{fp, make_struct, KeyedBy, KeyedByRef, Structural, StructuralExt, FP};
+
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeSet, HashMap, HashSet},
+    hash::{Hash, Hasher},
+};
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public)]
+struct Event {
+    user: u32,
+    kind: &'static str,
+    day: u16,
+    hour: u8,
+    payload: String,
+}
+
+fn event(user: u32, kind: &'static str, day: u16, hour: u8, payload: &str) -> Event {
+    Event {
+        user,
+        kind,
+        day,
+        hour,
+        payload: payload.to_string(),
+    }
+}
+
+fn hash_with(f: impl FnOnce(&mut DefaultHasher)) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    f(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn hash_fields() {
+    let this = event(3, "login", 5, 8, "foo");
+    let other = event(3, "login", 6, 8, "bar");
+
+    assert_eq!(
+        hash_with(|h| this.hash_fields(fp!(user, kind, hour), h)),
+        hash_with(|h| other.hash_fields(fp!(user, kind, hour), h)),
+    );
+    assert_eq!(
+        hash_with(|h| this.hash_fields(fp!(user, kind, hour), h)),
+        hash_with(|h| (3_u32, "login", 8_u8).hash(h)),
+    );
+    assert_ne!(
+        hash_with(|h| this.hash_fields(fp!(user, day), h)),
+        hash_with(|h| other.hash_fields(fp!(user, day), h)),
+    );
+
+    let tuple = (13, 'a', "b");
+    assert_eq!(
+        hash_with(|h| tuple.hash_fields(fp!(2, 0), h)),
+        hash_with(|h| ("b", 13).hash(h)),
+    );
+
+    let anon = make_struct! { a: 3_u8, b: 5_u8 };
+    assert_eq!(
+        hash_with(|h| anon.hash_fields(fp!(b), h)),
+        hash_with(|h| 5_u8.hash(h)),
+    );
+}
+
+#[test]
+fn keyed_by_eq_ord() {
+    type Key = FP!(user, kind, day);
+
+    let events = [
+        event(3, "login", 5, 8, "foo"),
+        event(3, "login", 5, 9, "bar"),
+        event(3, "logout", 5, 8, "foo"),
+        event(2, "misc", 9, 8, "foo"),
+    ];
+    let a = KeyedBy::new(events[0].clone(), fp!(user, kind, day));
+    let b = KeyedBy::new(events[1].clone(), fp!(user, kind, day));
+    let c = KeyedBy::new(events[2].clone(), fp!(user, kind, day));
+    let d = KeyedBy::new(events[3].clone(), fp!(user, kind, day));
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert!(a < c);
+    assert!(d < a);
+    assert_eq!(a.key(), (&3, &"login", &5));
+    assert_eq!(a.payload, "foo");
+    assert_eq!(
+        a.as_keyed_ref(),
+        KeyedByRef::new(&events[1], fp!(user, kind, day))
+    );
+    assert_eq!(a.clone().into_inner(), events[0]);
+
+    let set = vec![a, b, c, d]
+        .into_iter()
+        .collect::<BTreeSet<KeyedBy<Event, Key>>>();
+    let kinds = set.iter().map(|e| e.kind).collect::<Vec<_>>();
+    assert_eq!(kinds, vec!["misc", "login", "logout"]);
+}
+
+#[test]
+fn keyed_by_dedup() {
+    let events = vec![
+        event(1, "login", 5, 8, "a"),
+        event(1, "login", 5, 9, "b"),
+        event(1, "login", 6, 8, "c"),
+        event(2, "login", 5, 8, "d"),
+        event(1, "login", 5, 8, "e"),
+    ];
+
+    let mut counts = HashMap::new();
+    for ev in &events {
+        *counts
+            .entry(KeyedByRef::new(ev, fp!(user, kind, day, hour)))
+            .or_insert(0) += 1;
+    }
+
+    let mut counts = counts
+        .into_iter()
+        .map(|(k, v)| (k.into_inner().payload.clone(), v))
+        .collect::<Vec<_>>();
+    counts.sort();
+    assert_eq!(
+        counts,
+        vec![
+            ("a".to_string(), 2),
+            ("b".to_string(), 1),
+            ("c".to_string(), 1),
+            ("d".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn keyed_by_owned_hash_set() {
+    let mut set = HashSet::new();
+    assert!(set.insert(KeyedBy::new(event(1, "login", 5, 8, "a"), fp!(user, kind))));
+    assert!(set.insert(KeyedBy::new(event(1, "logout", 5, 8, "b"), fp!(user, kind))));
+    assert!(!set.insert(KeyedBy::new(event(1, "login", 6, 9, "c"), fp!(user, kind))));
+    assert!(set.insert(KeyedBy::new(event(2, "login", 5, 8, "d"), fp!(user, kind))));
+    assert_eq!(set.len(), 3);
+
+    let key = KeyedBy::new(event(1, "login", 0, 0, ""), fp!(user, kind));
+    assert_eq!(set.get(&key).unwrap().payload, "a");
+
+    let mut payloads = set
+        .into_iter()
+        .map(|k| k.into_inner().payload)
+        .collect::<Vec<String>>();
+    payloads.sort();
+    assert_eq!(payloads, vec!["a", "b", "d"]);
+}
+
+#[derive(Structural, Debug)]
+#[struc(public)]
+struct Borrowing<'a> {
+    name: &'a str,
+    id: u32,
+}
+
+#[test]
+fn keyed_by_non_static() {
+    let names = vec!["foo".to_string(), "bar".to_string()];
+    let values = vec![
+        Borrowing {
+            name: &names[0],
+            id: 0,
+        },
+        Borrowing {
+            name: &names[1],
+            id: 1,
+        },
+        Borrowing {
+            name: &names[0],
+            id: 2,
+        },
+    ];
+
+    let set = values
+        .iter()
+        .map(|v| KeyedByRef::new(v, fp!(name)))
+        .collect::<BTreeSet<_>>();
+    let names = set.iter().map(|v| v.name).collect::<Vec<_>>();
+    assert_eq!(names, vec!["bar", "foo"]);
+}
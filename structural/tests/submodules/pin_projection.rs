@@ -0,0 +1,133 @@
+use structural::{
+    field::{
+        pin_projection::{Pinned, Unpinned},
+        PinField,
+    },
+    fp, structural_alias, Structural, StructuralExt, FP,
+};
+
+use std::{marker::PhantomPinned, pin::Pin};
+
+#[derive(Structural, Debug)]
+#[struc(public)]
+struct Task<F> {
+    #[struc(pin)]
+    future: F,
+    #[struc(pin, rename = "pinned marker")]
+    marker: PhantomPinned,
+    polls: u32,
+    #[struc(access = "ref")]
+    name: &'static str,
+    #[struc(private)]
+    cached: Vec<u32>,
+}
+
+fn task<F>(future: F) -> Task<F> {
+    Task {
+        future,
+        marker: PhantomPinned,
+        polls: 0,
+        name: "foo",
+        cached: Vec::new(),
+    }
+}
+
+#[test]
+fn pin_field_mut() {
+    let mut this = Box::pin(task(3_u8));
+
+    {
+        let future: Pin<&mut u8> = this.as_mut().pin_field_mut(fp!(future));
+        *future.get_mut() += 2;
+    }
+    let _: Pin<&mut PhantomPinned> = this.as_mut().pin_field_mut(fp!("pinned marker"));
+    let polls: &mut u32 = this.as_mut().pin_field_mut(fp!(polls));
+    *polls += 10;
+
+    assert_eq!(this.future, 5);
+    assert_eq!(this.polls, 10);
+    assert_eq!(this.name, "foo");
+    assert_eq!(this.cached, Vec::<u32>::new());
+}
+
+#[test]
+fn pin_fields_mut() {
+    let mut this = Box::pin(task("hello"));
+
+    let (polls, future, _marker): (&mut u32, Pin<&mut &str>, Pin<&mut PhantomPinned>) = this
+        .as_mut()
+        .pin_fields_mut(fp!(polls, future, "pinned marker"));
+    *polls = 3;
+    *future.get_mut() = "world";
+
+    assert_eq!(this.polls, 3);
+    assert_eq!(this.future, "world");
+}
+
+structural_alias! {
+    trait Polled {
+        mut polls: u32,
+    }
+}
+
+fn bump<T, F>(this: Pin<&mut T>) -> Pin<&mut F>
+where
+    T: Polled,
+    T: PinField<FP!(polls), Pinning = Unpinned>,
+    T: PinField<FP!(future), Ty = F, Pinning = Pinned>,
+{
+    let (polls, future) = this.pin_fields_mut(fp!(polls, future));
+    *polls += 1;
+    future
+}
+
+#[test]
+fn through_structural_aliases() {
+    let mut this = Box::pin(task(5_u64));
+    *bump(this.as_mut()).get_mut() *= 2;
+    bump(this.as_mut());
+    assert_eq!(this.polls, 2);
+    assert_eq!(this.future, 10);
+    assert_eq!(this.field_(fp!(polls)), &2);
+}
+
+fn bump_si<T>(this: Pin<&mut T>) -> Pin<&mut u64>
+where
+    T: Task_SI<u64>,
+{
+    let (polls, future) = this.pin_fields_mut(fp!(polls, future));
+    *polls += 1;
+    future
+}
+
+#[test]
+fn through_the_si_trait() {
+    let mut this = Box::pin(task(5_u64));
+    *bump_si(this.as_mut()).get_mut() *= 3;
+    bump_si(this.as_mut());
+    assert_eq!(this.polls, 2);
+    assert_eq!(this.future, 15);
+}
+
+#[derive(Structural)]
+#[struc(public)]
+struct OnlyPinsUnpin {
+    #[struc(pin)]
+    pinned: u32,
+    unpinned: PhantomPinned,
+}
+
+#[test]
+fn unpin_impl() {
+    fn assert_unpin<T: Unpin>() {}
+
+    // Only the types of pinned fields have to be `Unpin`.
+    assert_unpin::<OnlyPinsUnpin>();
+
+    let mut this = OnlyPinsUnpin {
+        pinned: 3,
+        unpinned: PhantomPinned,
+    };
+    let this = Pin::new(&mut this);
+    let _: &mut PhantomPinned = this.pin_field_mut(fp!(unpinned));
+}
@@ -0,0 +1,166 @@
+use structural::{
+    convert::FromStructural,
+    fp, make_struct, record,
+    record::{Field, Record},
+    structural_alias,
+    test_utils::OrOnDrop,
+    Record, Structural, StructuralExt, TS,
+};
+
+use std::cell::Cell;
+
+structural_alias! {
+    trait Point_SA<T> {
+        mut move x: T,
+        mut move y: T,
+    }
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[test]
+fn record_types() {
+    let _: Record<()> = record! {};
+    let _: Record! {} = Record(());
+    let _: Record<(
+        Field<TS!(a), u32>,
+        Field<TS!("b c"), char>,
+        Field<TS!(0), ()>,
+    )> = record! { a: 3, "b c": 'a', 0: () };
+
+    let a = 5_u8;
+    let this: Record! { a: u8, b: &str } = record! { a, b: "hello" };
+    assert_eq!(this, Record((Field::new(5), Field::new("hello"))));
+    assert_eq!(format!("{:?}", this), "Record((5, \"hello\"))");
+    assert_eq!(this.clone(), this);
+}
+
+#[test]
+fn accessors() {
+    fn sum<T>(this: &mut impl Point_SA<u32>) -> u32 {
+        *this.field_mut(fp!(x)) += 1;
+        let (x, y) = this.fields(fp!(x, y));
+        x + y
+    }
+
+    let mut this = record! { y: 5_u32, unrelated: "hi", x: 3_u32 };
+    assert_eq!(sum::<()>(&mut this), 9);
+
+    assert_eq!(this.field_(fp!(x)), &4);
+    assert_eq!(this.fields(fp!(y, unrelated, x)), (&5, &"hi", &4));
+    assert_eq!(this.fields_mut(fp!(x, y)), (&mut 4, &mut 5));
+    assert_eq!(this.cloned_fields(fp!(unrelated, y)), ("hi", 5));
+    assert_eq!(this.into_fields(fp!(x, unrelated)), (4, "hi"));
+
+    let this = record! { a: 0, b: 1, c: 2, d: 3, e: 4, f: 5, g: 6, h: 7, i: 8, j: 9, k: 10, l: 11 };
+    assert_eq!(this.fields(fp!(l, a, f)), (&11, &0, &5));
+    assert_eq!(this.into_field(fp!(k)), 10);
+}
+
+#[test]
+fn dropping() {
+    let bits = Cell::new(0);
+    {
+        let this = record! {
+            a: OrOnDrop::new(3, &bits, 1),
+            b: OrOnDrop::new(5, &bits, 1 << 1),
+            c: OrOnDrop::new(8, &bits, 1 << 2),
+        };
+        let (c, a) = this.into_fields(fp!(c, a));
+        assert_eq!(bits.get(), 0b010);
+        assert_eq!(c.into_inner(), 8);
+        assert_eq!(bits.get(), 0b110);
+        drop(a);
+    }
+    // `OrOnDrop` panics if it's dropped twice
+    assert_eq!(bits.get(), 0b111);
+}
+
+#[test]
+fn from_structural() {
+    let point: Point = record! { x: 3, y: 5, z: 8 }.into_struc();
+    assert_eq!(point, Point { x: 3, y: 5 });
+
+    let this: Record! { y: u32, x: u32 } = Record::from_structural(point);
+    assert_eq!(this, record! { y: 5, x: 3 });
+
+    let this: Record! { foo: &str } = make_struct! { foo: "foo", bar: 0 }.into_struc();
+    assert_eq!(this.into_field(fp!(foo)), "foo");
+
+    let this: Record! { 1: char, 0: u32 } = (3, 'a').into_struc();
+    assert_eq!(this, record! { 1: 'a', 0: 3 });
+
+    let this: Record! {
+        a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8, i: u8, j: u8, k: u8, l: u8,
+    } = record! { l: 11, k: 10, j: 9, i: 8, h: 7, g: 6, f: 5, e: 4, d: 3, c: 2, b: 1, a: 0 }
+        .into_struc();
+    assert_eq!(this.cloned_fields(fp!(a, e, l)), (0, 4, 11));
+}
+
+#[test]
+fn operations() {
+    use core_extensions::type_asserts::AssertEq;
+    use structural::{
+        record::{InsertFieldOut, ProjectOut, RemoveFieldOut, RenameFieldOut},
+        ts, FP,
+    };
+
+    type Rec = Record! { a: u32, b: &'static str };
+
+    let _: AssertEq<
+        InsertFieldOut<Rec, TS!(c), char>,
+        Record! { a: u32, b: &'static str, c: char },
+    >;
+    let _: AssertEq<RemoveFieldOut<Rec, TS!(a)>, Record! { b: &'static str }>;
+    let _: AssertEq<RemoveFieldOut<Rec, TS!(b)>, Record! { a: u32 }>;
+    let _: AssertEq<RenameFieldOut<Rec, TS!(a), TS!(z)>, Record! { z: u32, b: &'static str }>;
+    let _: AssertEq<ProjectOut<Rec, FP!(b, a)>, Record! { b: &'static str, a: u32 }>;
+    let _: AssertEq<ProjectOut<Rec, FP!(a)>, Record! { a: u32 }>;
+
+    let this: Rec = record! { a: 3, b: "5" };
+
+    let this = this.insert_field(ts!(c), '8');
+    assert_eq!(this, record! { a: 3, b: "5", c: '8' });
+
+    let this = this.rename_field(ts!(b), ts!(bar));
+    assert_eq!(this, record! { a: 3, bar: "5", c: '8' });
+
+    let this = this.remove_field(ts!(a));
+    assert_eq!(this, record! { bar: "5", c: '8' });
+
+    assert_eq!(this.project(fp!(c, bar)), record! { c: '8', bar: "5" });
+    assert_eq!(this.project(fp!(c)), record! { c: '8' });
+
+    let this = record! {}.insert_field(ts!(a), 0);
+    assert_eq!(this.remove_field(ts!(a)), Record(()));
+}
+
+#[test]
+fn operations_dropping() {
+    use structural::ts;
+
+    let bits = Cell::new(0);
+    {
+        let this = record! {
+            a: OrOnDrop::new(3, &bits, 1),
+            b: OrOnDrop::new(5, &bits, 1 << 1),
+            c: OrOnDrop::new(8, &bits, 1 << 2),
+        };
+        let this = this.rename_field(ts!(c), ts!(z));
+        assert_eq!(bits.get(), 0);
+
+        let this = this.remove_field(ts!(b));
+        assert_eq!(bits.get(), 0b010);
+
+        let this = this.project(fp!(z));
+        assert_eq!(bits.get(), 0b011);
+
+        drop(this);
+    }
+    assert_eq!(bits.get(), 0b111);
+}
@@ -0,0 +1,198 @@
+use structural::{fp, remote_structural, test_utils::OrOnDrop, Structural, StructuralExt};
+
+use std::{cell::Cell, fmt::Debug};
+
+// Pretend that this is another crate
+mod other {
+    use std::fmt::Debug;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Point<T> {
+        pub x: T,
+        pub y: T,
+        pub(crate) label: &'static str,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Pair<A, B: Debug>(pub A, pub B);
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Config<'a> {
+        pub name: &'a str,
+        pub verbose: bool,
+    }
+
+    pub struct Triple<A, B, C> {
+        pub a: A,
+        pub b: B,
+        pub c: C,
+    }
+
+    pub struct Wrapper<T>(pub T, pub u32, pub T);
+}
+
+use other::{Config, Pair, Point, Triple, Wrapper};
+
+#[derive(Structural)]
+#[struc(remote = "other::Point")]
+struct PointDef<T> {
+    pub x: T,
+    #[struc(rename = "why", access = "ref")]
+    pub y: T,
+    label: &'static str,
+}
+
+#[derive(Structural)]
+#[struc(public, remote = "other::Pair", newtype = "PairS")]
+pub struct PairDef<A, B: Debug>(A, B);
+
+#[derive(Structural)]
+#[struc(remote = "other::Config", newtype = "ConfigS")]
+#[struc(access = "mut")]
+struct ConfigDef<'a> {
+    pub name: &'a str,
+    pub verbose: bool,
+}
+
+remote_structural! {
+    struct TripleS[A, B, C](Triple<A, B, C>) {
+        a: A,
+        b: B,
+        c: C,
+    }
+}
+
+remote_structural! {
+    #[derive(Debug, Clone, PartialEq)]
+    pub(crate) struct ConfigM['a,](Config<'a>) {
+        name: &'a str,
+        verbose: bool,
+    }
+}
+
+remote_structural! {
+    struct WrapperS[T](Wrapper<T>)
+    where[ T: 'static ]
+    {
+        0: T,
+        1: u32,
+        2: T,
+    }
+}
+
+fn point_xy<T: Clone>(this: &impl PointDef_SI<T>) -> (T, T) {
+    let (x, y) = this.fields(fp!(x, why));
+    (x.clone(), y.clone())
+}
+
+#[test]
+fn remote_in_place() {
+    let mut point = Point {
+        x: 3,
+        y: 5,
+        label: "hello",
+    };
+    assert_eq!(point_xy(&point), (3, 5));
+
+    *point.field_mut(fp!(x)) = 8;
+    assert_eq!(point_xy(&point), (8, 5));
+    assert_eq!(point.label, "hello");
+
+    assert_eq!(point.into_field(fp!(x)), 8);
+}
+
+fn pair_fields<A: Clone, B: Clone + Debug>(this: impl PairS_SI<A, B>) -> (A, B) {
+    this.into_fields(fp!(0, 1))
+}
+
+#[test]
+fn remote_newtype() {
+    let mut pair = PairS(Pair(3, "foo"));
+    assert_eq!(pair.fields(fp!(0, 1)), (&3, &"foo"));
+
+    *pair.field_mut(fp!(0)) = 5;
+    assert_eq!(pair.0, Pair(5, "foo"));
+    assert_eq!(pair_fields(pair), (5, "foo"));
+
+    let pair: PairS<u8, char> = Pair(8, 'a').into();
+    let pair: Pair<u8, char> = pair.into();
+    assert_eq!(pair, Pair(8, 'a'));
+
+    let mut config = ConfigS::from(Config {
+        name: "bar",
+        verbose: false,
+    });
+    *config.field_mut(fp!(verbose)) = true;
+    assert_eq!(config.fields(fp!(name, verbose)), (&"bar", &true));
+    assert_eq!(
+        config.0,
+        Config {
+            name: "bar",
+            verbose: true
+        }
+    );
+}
+
+#[test]
+fn remote_structural_macro() {
+    let mut config = ConfigM(Config {
+        name: "baz",
+        verbose: true,
+    });
+    assert_eq!(config.fields(fp!(name, verbose)), (&"baz", &true));
+    *config.field_mut(fp!(name)) = "qux";
+    assert_eq!(config.clone().into_field(fp!(name)), "qux");
+
+    let config: Config<'_> = config.into();
+    assert_eq!(config.name, "qux");
+}
+
+#[test]
+fn remote_newtype_drop() {
+    let number = Cell::new(0u64);
+    let make_triple = || {
+        TripleS(Triple {
+            a: OrOnDrop::new("a", &number, 1),
+            b: OrOnDrop::new("b", &number, 1 << 1),
+            c: OrOnDrop::new("c", &number, 1 << 2),
+        })
+    };
+
+    {
+        number.set(0);
+        let (a, c) = make_triple().into_fields(fp!(a, c));
+        // ensuring that the non-moved out fields were dropped
+        assert_eq!(number.get(), 0b010);
+        assert_eq!((a.into_inner(), c.into_inner()), ("a", "c"));
+    }
+    {
+        number.set(0);
+        let b = make_triple().into_field(fp!(b));
+        assert_eq!(number.get(), 0b101);
+        drop(b);
+        assert_eq!(number.get(), 0b111);
+    }
+}
+
+fn config_name<'a>(mut this: impl ConfigM_SI<'a>) -> &'a str {
+    *this.field_mut(fp!(verbose)) = false;
+    this.into_field(fp!(name))
+}
+
+fn wrapper_ends<T: 'static>(this: impl WrapperS_SI<T>) -> (T, T) {
+    this.into_fields(fp!(0, 2))
+}
+
+#[test]
+fn remote_structural_si() {
+    let config = ConfigM(Config {
+        name: "baz",
+        verbose: true,
+    });
+    assert_eq!(config_name(config.clone()), "baz");
+    assert!(config.0.verbose);
+
+    let wrapper = WrapperS(Wrapper("a", 5, "c"));
+    assert_eq!(wrapper.fields(fp!(0, 1, 2)), (&"a", &5, &"c"));
+    assert_eq!(wrapper_ends(wrapper), ("a", "c"));
+}
@@ -0,0 +1,173 @@
+use structural::{
+    fp,
+    soa::{SoaMut, SoaRef, SoaVec},
+    test_utils::OrOnDrop,
+    Structural, StructuralExt,
+};
+
+use std::{cell::Cell, fmt::Debug};
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(soa)]
+pub struct Entity<'a, T: Clone>
+where
+    T: Debug,
+{
+    pub name: &'a str,
+    #[struc(rename = "position")]
+    pub pos: (T, T),
+    pub alive: bool,
+    hidden: u32,
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, soa, access = "ref move")]
+struct Pair(u32, &'static str);
+
+#[derive(Structural)]
+#[struc(soa)]
+struct Droppable<'a> {
+    pub kept: OrOnDrop<'a, u8>,
+    dropped: OrOnDrop<'a, u8>,
+}
+
+#[derive(Structural)]
+#[struc(soa)]
+struct NoPublicFields {
+    _hidden: u32,
+}
+
+fn entity(name: &str, x: i32, y: i32) -> Entity<'_, i32> {
+    Entity {
+        name,
+        pos: (x, y),
+        alive: true,
+        hidden: 0,
+    }
+}
+
+#[test]
+fn push_and_columns() {
+    let mut soa = SoaVec::with_capacity(2);
+    assert!(soa.is_empty());
+
+    soa.push(entity("foo", 3, 5));
+    soa.push(entity("bar", 8, 13));
+    soa.extend(vec![entity("baz", 21, 34)]);
+
+    assert_eq!(soa.len(), 3);
+    assert_eq!(soa.column(fp!(name)), &["foo", "bar", "baz"]);
+    assert_eq!(soa.column(fp!(position)), &[(3, 5), (8, 13), (21, 34)]);
+    assert_eq!(soa.column(fp!(alive)), &[true, true, true]);
+    assert_eq!(soa.columns().name, vec!["foo", "bar", "baz"]);
+
+    soa.column_mut(fp!(alive))[1] = false;
+    {
+        let (names, positions): (&mut [&str], &mut [(i32, i32)]) =
+            soa.columns_mut(fp!(name, position));
+        names[0] = "qux";
+        positions[2].1 = 55;
+    }
+    assert_eq!(soa.column(fp!(name)), &["qux", "bar", "baz"]);
+    assert_eq!(soa.column(fp!(position)), &[(3, 5), (8, 13), (21, 55)]);
+    assert_eq!(soa.column(fp!(alive)), &[true, false, true]);
+
+    soa.truncate(5);
+    assert_eq!(soa.len(), 3);
+    soa.truncate(1);
+    assert_eq!(soa.len(), 1);
+    assert_eq!(soa.column(fp!(name)), &["qux"]);
+    assert!(soa.get(1).is_none());
+
+    soa.clear();
+    assert!(soa.is_empty());
+    assert_eq!(soa.column(fp!(position)), &[]);
+}
+
+#[test]
+fn tuple_struct() {
+    let soa = vec![Pair(3, "foo"), Pair(5, "bar")]
+        .into_iter()
+        .collect::<SoaVec<_>>();
+
+    assert_eq!(soa.column(fp!(0)), &[3, 5]);
+    assert_eq!(soa.column(fp!(1)), &["foo", "bar"]);
+}
+
+fn pair_fields(this: &impl Pair_SI) -> (u32, &'static str) {
+    let (a, b) = this.fields(fp!(0, 1));
+    (*a, *b)
+}
+
+fn entity_name<'a, T>(this: impl Entity_SI<'a, T>) -> &'a str
+where
+    T: Clone + Debug,
+{
+    this.into_field(fp!(name))
+}
+
+#[test]
+fn views() {
+    let pairs = vec![Pair(3, "foo"), Pair(5, "bar")]
+        .into_iter()
+        .collect::<SoaVec<_>>();
+
+    let views = pairs.iter().collect::<Vec<SoaRef<'_, Pair>>>();
+    assert_eq!(views.len(), 2);
+    assert_eq!(views[1].index(), 1);
+    assert_eq!(views[0].fields(fp!(0, 1)), (&3, &"foo"));
+    assert_eq!(pair_fields(&views[0]), (3, "foo"));
+    assert_eq!(pair_fields(&pairs.get(1).unwrap()), (5, "bar"));
+    assert_eq!(
+        pairs
+            .iter()
+            .rev()
+            .map(|x| x.into_field(fp!(0)))
+            .collect::<Vec<u32>>(),
+        vec![5, 3]
+    );
+    assert!(pairs.get(2).is_none());
+
+    let mut soa = SoaVec::new();
+    soa.push(entity("foo", 3, 5));
+    soa.push(entity("bar", 8, 13));
+
+    {
+        let mut view: SoaMut<'_, Entity<'_, i32>> = soa.get_mut(1).unwrap();
+        assert_eq!(view.index(), 1);
+        *view.field_mut(fp!(name)) = "baz";
+        let (pos, alive) = view.fields_mut(fp!(position, alive));
+        pos.0 += 1;
+        *alive = false;
+        assert_eq!(view.as_ref().fields(fp!(position.0, alive)), (&9, &false));
+
+        assert_eq!(entity_name(view), "baz");
+    }
+    assert!(soa.get_mut(2).is_none());
+
+    assert_eq!(soa.column(fp!(name)), &["foo", "baz"]);
+    assert_eq!(soa.column(fp!(position)), &[(3, 5), (9, 13)]);
+    assert_eq!(soa.column(fp!(alive)), &[true, false]);
+}
+
+#[test]
+fn drops_private_fields() {
+    let bits = Cell::new(0u64);
+    let mut soa = SoaVec::new();
+    soa.push(Droppable {
+        kept: OrOnDrop::new(3, &bits, 0b01),
+        dropped: OrOnDrop::new(5, &bits, 0b10),
+    });
+    assert_eq!(bits.get(), 0b10);
+    assert_eq!(soa.get(0).unwrap().field_(fp!(kept)).bits_to_set(), 0b01);
+
+    soa.clear();
+    assert_eq!(bits.get(), 0b11);
+
+    let mut soa = SoaVec::new();
+    soa.push(NoPublicFields { _hidden: 0 });
+    soa.push(NoPublicFields { _hidden: 1 });
+    assert_eq!(soa.len(), 2);
+    soa.truncate(1);
+    assert_eq!(soa.len(), 1);
+}
@@ -2,9 +2,23 @@
 
 use structural::structural_aliases as sa;
 
-use structural::assert_implements;
+use structural::{
+    assert_implements,
+    convert::{FromStructural, TryFromStructural},
+    fp,
+    test_utils::OrOnDrop,
+    StructuralExt,
+};
 
-use std::ops;
+use std::{
+    borrow::Cow,
+    cell::{Cell, UnsafeCell},
+    cmp::Reverse,
+    collections::{hash_map::Entry, HashMap},
+    num::{NonZeroI8, NonZeroU32, NonZeroUsize, Saturating, Wrapping},
+    ops::{self, Bound, ControlFlow},
+    task::Poll,
+};
 
 #[test]
 fn std_types() {
@@ -19,4 +33,124 @@ fn std_types() {
     assert_implements!(for[T] ops::RangeFrom<T>, sa::RangeFrom_SI<T>);
     assert_implements!(for[T] ops::RangeTo<T>, sa::RangeTo_SI<T>);
     assert_implements!(for[T] ops::RangeToInclusive<T>, sa::RangeTo_SI<T>);
+
+    assert_implements!(for[T] Bound<T>, sa::Bound_ESI<T>);
+    assert_implements!(for[T] Bound<T>, sa::BoundMove_ESI<T>);
+    assert_implements!(for[B,C] ControlFlow<B,C>, sa::ControlFlow_ESI<B,C>);
+    assert_implements!(for[B,C] ControlFlow<B,C>, sa::ControlFlowMove_ESI<B,C>);
+    assert_implements!(for[T] Poll<T>, sa::Poll_ESI<T>);
+    assert_implements!(for[T] Poll<T>, sa::PollMove_ESI<T>);
+    assert_implements!(for['a] Cow<'a,str>, sa::Cow_ESI<'a,str>);
+    assert_implements!(for['a,T:Clone] Cow<'a,[T]>, sa::CowMove_ESI<'a,[T]>);
+    assert_implements!(for['a,K,V] Entry<'a,K,V>, sa::HashMapEntry_ESI<'a,K,V>);
+    assert_implements!(for['a,K,V] Entry<'a,K,V>, sa::HashMapEntryMove_ESI<'a,K,V>);
+
+    assert_implements!(for[T] Wrapping<T>, sa::Wrapping_SI<T>);
+    assert_implements!(for[T] Saturating<T>, sa::Saturating_SI<T>);
+    assert_implements!(for[T] Reverse<T>, sa::Reverse_SI<T>);
+    assert_implements!(NonZeroU32, sa::NonZero_SI<u32>);
+    assert_implements!(NonZeroI8, sa::NonZero_SI<i8>);
+    assert_implements!((u32,), sa::CellMove_SI<u32>);
+    assert_implements!(Wrapping<&str>, sa::CellMove_SI<&str>);
+}
+
+#[test]
+fn std_enums() {
+    let mut bound = Bound::Included(3);
+    assert_eq!(bound.field_(fp!(::Included.0)), Some(&3));
+    assert_eq!(bound.field_(fp!(::Excluded.0)), None);
+    *bound.field_mut(fp!(::Included.0)).unwrap() = 5;
+    assert_eq!(bound, Bound::Included(5));
+    assert!(Bound::<u8>::Unbounded.is_variant(fp!(Unbounded)));
+    assert_eq!(
+        Bound::from_structural(Bound::Excluded(8)),
+        Bound::Excluded(8)
+    );
+
+    let flow = ControlFlow::<u8, &str>::Break(13);
+    assert_eq!(flow.field_(fp!(::Break.0)), Some(&13));
+    assert_eq!(flow.into_field(fp!(::Continue.0)), None);
+    let flow = ControlFlow::<u8, &str>::Continue("foo");
+    assert_eq!(flow.into_field(fp!(::Continue.0)), Some("foo"));
+
+    let poll = Poll::Ready(vec![3]);
+    assert_eq!(poll.field_(fp!(::Ready.0)), Some(&vec![3]));
+    assert!(Poll::<()>::Pending.is_variant(fp!(Pending)));
+    assert_eq!(Poll::from_structural(poll), Poll::Ready(vec![3]));
+
+    let cow = Cow::<str>::Borrowed("hello");
+    assert_eq!(cow.field_(fp!(::Borrowed.0)), Some(&"hello"));
+    assert_eq!(cow.field_(fp!(::Owned.0)), None);
+    let cow = Cow::<str>::Owned("world".to_string());
+    assert_eq!(cow.into_field(fp!(::Owned.0)), Some("world".to_string()));
+
+    let mut map = HashMap::new();
+    map.insert(3, "foo");
+    match map.entry(3).into_field(fp!(::Occupied.0)) {
+        Some(entry) => assert_eq!(entry.get(), &"foo"),
+        None => panic!("expected an occupied entry"),
+    }
+    let entry = Entry::from_structural(map.entry(5));
+    assert!(entry.is_variant(fp!(Vacant)));
+    entry.or_insert("bar");
+    assert_eq!(map[&5], "bar");
+}
+
+#[test]
+fn std_newtypes() {
+    let mut wrapping = Wrapping(3u8);
+    *wrapping.field_mut(fp!(0)) = 5;
+    assert_eq!(wrapping.into_field(fp!(0)), 5);
+    assert_eq!(Wrapping::from_structural((8u8,)), Wrapping(8));
+
+    let saturating = Saturating(13i32);
+    assert_eq!(saturating.field_(fp!(0)), &13);
+    assert_eq!(Saturating::from_structural(Wrapping(21)), Saturating(21));
+
+    let reverse = Reverse("foo");
+    assert_eq!(reverse.field_(fp!(0)), &"foo");
+    assert_eq!(Reverse::from_structural(("bar",)), Reverse("bar"));
+
+    let nonzero = NonZeroU32::new(34).unwrap();
+    assert_eq!(nonzero.field_(fp!(0)), &34);
+    assert_eq!(
+        NonZeroUsize::try_from_structural((55usize,)).ok(),
+        NonZeroUsize::new(55)
+    );
+    assert_eq!(
+        NonZeroI8::try_from_structural((0i8,)).map_err(|e| e.from),
+        Err((0i8,))
+    );
+
+    let cell = Cell::from_structural((3u32,));
+    assert_eq!(cell.get(), 3);
+    let cell = UnsafeCell::from_structural(Wrapping("baz"));
+    assert_eq!(cell.into_inner(), "baz");
+
+    fn into_cell<T>(this: impl sa::CellMove_SI<T>) -> Cell<T> {
+        Cell::from_structural(this)
+    }
+    assert_eq!(into_cell(Reverse(5u8)).into_field(fp!(0)), 5);
+
+    let cell = Cell::new(String::from("qux"));
+    assert_eq!(cell.into_field(fp!(0)), "qux");
+    let cell = UnsafeCell::new(vec![8u8, 13]);
+    assert_eq!(cell.into_fields(fp!(0)), (vec![8u8, 13],));
+}
+
+#[test]
+fn cells_by_value_drop() {
+    let number = Cell::new(0u64);
+
+    let cell = Cell::new(OrOnDrop::new("foo", &number, 1));
+    let (inner,) = cell.into_fields(fp!(0));
+    assert_eq!(number.get(), 0);
+    assert_eq!(inner.into_inner(), "foo");
+    assert_eq!(number.get(), 1);
+
+    let cell = UnsafeCell::new(OrOnDrop::new("bar", &number, 2));
+    let inner = cell.into_field(fp!(0));
+    assert_eq!(number.get(), 1);
+    drop(inner);
+    assert_eq!(number.get(), 3);
 }
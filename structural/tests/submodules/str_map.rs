@@ -0,0 +1,278 @@
+use structural::{
+    fp,
+    str_map::{self, StrMap, StrMapError},
+    validate::ValidationError,
+    Record, Structural, StructuralExt,
+};
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    str::FromStr,
+};
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_str_map)]
+struct Server {
+    host: String,
+    #[struc(rename = "listen-port")]
+    port: u16,
+    #[struc(init_with_val = "vec![]")]
+    #[struc(not_public)]
+    connections: Vec<u32>,
+    #[struc(init_with_lit = 1.5)]
+    timeout: f64,
+    #[struc(str_map_nested)]
+    limits: Limits<u32>,
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(from_str_map, validate = "check_limits", bound = "T: Default")]
+struct Limits<T>
+where
+    T: PartialOrd + Display,
+{
+    #[struc(init_with_default)]
+    pub min: T,
+    pub max: T,
+}
+
+fn check_limits<T: PartialOrd + Display>(this: &Limits<T>) -> Result<(), String> {
+    if this.min <= this.max {
+        Ok(())
+    } else {
+        Err(format!("{} is greater than {}", this.min, this.max))
+    }
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_str_map)]
+struct Wrapper<T: FromStr>(T, #[struc(str_map_nested)] Limits<u8>)
+where
+    T::Err: Display;
+
+fn server_map() -> HashMap<String, String> {
+    vec![
+        ("host", "localhost"),
+        ("listen-port", "8080"),
+        ("limits__max", "100"),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect()
+}
+
+#[test]
+fn from_hash_map() {
+    let mut map = server_map();
+    assert_eq!(
+        str_map::from_str_map(&map),
+        Ok(Server {
+            host: "localhost".to_string(),
+            port: 8080,
+            connections: vec![],
+            timeout: 1.5,
+            limits: Limits { min: 0, max: 100 },
+        }),
+    );
+
+    map.insert("timeout".to_string(), "0.25".to_string());
+    map.insert("limits__min".to_string(), "10".to_string());
+    // Private fields aren't loaded from the map
+    map.insert("connections".to_string(), "nope".to_string());
+    let server = str_map::from_str_map::<Server, _>(&map).unwrap();
+    assert_eq!(server.timeout, 0.25);
+    assert_eq!(server.limits, Limits { min: 10, max: 100 });
+}
+
+#[test]
+fn prefixed() {
+    let map = vec![("app.0", "hello"), ("app.1__max", "3")]
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+
+    assert_eq!(
+        str_map::from_str_map_prefixed::<Wrapper<String>, _>(&map, "app."),
+        Ok(Wrapper("hello".to_string(), Limits { min: 0, max: 3 })),
+    );
+    assert_eq!(
+        str_map::from_str_map_prefixed::<Wrapper<u32>, _>(&map, "app."),
+        Err(StrMapError::Parse {
+            key: "app.0".to_string(),
+            value: "hello".to_string(),
+            message: "invalid digit found in string".to_string(),
+        }),
+    );
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_str_map)]
+struct Client {
+    name: String,
+    #[struc(
+        str_map_nested,
+        init_with_val = "Proxy{ host: \"none\".into(), port: 0 }"
+    )]
+    proxy: Proxy,
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_str_map)]
+struct Proxy {
+    host: String,
+    port: u16,
+}
+
+#[test]
+fn nested_with_init() {
+    let mut map = BTreeMap::new();
+    map.insert("name", "client");
+    map.insert("proxy__host", "localhost");
+
+    let default_proxy = Proxy {
+        host: "none".into(),
+        port: 0,
+    };
+    assert_eq!(
+        str_map::from_str_map(&map),
+        Ok(Client {
+            name: "client".into(),
+            proxy: default_proxy,
+        }),
+    );
+
+    map.insert("proxy__port", "8080");
+    assert_eq!(
+        str_map::from_str_map(&map),
+        Ok(Client {
+            name: "client".into(),
+            proxy: Proxy {
+                host: "localhost".into(),
+                port: 8080,
+            },
+        }),
+    );
+
+    map.insert("proxy__port", "high");
+    assert_eq!(
+        str_map::from_str_map::<Client, _>(&map).unwrap_err().key(),
+        Some("proxy__port"),
+    );
+}
+
+#[test]
+fn into_record() {
+    let map = server_map();
+
+    let record: Record! { host: String, "listen-port": u16 } = str_map::from_str_map(&map).unwrap();
+    assert_eq!(
+        record.into_fields(fp!(host, "listen-port")),
+        ("localhost".to_string(), 8080),
+    );
+
+    assert_eq!(
+        str_map::from_str_map::<Record! { timeout: f64 }, _>(&map).unwrap_err(),
+        StrMapError::Missing {
+            key: "timeout".to_string()
+        },
+    );
+}
+
+#[test]
+fn errors() {
+    let mut map = server_map();
+    map.remove("listen-port");
+    let err = str_map::from_str_map::<Server, _>(&map).unwrap_err();
+    assert_eq!(
+        err,
+        StrMapError::Missing {
+            key: "listen-port".to_string()
+        }
+    );
+    assert_eq!(err.key(), Some("listen-port"));
+    assert_eq!(err.to_string(), "missing the `listen-port` key");
+
+    let mut map = server_map();
+    map.insert("limits__max".to_string(), "-1".to_string());
+    let err = str_map::from_str_map::<Server, _>(&map).unwrap_err();
+    assert_eq!(err.key(), Some("limits__max"));
+    assert!(err
+        .to_string()
+        .starts_with("could not parse \"-1\" for the `limits__max` key: "));
+
+    let mut map = server_map();
+    map.insert("limits__min".to_string(), "200".to_string());
+    let err = str_map::from_str_map::<Server, _>(&map).unwrap_err();
+    match &err {
+        StrMapError::Invalid(errors) => assert_eq!(
+            errors.as_slice(),
+            &[ValidationError::container("200 is greater than 100")][..],
+        ),
+        _ => panic!("unexpected error: {:?}", err),
+    }
+    assert_eq!(err.key(), None);
+}
+
+#[test]
+fn validated_after_init() {
+    let mut map = BTreeMap::new();
+    map.insert("min", "-10");
+    map.insert("max", "-5");
+    assert_eq!(
+        str_map::from_str_map(&map),
+        Ok(Limits { min: -10, max: -5 }),
+    );
+
+    map.remove("min");
+    assert_eq!(
+        str_map::from_str_map::<Limits<i32>, _>(&map)
+            .unwrap_err()
+            .to_string(),
+        "0 is greater than -5",
+    );
+}
+
+/// A map that returns owned strings.
+struct Uppercased(BTreeMap<&'static str, &'static str>);
+
+impl StrMap for Uppercased {
+    fn get_str(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.0.get(key).map(|v| Cow::Owned(v.to_uppercase()))
+    }
+}
+
+#[test]
+fn custom_map() {
+    let map = Uppercased(vec![("0", "abc"), ("1__max", "3")].into_iter().collect());
+    assert_eq!(
+        str_map::from_str_map(&&map),
+        Ok(Wrapper("ABC".to_string(), Limits { min: 0, max: 3 })),
+    );
+}
+
+#[test]
+fn from_env() {
+    std::env::set_var("STRUC_TEST_STR_MAP_HOST", "example.com");
+    std::env::set_var("STRUC_TEST_STR_MAP_LISTEN-PORT", "443");
+    std::env::set_var("STRUC_TEST_STR_MAP_LIMITS__MIN", "1");
+    std::env::set_var("STRUC_TEST_STR_MAP_LIMITS__MAX", "2");
+
+    assert_eq!(
+        str_map::from_env("struc_test_str_map_"),
+        Ok(Server {
+            host: "example.com".to_string(),
+            port: 443,
+            connections: vec![],
+            timeout: 1.5,
+            limits: Limits { min: 1, max: 2 },
+        }),
+    );
+
+    assert_eq!(
+        str_map::from_env::<Server>("struc_test_str_map_missing_"),
+        Err(StrMapError::Missing {
+            key: "struc_test_str_map_missing_host".to_string()
+        }),
+    );
+}
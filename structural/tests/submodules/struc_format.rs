@@ -0,0 +1,129 @@
+use structural::{
+    make_struct, struc_format, struc_write, structural_alias, Structural, StructuralExt,
+};
+
+use std::{cell::Cell, fmt::Write as _, io::Write as _};
+
+structural_alias! {
+    trait Named {
+        name: String,
+        pos: (u32, u32),
+    }
+}
+
+#[derive(Structural, Clone)]
+#[struc(public)]
+struct Entity {
+    name: String,
+    pos: (u32, u32),
+    parent: Option<Box<Entity>>,
+}
+
+#[derive(Structural)]
+enum Shape {
+    Circle { radius: u32 },
+    Square(u32),
+}
+
+fn describe(this: &dyn Named) -> String {
+    struc_format!("{name} at ({pos.0}, {pos.1})", this)
+}
+
+#[test]
+fn basic_formatting() {
+    let entity = Entity {
+        name: "Bob".into(),
+        pos: (3, 5),
+        parent: None,
+    };
+
+    assert_eq!(describe(&entity), "Bob at (3, 5)");
+    assert_eq!(
+        describe(&make_struct! { name: "Anon".to_string(), pos: (8, 13) }),
+        "Anon at (8, 13)"
+    );
+
+    assert_eq!(struc_format!("", entity), "");
+    assert_eq!(struc_format!("{{name}} }}{{", entity), "{name} }{");
+    assert_eq!(
+        struc_format!("{name:?}|{name:>5}|{pos.0:03}|{pos.1:#x}|{pos:?}", entity),
+        r#""Bob"|  Bob|003|0x5|(3, 5)"#
+    );
+    assert_eq!(struc_format!("{0}-{1}", (false, 'c')), "false-c");
+}
+
+#[test]
+fn optional_fields() {
+    let mut entity = Entity {
+        name: "Bob".into(),
+        pos: (3, 5),
+        parent: None,
+    };
+    assert_eq!(
+        struc_format!("[{parent?.name}][{parent?.name|orphan}]", entity),
+        "[][orphan]"
+    );
+    assert_eq!(
+        struc_format!("[{parent?.pos.0|none:>6}][{parent?.name|none:?}]", entity),
+        "[  none][none]"
+    );
+
+    entity.parent = Some(Box::new(Entity {
+        name: "Alice".into(),
+        pos: (8, 13),
+        parent: None,
+    }));
+    assert_eq!(
+        struc_format!("[{parent?.name}][{parent?.name|orphan}]", entity),
+        "[Alice][Alice]"
+    );
+    assert_eq!(
+        struc_format!("[{parent?.pos.0|none:>6}][{parent?.name|none:?}]", entity),
+        r#"[     8]["Alice"]"#
+    );
+
+    let circle = Shape::Circle { radius: 21 };
+    let square = Shape::Square(34);
+    assert_eq!(
+        struc_format!("{::Circle.radius|-}/{::Square.0|-}", circle),
+        "21/-"
+    );
+    assert_eq!(
+        struc_format!("{::Circle.radius|-}/{::Square.0|-}", square),
+        "-/34"
+    );
+}
+
+#[test]
+fn evaluated_once() {
+    let count = Cell::new(0);
+    let make = || {
+        count.set(count.get() + 1);
+        (3, 5, 8)
+    };
+    assert_eq!(struc_format!("{0}{1}{2}", make()), "358");
+    assert_eq!(count.get(), 1);
+
+    let entity = Entity {
+        name: "Bob".into(),
+        pos: (3, 5),
+        parent: None,
+    };
+    // The value is only borrowed
+    assert_eq!(struc_format!("{name}", entity), "Bob");
+    assert_eq!(entity.into_field(structural::fp!(name)), "Bob");
+}
+
+#[test]
+fn writing() {
+    let point = make_struct! { x: 3, y: 5 };
+
+    let mut string = String::new();
+    struc_write!(string, "({x}, {y})", point).unwrap();
+    struc_write!(&mut string, " {x:?}", &point).unwrap();
+    assert_eq!(string, "(3, 5) 3");
+
+    let mut bytes = Vec::<u8>::new();
+    struc_write!(bytes, "({y}, {x})", point).unwrap();
+    assert_eq!(bytes, b"(5, 3)");
+}
@@ -588,3 +588,70 @@ mod with_generic_names {
         )
     }
 }
+
+/////////////////////////////////////////////
+
+mod impl_trait_fields {
+    use super::*;
+
+    use structural::make_struct;
+
+    structural_alias! {
+        trait Shape<T>{
+            ref area:impl Into<f64>+Copy,
+            mut move dims:impl Dims<T>,
+        }
+
+        trait Dims<T>{
+            width:T,
+            height:T,
+        }
+
+        trait Round{
+            ref Circle{
+                radius:impl Into<f64>+Copy,
+            },
+        }
+    }
+
+    #[derive(Structural, Copy, Clone)]
+    #[struc(no_trait)]
+    enum Shapes {
+        Circle { radius: u32 },
+        Square { side: u32 },
+    }
+
+    fn area<S, T>(this: &S) -> f64
+    where
+        S: Shape<T>,
+    {
+        (*this.field_(fp!(area))).into()
+    }
+
+    fn width<S, T>(this: S) -> T
+    where
+        S: Shape<T>,
+    {
+        this.into_field(fp!(dims.width))
+    }
+
+    fn radius<S>(this: &S) -> Option<f64>
+    where
+        S: Round,
+    {
+        this.field_(fp!(::Circle.radius)).map(|x| (*x).into())
+    }
+
+    #[test]
+    fn impl_trait_fields() {
+        let shape = make_struct! {
+            area: 10_u32,
+            dims: make_struct!{ width: 3_u8, height: 5_u8 },
+        };
+        assert_eq!(area(&shape), 10.0);
+        assert_eq!(width(shape), 3_u8);
+
+        assert_eq!(radius(&Shapes::Circle { radius: 7 }), Some(7.0));
+        assert_eq!(radius(&Shapes::Square { side: 7 }), None);
+    }
+}
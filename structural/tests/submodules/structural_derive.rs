@@ -564,3 +564,160 @@ mod enum_with_constraints {
         where[ T:'a,U:'a, ]
     }
 }
+
+mod assoc_types {
+    use structural::{
+        fp, make_struct, FieldType, IntoVariantFieldMut, Structural, StructuralExt, FP, TS,
+    };
+
+    use std::fmt::Debug;
+
+    #[derive(Structural, Copy, Clone)]
+    #[struc(public, assoc_types)]
+    pub struct Point3D<T> {
+        x: T,
+        y: T,
+        z: T,
+    }
+
+    #[derive(Structural, Clone)]
+    #[struc(assoc_types, bound = "U: Debug + 'a")]
+    pub struct Bounded<'a, T: Copy, U>
+    where
+        U: Clone,
+    {
+        pub list: Vec<T>,
+        pub first: T,
+        pub ref_: &'a U,
+        pub other: U,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Structural, Copy, Clone)]
+    #[struc(assoc_types)]
+    pub enum Either<L, R> {
+        Left(L),
+        Right(R),
+    }
+
+    fn sum<P>(this: &P) -> P::T
+    where
+        P: Point3D_SI,
+        P::T: Copy + std::ops::Add<Output = P::T>,
+    {
+        let (x, y, z) = this.fields(fp!(x, y, z));
+        *x + *y + *z
+    }
+
+    fn first_and_last<'a, B>(this: &B) -> (B::T, Option<B::T>, String)
+    where
+        B: Bounded_SI<'a>,
+    {
+        (
+            *this.field_(fp!(first)),
+            this.field_(fp!(list)).last().cloned(),
+            format!("{:?}", this.field_(fp!(ref_))),
+        )
+    }
+
+    #[derive(Structural)]
+    #[struc(public, assoc_types)]
+    pub struct ImplField<T: Copy> {
+        a: T,
+        #[struc(impl = "Into<T> + Copy")]
+        b: u8,
+    }
+
+    fn impl_field<F: ImplField_SI>(this: &F) -> (F::T, F::T) {
+        (*this.field_(fp!(a)), (*this.field_(fp!(b))).into())
+    }
+
+    #[derive(Structural)]
+    #[struc(public, assoc_types)]
+    pub struct Projected<I: Iterator> {
+        iter: I,
+        last: Option<I::Item>,
+    }
+
+    // `I::Item` is replaced with `<P::I as Iterator>::Item`
+    fn next_and_last<P>(this: &mut P) -> String
+    where
+        P: Projected_SI,
+        <P::I as Iterator>::Item: Debug,
+    {
+        let next = this.field_mut(fp!(iter)).next();
+        format!("{:?} {:?}", next, this.field_(fp!(last)))
+    }
+
+    // `V` isn't the type of any field,so it stays a type parameter of the trait.
+    #[derive(Structural)]
+    #[struc(public, assoc_types)]
+    pub struct Mixed<K, V> {
+        key: K,
+        values: Vec<V>,
+    }
+
+    fn key_and_len<M, V>(this: &M) -> (&M::K, usize)
+    where
+        M: Mixed_SI<V>,
+    {
+        (this.field_(fp!(key)), this.field_(fp!(values)).len())
+    }
+
+    fn left<E: Either_SI>(this: &E) -> Option<&E::L> {
+        this.field_(fp!(::Left.0))
+    }
+
+    fn _assoc_types_are_field_types<P: Point3D_SI>()
+    where
+        P: FieldType<FP!(x), Ty = P::T>,
+    {
+    }
+
+    fn _variant_alias<This, V>()
+    where
+        This: Point3D_VSI<V> + IntoVariantFieldMut<V, TS!(x), Ty = This::T>,
+    {
+    }
+
+    #[test]
+    fn assoc_types() {
+        assert_eq!(sum(&Point3D { x: 3, y: 5, z: 8 }), 16);
+        assert_eq!(sum(&make_struct! { x: 1.5, y: 2.0, z: 4.0 }), 7.5);
+
+        let hello = "hello".to_string();
+        let bounded = Bounded {
+            list: vec![3, 5, 8],
+            first: 13,
+            ref_: &hello,
+            other: String::new(),
+        };
+        assert_eq!(
+            first_and_last(&bounded),
+            (13, Some(8), "\"hello\"".to_string())
+        );
+
+        assert_eq!(impl_field(&ImplField { a: 3_u32, b: 5 }), (3, 5));
+
+        let mut projected = Projected {
+            iter: vec![3, 5].into_iter(),
+            last: Some(8),
+        };
+        assert_eq!(next_and_last(&mut projected), "Some(3) Some(8)");
+
+        let mixed = Mixed {
+            key: "k",
+            values: vec![0u8; 3],
+        };
+        assert_eq!(key_and_len::<_, u8>(&mixed), (&"k", 3));
+
+        assert_eq!(left(&Either::<u8, ()>::Left(3)), Some(&3));
+        assert_eq!(left(&Either::<u8, ()>::Right(())), None);
+    }
+
+    fn _enum_bounds<E>()
+    where
+        E: Either_SI + IntoVariantFieldMut<TS!(Left), TS!(0), Ty = E::L>,
+    {
+    }
+}
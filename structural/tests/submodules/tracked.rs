@@ -0,0 +1,207 @@
+use structural::{
+    field::FieldIndex, fp, make_struct, record, test_utils::OrOnDrop, Structural, StructuralExt,
+    Tracked, FP,
+};
+
+use std::cell::Cell;
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public)]
+struct Row {
+    id: u32,
+    #[struc(rename = "full name")]
+    name: String,
+    address: Address,
+    #[struc(private)]
+    cached: u64,
+}
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public)]
+struct Address {
+    street: String,
+    number: u32,
+}
+
+fn row() -> Row {
+    Row {
+        id: 3,
+        name: "foo".to_string(),
+        address: Address {
+            street: "bar".to_string(),
+            number: 5,
+        },
+        cached: 0,
+    }
+}
+
+#[test]
+fn field_indices() {
+    assert_eq!(<Row as FieldIndex<FP!(id)>>::INDEX, 0);
+    assert_eq!(<Row as FieldIndex<FP!("full name")>>::INDEX, 1);
+    assert_eq!(<Row as FieldIndex<FP!(address)>>::INDEX, 2);
+
+    assert_eq!(<(u8, u8) as FieldIndex<FP!(1)>>::INDEX, 1);
+    assert_eq!(<[u8; 32] as FieldIndex<FP!(31)>>::INDEX, 31);
+
+    fn index_of<T, N>(_: &T, _: N) -> u8
+    where
+        T: FieldIndex<N>,
+    {
+        T::INDEX
+    }
+    let rec = record! { a: 0, b: 1, c: 2 };
+    assert_eq!(index_of(&rec, fp!(c)), 2);
+
+    let anon = make_struct! { x: 0, y: 1 };
+    assert_eq!(index_of(&anon, fp!(y)), 1);
+
+    let shape = Shape::Circle { radius: 0 };
+    assert_eq!(index_of(&shape, fp!(::Circle.radius)), 0);
+    assert_eq!(index_of(&shape, fp!(::Rect.width)), 0);
+    assert_eq!(index_of(&shape, fp!(::Rect.height)), 1);
+}
+
+#[test]
+fn tracking_struct() {
+    let mut this = Tracked::new(row());
+    assert!(this.dirty_fields().is_empty());
+
+    // Shared access doesn't make fields dirty
+    assert_eq!(this.field_(fp!(id)), &3);
+    assert_eq!(this.fields(fp!(id, "full name")), (&3, &"foo".to_string()));
+    assert!(this.dirty_fields().is_empty());
+
+    this.field_mut(fp!("full name")).push_str("oo");
+    assert!(this.is_dirty(fp!("full name")));
+    assert!(!this.is_dirty(fp!(id)));
+    assert!(!this.is_dirty(fp!(address)));
+
+    // Accessing a nested field makes the top-level field dirty
+    *this.field_mut(fp!(address.number)) += 1;
+    assert!(this.is_dirty(fp!(address)));
+    assert!(!this.is_dirty(fp!(id)));
+    assert_eq!(this.dirty_fields().len(), 2);
+
+    this.clear();
+    assert!(this.dirty_fields().is_empty());
+    assert_eq!(this.dirty_fields().len(), 0);
+
+    let (id, street) = this.fields_mut(fp!(id, address.street));
+    *id += 10;
+    street.push('!');
+    assert!(this.is_dirty(fp!(id)));
+    assert!(this.is_dirty(fp!(address)));
+    assert_eq!(this.dirty_fields().len(), 2);
+
+    let inner = this.into_inner();
+    assert_eq!(inner.id, 13);
+    assert_eq!(inner.name, "foooo");
+    assert_eq!(inner.address.street, "bar!");
+    assert_eq!(inner.address.number, 6);
+}
+
+#[test]
+fn tracking_other_types() {
+    let mut tuple = Tracked::new((3, 5, 8));
+    *tuple.field_mut(fp!(2)) += 1;
+    assert_eq!(*tuple, (3, 5, 9));
+    assert!(tuple.is_dirty(fp!(2)));
+    assert!(!tuple.is_dirty(fp!(0)));
+
+    let mut array = Tracked::new([0; 8]);
+    for x in array.fields_array_mut(fp!(1, 7)).iter_mut() {
+        **x += 1;
+    }
+    assert!(array.is_dirty(fp!(1)));
+    assert!(array.is_dirty(fp!(7)));
+    assert!(!array.is_dirty(fp!(0)));
+    assert_eq!(array.dirty_fields().len(), 2);
+
+    let mut rec = Tracked::new(record! { a: 0, b: "hello" });
+    *rec.field_mut(fp!(b)) = "world";
+    assert!(rec.is_dirty(fp!(b)));
+    assert!(!rec.is_dirty(fp!(a)));
+    assert_eq!(rec.into_inner(), record! { a: 0, b: "world" });
+}
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+enum Shape {
+    Circle { radius: u32 },
+    Rect { width: u32, height: u32 },
+}
+
+#[test]
+fn tracking_enum() {
+    let mut this = Tracked::new(Shape::Rect {
+        width: 3,
+        height: 5,
+    });
+    assert!(this.is_variant(fp!(Rect)));
+    assert_eq!(this.field_(fp!(::Rect.width)), Some(&3));
+    assert!(this.dirty_fields().is_empty());
+
+    // Accessing a field of another variant doesn't mark it as dirty
+    assert_eq!(this.field_mut(fp!(::Circle.radius)), None);
+    assert!(this.dirty_fields().is_empty());
+
+    if let Some(width) = this.field_mut(fp!(::Rect.width)) {
+        *width += 1;
+    }
+    // `::Circle.radius` has the same index as `::Rect.width`
+    assert!(this.is_dirty(fp!(::Rect.width)));
+    assert!(!this.is_dirty(fp!(::Circle.radius)));
+    assert!(!this.is_dirty(fp!(::Rect.height)));
+
+    if let Some(height) = this.field_mut(fp!(::Rect.height)) {
+        *height += 1;
+    }
+    assert!(this.is_dirty(fp!(::Rect.height)));
+    assert_eq!(this.dirty_fields().len(), 2);
+
+    assert_eq!(this.clone().into_field(fp!(::Rect.height)), Some(6));
+    assert_eq!(
+        this.into_inner(),
+        Shape::Rect {
+            width: 4,
+            height: 6
+        }
+    );
+}
+
+#[test]
+fn tracking_std_enum() {
+    let mut this = Tracked::new(Some((3, 5)));
+    if let Some(x) = this.field_mut(fp!(::Some.0)) {
+        x.1 += 1;
+    }
+    assert!(this.is_dirty(fp!(::Some.0)));
+    assert_eq!(*this, Some((3, 6)));
+
+    let mut this = Tracked::new(Err::<u32, u32>(3));
+    assert_eq!(this.field_mut(fp!(::Ok.0)), None);
+    assert!(!this.is_dirty(fp!(::Ok.0)));
+    *this.field_mut(fp!(::Err.0)).unwrap() += 1;
+    assert!(this.is_dirty(fp!(::Err.0)));
+    assert!(!this.is_dirty(fp!(::Ok.0)));
+    assert_eq!(*this, Err(4));
+}
+
+#[test]
+fn into_fields() {
+    let bits = Cell::new(0);
+    {
+        let this = Tracked::new((
+            OrOnDrop::new(3, &bits, 1),
+            OrOnDrop::new(5, &bits, 1 << 1),
+            OrOnDrop::new(8, &bits, 1 << 2),
+        ));
+        let (c, a) = this.into_fields(fp!(2, 0));
+        assert_eq!(bits.get(), 0b010);
+        assert_eq!(c.into_inner(), 8);
+        assert_eq!(bits.get(), 0b110);
+        drop(a);
+    }
+    // `OrOnDrop` panics if it's dropped twice
+    assert_eq!(bits.get(), 0b111);
+}
@@ -0,0 +1,117 @@
+use structural::{
+    field::AllFields,
+    fp,
+    test_utils::OrOnDrop,
+    type_level::{TList, TNil},
+    Structural, StructuralExt, UninitBuilder, FP,
+};
+
+use std::{cell::Cell, mem::MaybeUninit};
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public)]
+struct Config<T> {
+    name: String,
+    #[struc(rename = "retry count")]
+    retries: u32,
+    extra: T,
+}
+
+#[test]
+fn init_all_fields() {
+    let mut slot = MaybeUninit::<Config<Vec<u8>>>::uninit();
+    let config = UninitBuilder::new(&mut slot)
+        .init(fp!(extra), vec![3, 5])
+        .init(fp!("retry count"), 8)
+        .init(fp!(name), "foo".to_string())
+        .finish();
+    config.extra.push(8);
+
+    assert_eq!(
+        unsafe { slot.assume_init() },
+        Config {
+            name: "foo".to_string(),
+            retries: 8,
+            extra: vec![3, 5, 8],
+        }
+    );
+}
+
+#[test]
+fn all_fields_names() {
+    let _: <Config<()> as AllFields>::Names =
+        <TList<FP!(name), TList<FP!("retry count"), TList<FP!(extra), TNil>>>>::NEW;
+    let _: <(u8, u16) as AllFields>::Names = <TList<FP!(0), TList<FP!(1), TNil>>>::NEW;
+    let _: <() as AllFields>::Names = TNil;
+}
+
+#[test]
+fn tuples() {
+    let mut slot = MaybeUninit::<(u8, String, bool)>::uninit();
+    let tup = UninitBuilder::new(&mut slot)
+        .init(fp!(1), "bar".to_string())
+        .init(fp!(2), true)
+        .init(fp!(0), 13)
+        .finish();
+    assert_eq!(*tup, (13, "bar".to_string(), true));
+    drop(unsafe { slot.assume_init() });
+
+    let mut slot = MaybeUninit::<()>::uninit();
+    let unit: &mut () = UninitBuilder::new(&mut slot).finish();
+    assert_eq!(*unit, ());
+}
+
+#[test]
+fn drops_initialized_fields() {
+    let bits = Cell::new(0);
+    {
+        let mut slot =
+            MaybeUninit::<(OrOnDrop<'_, u8>, OrOnDrop<'_, u8>, OrOnDrop<'_, u8>)>::uninit();
+        let _builder = UninitBuilder::new(&mut slot)
+            .init(fp!(2), OrOnDrop::new(3, &bits, 1 << 2))
+            .init(fp!(0), OrOnDrop::new(5, &bits, 1));
+        assert_eq!(bits.get(), 0);
+    }
+    // `OrOnDrop` panics if it's dropped twice
+    assert_eq!(bits.get(), 0b101);
+
+    bits.set(0);
+    {
+        let mut slot = MaybeUninit::<Config<OrOnDrop<'_, u8>>>::uninit();
+        let _builder =
+            UninitBuilder::new(&mut slot).init(fp!(extra), OrOnDrop::new(8, &bits, 1 << 5));
+    }
+    assert_eq!(bits.get(), 1 << 5);
+
+    bits.set(0);
+    {
+        let mut slot = MaybeUninit::<(OrOnDrop<'_, u8>, OrOnDrop<'_, u8>)>::uninit();
+        UninitBuilder::new(&mut slot)
+            .init(fp!(1), OrOnDrop::new(13, &bits, 1 << 1))
+            .init(fp!(0), OrOnDrop::new(21, &bits, 1))
+            .finish();
+        assert_eq!(bits.get(), 0);
+        let tup = unsafe { slot.assume_init() };
+        let (a, b) = tup.into_fields(fp!(0, 1));
+        assert_eq!(a.into_inner(), 21);
+        assert_eq!(bits.get(), 0b01);
+        drop(b);
+    }
+    assert_eq!(bits.get(), 0b11);
+}
+
+#[test]
+fn initializes_in_place() {
+    let mut slot = Box::new(MaybeUninit::<([u64; 64], String)>::uninit());
+    let addr = slot.as_ptr();
+
+    let builder = UninitBuilder::new(&mut *slot).init(fp!(0), [3; 64]);
+    assert_eq!(builder.as_ptr(), addr);
+    let value = builder.init(fp!(1), "foo".to_string()).finish();
+    assert_eq!(value as *const _, addr);
+    value.1.push_str("bar");
+
+    let value = unsafe { Box::from_raw(Box::into_raw(slot) as *mut ([u64; 64], String)) };
+    assert_eq!(value.0[..], [3; 64][..]);
+    assert_eq!(value.1, "foobar");
+}
@@ -0,0 +1,379 @@
+use structural::{
+    convert::TryFromError,
+    fp, make_struct,
+    validate::{Validate, ValidateField, ValidationError, ValidationErrors},
+    Structural, StructuralExt, TS,
+};
+
+use std::{borrow::Cow, cell::Cell};
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public, from_structural)]
+#[struc(validate = "self::check_dates")]
+struct Booking {
+    #[struc(validate = "non_empty")]
+    #[struc(rename = "guest name")]
+    name: String,
+    #[struc(validate = "in_range")]
+    guests: u32,
+    check_in: u32,
+    check_out: u32,
+    #[struc(private, init_with_default, validate = "always_fails")]
+    notes: Vec<String>,
+}
+
+fn non_empty(s: &str) -> Result<(), &'static str> {
+    if s.is_empty() {
+        Err("must not be empty")
+    } else {
+        Ok(())
+    }
+}
+
+fn in_range(n: &u32) -> Result<(), String> {
+    if 1 <= *n && *n <= 4 {
+        Ok(())
+    } else {
+        Err(format!("{} is not in 1..=4", n))
+    }
+}
+
+fn always_fails(_: &[String]) -> Result<(), Cow<'static, str>> {
+    Err(Cow::Borrowed("notes are read-only"))
+}
+
+fn check_dates(this: &Booking) -> Result<(), &'static str> {
+    if this.check_in < this.check_out {
+        Ok(())
+    } else {
+        Err("must check out after checking in")
+    }
+}
+
+fn booking() -> Booking {
+    Booking {
+        name: "ann".to_string(),
+        guests: 2,
+        check_in: 10,
+        check_out: 12,
+        notes: Vec::new(),
+    }
+}
+
+#[test]
+fn validate_all() {
+    let errors = booking().validate().unwrap_err();
+    assert_eq!(
+        errors.as_slice(),
+        &[ValidationError::field("notes", "notes are read-only")][..]
+    );
+
+    let this = Booking {
+        name: String::new(),
+        guests: 0,
+        check_in: 12,
+        check_out: 12,
+        ..booking()
+    };
+    let errors = this.validate().unwrap_err();
+    assert_eq!(errors.len(), 4);
+    assert_eq!(
+        errors.field_names().collect::<Vec<_>>(),
+        vec!["guest name", "guests", "notes"],
+    );
+    assert_eq!(
+        errors.iter().last(),
+        Some(&ValidationError::container(
+            "must check out after checking in"
+        ))
+    );
+    assert_eq!(
+        errors.to_string(),
+        "`guest name`: must not be empty\n\
+         `guests`: 0 is not in 1..=4\n\
+         `notes`: notes are read-only\n\
+         must check out after checking in",
+    );
+}
+
+#[test]
+fn validate_subset() {
+    let this = Booking {
+        name: String::new(),
+        guests: 9,
+        ..booking()
+    };
+
+    assert_eq!(this.validate_fields(fp!(check_in, check_out)), Ok(()));
+
+    let errors = this.validate_fields(fp!(guests)).unwrap_err();
+    assert_eq!(
+        errors.into_vec(),
+        vec![ValidationError::field("guests", "9 is not in 1..=4")]
+    );
+
+    let errors = this
+        .validate_fields(fp!(check_out, "guest name", guests))
+        .unwrap_err();
+    assert_eq!(
+        errors.field_names().collect::<Vec<_>>(),
+        vec!["guest name", "guests"]
+    );
+
+    let mut errors = ValidationErrors::new();
+    this.validate_field(<TS!(guests)>::NEW, &mut errors);
+    assert_eq!(errors.len(), 1);
+}
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public, no_trait, access = "mut move")]
+struct Form {
+    #[struc(rename = "guest name")]
+    name: String,
+    guests: u32,
+    check_in: u32,
+    check_out: u32,
+    submitted: bool,
+}
+
+#[test]
+fn try_from_structural() {
+    let valid = Form {
+        name: "bob".to_string(),
+        guests: 1,
+        check_in: 3,
+        check_out: 5,
+        submitted: true,
+    };
+    assert_eq!(
+        valid.try_into_struc::<Booking>(),
+        Ok(Booking {
+            name: "bob".to_string(),
+            guests: 1,
+            check_in: 3,
+            check_out: 5,
+            notes: Vec::new(),
+        })
+    );
+
+    let invalid = Form {
+        name: String::new(),
+        guests: 5,
+        check_in: 3,
+        check_out: 5,
+        submitted: false,
+    };
+    let TryFromError { from, error } = invalid.clone().try_into_struc::<Booking>().unwrap_err();
+    assert_eq!(from, invalid);
+    assert_eq!(
+        error.field_names().collect::<Vec<_>>(),
+        vec!["guest name", "guests"]
+    );
+
+    // The validator of a field that's initialized by the `init_*` attributes
+    // (the `notes` field) doesn't run.
+    let valid = booking().try_into_struc::<Booking>().unwrap();
+    assert_eq!(valid, booking());
+
+    // The container validator runs on a value constructed from clones of the fields,
+    // the converted-from value is returned if it fails.
+    let dates_swapped = Form {
+        name: "cid".to_string(),
+        guests: 4,
+        check_in: 5,
+        check_out: 3,
+        submitted: true,
+    };
+    let TryFromError { from, error } = dates_swapped
+        .clone()
+        .try_into_struc::<Booking>()
+        .unwrap_err();
+    assert_eq!(from, dates_swapped);
+    assert_eq!(
+        error.as_slice(),
+        &[ValidationError::container(
+            "must check out after checking in"
+        )][..],
+    );
+
+    // The container validator doesn't run if any field is invalid.
+    let both_invalid = Form {
+        guests: 0,
+        ..dates_swapped.clone()
+    };
+    let TryFromError { from, error } = both_invalid
+        .clone()
+        .try_into_struc::<Booking>()
+        .unwrap_err();
+    assert_eq!(from, both_invalid);
+    assert_eq!(error.field_names().collect::<Vec<_>>(), vec!["guests"]);
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(validate = "Pair::check")]
+struct Pair<T>(
+    #[struc(public)] T,
+    #[struc(public, validate = "positive")] i32,
+)
+where
+    T: PartialEq;
+
+impl<T: PartialEq> Pair<T> {
+    fn check(&self) -> Result<(), &'static str> {
+        Ok(())
+    }
+}
+
+fn positive(n: &i32) -> Result<(), &'static str> {
+    if *n > 0 {
+        Ok(())
+    } else {
+        Err("must be positive")
+    }
+}
+
+#[test]
+fn tuple_struct() {
+    assert_eq!(Pair("hello", 3).validate(), Ok(()));
+    assert_eq!(Pair("hello", 0).validate_fields(fp!(0)), Ok(()));
+    assert_eq!(
+        Pair("hello", 0)
+            .validate_fields(fp!(0, 1))
+            .unwrap_err()
+            .as_slice(),
+        &[ValidationError::field("1", "must be positive")][..],
+    );
+}
+
+thread_local! {
+    static DROPS: Cell<u32> = Cell::new(0);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Counted(u32);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        DROPS.with(|x| x.set(x.get() + 1));
+    }
+}
+
+fn counted_zero() -> Counted {
+    Counted(0)
+}
+
+#[derive(Structural, Debug)]
+#[struc(public, from_structural)]
+#[struc(validate = "Ordered::check")]
+struct Ordered {
+    low: Counted,
+    high: Counted,
+    #[struc(private, init_with_fn = "counted_zero")]
+    extra: Counted,
+}
+
+impl Ordered {
+    fn check(&self) -> Result<(), &'static str> {
+        if self.low.0 < self.high.0 {
+            Ok(())
+        } else {
+            Err("low must be less than high")
+        }
+    }
+}
+
+#[test]
+fn container_validator_drops() {
+    let drops = || DROPS.with(|x| x.get());
+
+    let from = make_struct! {
+        low: Counted(3),
+        high: Counted(1),
+        other: Counted(8),
+    };
+    let TryFromError { from, error } = match from.try_into_struc::<Ordered>() {
+        Ok(_) => panic!("expected an error"),
+        Err(e) => e,
+    };
+    // Only the value that the container validator ran on was dropped
+    assert_eq!(drops(), 3);
+    assert_eq!(error.len(), 1);
+    let (low, high, other) = from.fields(fp!(low, high, other));
+    assert_eq!((low.0, high.0, other.0), (3, 1, 8));
+    drop(from);
+    assert_eq!(drops(), 6);
+
+    let from = make_struct! {
+        low: Counted(1),
+        high: Counted(3),
+        other: Counted(8),
+    };
+    let converted = match from.try_into_struc::<Ordered>() {
+        Ok(x) => x,
+        Err(_) => panic!("expected a value"),
+    };
+    // The converted-from value was dropped
+    assert_eq!(drops(), 9);
+    assert_eq!((converted.low.0, converted.high.0), (1, 3));
+    drop(converted);
+    assert_eq!(drops(), 12);
+}
+
+thread_local! {
+    static PRE_MOVES: Cell<u32> = Cell::new(0);
+}
+
+#[derive(Structural, Debug, Clone, PartialEq)]
+#[struc(public, no_trait, pre_move = "Source::on_pre_move")]
+struct Source {
+    low: u32,
+    high: u32,
+}
+
+impl Source {
+    fn on_pre_move(&mut self) {
+        PRE_MOVES.with(|x| x.set(x.get() + 1));
+    }
+}
+
+impl Drop for Source {
+    fn drop(&mut self) {
+        self.on_pre_move();
+    }
+}
+
+#[derive(Structural, Debug, PartialEq)]
+#[struc(public, from_structural)]
+#[struc(validate = "Span::check")]
+struct Span {
+    low: u32,
+    high: u32,
+}
+
+impl Span {
+    fn check(&self) -> Result<(), &'static str> {
+        if self.low < self.high {
+            Ok(())
+        } else {
+            Err("low must be less than high")
+        }
+    }
+}
+
+#[test]
+fn container_validator_pre_move() {
+    let pre_moves = || PRE_MOVES.with(|x| x.get());
+
+    let TryFromError { from, error } = Source { low: 3, high: 1 }
+        .try_into_struc::<Span>()
+        .unwrap_err();
+    assert_eq!(pre_moves(), 0);
+    assert_eq!(error.len(), 1);
+    assert_eq!((from.low, from.high), (3, 1));
+    drop(from);
+    assert_eq!(pre_moves(), 1);
+
+    let converted = Source { low: 1, high: 3 }.try_into_struc::<Span>();
+    assert_eq!(converted, Ok(Span { low: 1, high: 3 }));
+    assert_eq!(pre_moves(), 2);
+}
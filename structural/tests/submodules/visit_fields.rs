@@ -0,0 +1,225 @@
+use structural::{
+    make_struct,
+    visit_fields::{FieldAccess, FieldInfo},
+    FieldVisitor, Structural, VisitFields,
+};
+
+use std::fmt::Debug;
+
+/// Records every visited field,and whether it was visited mutably.
+#[derive(Default)]
+struct Recorder(Vec<(FieldInfo, String, bool)>);
+
+impl<T: ?Sized + Debug> FieldVisitor<T> for Recorder {
+    fn visit_field(&mut self, field: FieldInfo, value: &T) {
+        self.0.push((field, format!("{:?}", value), false));
+    }
+    fn visit_field_mut(&mut self, field: FieldInfo, value: &mut T) {
+        self.0.push((field, format!("{:?}", value), true));
+    }
+}
+
+fn visited<T>(this: &T) -> Vec<(FieldInfo, String, bool)>
+where
+    T: VisitFields<Recorder>,
+{
+    let mut visitor = Recorder::default();
+    this.visit_fields(&mut visitor);
+    visitor.0
+}
+
+fn visited_mut<T>(this: &mut T) -> Vec<(FieldInfo, String, bool)>
+where
+    T: VisitFields<Recorder>,
+{
+    let mut visitor = Recorder::default();
+    this.visit_fields_mut(&mut visitor);
+    visitor.0
+}
+
+fn field(variant: Option<&'static str>, name: &'static str, access: FieldAccess) -> FieldInfo {
+    FieldInfo {
+        variant,
+        name,
+        access,
+    }
+}
+
+#[derive(Structural)]
+struct Accesses<T> {
+    #[struc(access = "ref")]
+    pub shared: T,
+    #[struc(access = "mut")]
+    pub mutable: u32,
+    #[struc(access = "move")]
+    pub value: &'static str,
+    #[struc(rename = "renamed")]
+    pub mut_value: Option<T>,
+    #[allow(dead_code)]
+    private: (),
+}
+
+#[test]
+fn struct_fields() {
+    use self::FieldAccess as FA;
+
+    let mut this = Accesses {
+        shared: 3u8,
+        mutable: 5,
+        value: "8",
+        mut_value: Some(13),
+        private: (),
+    };
+
+    let expected = vec![
+        (field(None, "shared", FA::Shared), "3".to_string()),
+        (field(None, "mutable", FA::Mutable), "5".to_string()),
+        (field(None, "value", FA::Value), "\"8\"".to_string()),
+        (field(None, "renamed", FA::MutValue), "Some(13)".to_string()),
+    ];
+
+    let with_mut = |muts: [bool; 4]| {
+        expected
+            .iter()
+            .cloned()
+            .zip(muts.iter().cloned())
+            .map(|((f, v), m)| (f, v, m))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(visited(&this), with_mut([false; 4]));
+    assert_eq!(visited_mut(&mut this), with_mut([false, true, false, true]));
+}
+
+#[derive(Structural, Debug)]
+enum Enum {
+    Unit,
+    Tuple(u32, #[struc(rename = "second")] bool),
+    Braced {
+        #[struc(access = "ref")]
+        x: &'static str,
+        y: char,
+    },
+    #[struc(rename = "Renamed")]
+    Other {
+        #[struc(not_public)]
+        #[allow(dead_code)]
+        hidden: u32,
+        z: u64,
+    },
+    #[struc(newtype)]
+    Newtype((u8, u8)),
+}
+
+#[test]
+fn enum_fields() {
+    use self::FieldAccess as FA;
+
+    let t = |v, n, a, s: &str, m| (field(Some(v), n, a), s.to_string(), m);
+
+    assert_eq!(visited(&Enum::Unit), vec![]);
+
+    assert_eq!(
+        visited(&Enum::Tuple(3, true)),
+        vec![
+            t("Tuple", "0", FA::MutValue, "3", false),
+            t("Tuple", "second", FA::MutValue, "true", false),
+        ],
+    );
+    assert_eq!(
+        visited_mut(&mut Enum::Braced { x: "5", y: 'c' }),
+        vec![
+            t("Braced", "x", FA::Shared, "\"5\"", false),
+            t("Braced", "y", FA::MutValue, "'c'", true),
+        ],
+    );
+    assert_eq!(
+        visited(&Enum::Other { hidden: 8, z: 13 }),
+        vec![t("Renamed", "z", FA::MutValue, "13", false)],
+    );
+    assert_eq!(
+        visited_mut(&mut Enum::Newtype((21, 34))),
+        vec![t("Newtype", "0", FA::MutValue, "(21, 34)", true)],
+    );
+}
+
+#[test]
+fn std_types() {
+    use self::FieldAccess::MutValue;
+
+    let t = |v, n, s: &str, m| (field(v, n, MutValue), s.to_string(), m);
+
+    assert_eq!(
+        visited(&(3, "5", '8')),
+        vec![
+            t(None, "0", "3", false),
+            t(None, "1", "\"5\"", false),
+            t(None, "2", "'8'", false),
+        ],
+    );
+    assert_eq!(
+        visited_mut(&mut [13, 21]),
+        vec![t(None, "0", "13", true), t(None, "1", "21", true)],
+    );
+    assert_eq!(visited(&[0u8; 0]), vec![]);
+    assert_eq!(
+        visited(&make_struct! { foo: 3, bar: "hello" }),
+        vec![
+            t(None, "foo", "3", false),
+            t(None, "bar", "\"hello\"", false)
+        ],
+    );
+    assert_eq!(
+        visited(&(3..5)),
+        vec![t(None, "start", "3", false), t(None, "end", "5", false)],
+    );
+    assert_eq!(
+        visited_mut(&mut Some(8)),
+        vec![t(Some("Some"), "0", "8", true)],
+    );
+    assert_eq!(visited(&None::<u32>), vec![]);
+    assert_eq!(
+        visited(&Err::<u32, _>(13)),
+        vec![t(Some("Err"), "0", "13", false)],
+    );
+}
+
+/// Doubles every field that is mutably accessible.
+struct Doubler;
+
+impl FieldVisitor<u32> for Doubler {
+    fn visit_field(&mut self, _: FieldInfo, _: &u32) {}
+
+    fn visit_field_mut(&mut self, _: FieldInfo, value: &mut u32) {
+        *value *= 2;
+    }
+}
+
+impl FieldVisitor<&'static str> for Doubler {
+    fn visit_field(&mut self, _: FieldInfo, _: &&'static str) {}
+}
+
+impl FieldVisitor<Option<u32>> for Doubler {
+    fn visit_field(&mut self, _: FieldInfo, _: &Option<u32>) {}
+}
+
+#[test]
+fn mutation() {
+    let mut this = (3u32, 5u32);
+    this.visit_fields_mut(&mut Doubler);
+    assert_eq!(this, (6, 10));
+
+    let mut array = [8u32, 13, 21];
+    array.visit_fields_mut(&mut &mut Doubler);
+    assert_eq!(array, [16, 26, 42]);
+
+    let mut accesses = Accesses {
+        shared: 3u32,
+        mutable: 5,
+        value: "",
+        mut_value: None,
+        private: (),
+    };
+    accesses.visit_fields_mut(&mut Doubler);
+    assert_eq!((accesses.shared, accesses.mutable), (3, 10));
+}
@@ -0,0 +1,13 @@
+use rustc_version::Version;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(rust_1_78)");
+
+    let rver = rustc_version::version().unwrap();
+
+    // Enables outputting `#[diagnostic::on_unimplemented]` attributes
+    if Version::new(1, 78, 0) <= rver {
+        println!("cargo:rustc-cfg=rust_1_78");
+    }
+}
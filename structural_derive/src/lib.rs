@@ -34,6 +34,7 @@ mod ident_or_index;
 mod ignored_wrapper;
 mod impl_struct;
 mod parse_utils;
+mod struc_format;
 mod structural_alias_impl_mod;
 mod structural_derive;
 mod switch_tstring_aliases;
@@ -65,6 +66,50 @@ pub fn structural_alias_impl(input: TokenStream1) -> TokenStream1 {
     parse_or_compile_err(input, |sah: StructuralAliasesHack| Ok(sah.tokens)).into()
 }
 
+/// Declares the `<newtype>_SI` trait of the `remote_structural` macro,
+/// taking `structural_alias` input with the name of the newtype as the trait name.
+#[proc_macro]
+#[doc(hidden)]
+pub fn _remote_structural_alias(input: TokenStream1) -> TokenStream1 {
+    use proc_macro2::TokenTree;
+    use structural_alias_impl_mod::StructuralAliasesHack;
+
+    parse_or_compile_err(input, |tokens: TokenStream2| {
+        let mut newtype = None::<syn::Ident>;
+        let mut after_trait = false;
+        let renamed = tokens
+            .into_iter()
+            .map(|tt| match tt {
+                TokenTree::Ident(ref ident) if after_trait && newtype.is_none() => {
+                    let trait_ = syn::Ident::new(&format!("{}_SI", ident), ident.span());
+                    newtype = Some(ident.clone());
+                    TokenTree::Ident(trait_)
+                }
+                tt => {
+                    after_trait = match tt {
+                        TokenTree::Ident(ref ident) => ident == "trait",
+                        _ => false,
+                    };
+                    tt
+                }
+            })
+            .collect::<TokenStream2>();
+
+        let newtype = newtype.ok_or_else(|| {
+            syn::Error::new(proc_macro2::Span::call_site(), "expected a trait declaration")
+        })?;
+        let docs = format!(
+            "A trait aliasing the accessor impls for \
+             [{newtype}](./struct.{newtype}.html) fields",
+            newtype = newtype,
+        );
+
+        syn::parse2::<StructuralAliasesHack>(quote::quote!( #[doc = #docs] #renamed ))
+            .map(|sah| sah.tokens)
+    })
+    .into()
+}
+
 #[proc_macro]
 #[allow(non_snake_case)]
 #[doc(hidden)]
@@ -172,6 +217,12 @@ pub fn _switch_tstring_aliases(input: TokenStream1) -> TokenStream1 {
     parse_or_compile_err(input, switch_tstring_aliases::impl_).into()
 }
 
+#[proc_macro]
+#[doc(hidden)]
+pub fn _struc_format_impl(input: TokenStream1) -> TokenStream1 {
+    parse_or_compile_err(input, struc_format::impl_).into()
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 fn parse_or_compile_err<P, F>(input: TokenStream1, f: F) -> TokenStream2
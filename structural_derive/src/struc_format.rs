@@ -0,0 +1,188 @@
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+
+use quote::quote;
+
+use syn::{
+    parse::{self, Parse, ParseStream},
+    LitStr, Token,
+};
+
+/// This is what the `struc_format`,`struc_write`,and `struc_println` macros call,
+/// rewriting the field paths in the format string into positional arguments.
+pub(crate) fn impl_(parsed: StrucFormat) -> Result<TokenStream2, syn::Error> {
+    let StrucFormat {
+        callback,
+        prefix,
+        format_str,
+        this,
+    } = parsed;
+
+    let span = format_str.span();
+    let placeholders = parse_format_str(&format_str.value(), span)?;
+
+    let new_format_str = LitStr::new(&placeholders.format_str, span);
+
+    let args = placeholders.args.iter().map(|arg| {
+        let path = &arg.path;
+        let fallback = &arg.fallback;
+        quote!(
+            ::structural::pmr::IntoFormatArg::into_format_arg(
+                ::structural::StructuralExt::field_(__struc_this, ::structural::fp!(#path)),
+                #fallback,
+            )
+        )
+    });
+
+    Ok(quote!(
+        match #this {
+            ref __struc_this => #callback!(#prefix #new_format_str, #(#args,)*)
+        }
+    ))
+}
+
+pub(crate) struct StrucFormat {
+    callback: TokenStream2,
+    prefix: TokenStream2,
+    format_str: LitStr,
+    this: TokenStream2,
+}
+
+impl Parse for StrucFormat {
+    fn parse(input: ParseStream<'_>) -> parse::Result<Self> {
+        let callback = parse_group(input)?;
+        let prefix = parse_group(input)?;
+        let format_str = input.parse::<LitStr>()?;
+        input.parse::<Token!(,)>()?;
+        let this = input.parse::<TokenStream2>()?;
+        Ok(StrucFormat {
+            callback,
+            prefix,
+            format_str,
+            this,
+        })
+    }
+}
+
+/// Parses a delimited group,returning the tokens inside of it.
+fn parse_group(input: ParseStream<'_>) -> parse::Result<TokenStream2> {
+    match input.parse::<TokenTree2>()? {
+        TokenTree2::Group(group) => Ok(group.stream()),
+        tt => Err(syn::Error::new(tt.span(), "expected a delimited group")),
+    }
+}
+
+struct Placeholders {
+    format_str: String,
+    args: Vec<FormatArg>,
+}
+
+struct FormatArg {
+    path: TokenStream2,
+    fallback: String,
+}
+
+/// Replaces every `{field.path|fallback:spec}` placeholder in the format string
+/// with a positional `{:spec}` placeholder.
+fn parse_format_str(input: &str, span: Span) -> Result<Placeholders, syn::Error> {
+    let mut format_str = String::with_capacity(input.len());
+    let mut args = Vec::new();
+
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                format_str.push_str("{{");
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                format_str.push_str("}}");
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(placeholder_char) => placeholder.push(placeholder_char),
+                        None => {
+                            return Err(syn::Error::new(span, "unclosed `{` in format string"));
+                        }
+                    }
+                }
+
+                let (arg, spec) = parse_placeholder(&placeholder, span)?;
+                args.push(arg);
+                format_str.push('{');
+                if let Some(spec) = spec {
+                    format_str.push(':');
+                    format_str.push_str(spec);
+                }
+                format_str.push('}');
+            }
+            '}' => {
+                return Err(syn::Error::new(
+                    span,
+                    "unmatched `}` in format string,use `}}` to write a `}`",
+                ));
+            }
+            c => format_str.push(c),
+        }
+    }
+
+    Ok(Placeholders { format_str, args })
+}
+
+/// Parses the contents of a placeholder,returning the format spec separately.
+fn parse_placeholder(
+    placeholder: &str,
+    span: Span,
+) -> Result<(FormatArg, Option<&str>), syn::Error> {
+    let bytes = placeholder.as_bytes();
+
+    // The first `:` that isn't part of a `::`,since enum variants are prefixed with `::`.
+    let spec_pos = (0..bytes.len()).find(|&i| {
+        bytes[i] == b':'
+            && (i == 0 || bytes[i - 1] != b':')
+            && match bytes.get(i + 1) {
+                Some(&next) => next != b':',
+                None => true,
+            }
+    });
+
+    let (path_and_fallback, spec) = match spec_pos {
+        Some(pos) => (&placeholder[..pos], Some(&placeholder[pos + 1..])),
+        None => (placeholder, None),
+    };
+
+    let (path, fallback) = match path_and_fallback.find('|') {
+        Some(pos) => (&path_and_fallback[..pos], &path_and_fallback[pos + 1..]),
+        None => (path_and_fallback, ""),
+    };
+
+    let path = path.trim();
+    if path.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "expected a field path in the `{{{}}}` placeholder,eg: `{{foo.bar}}`",
+                placeholder,
+            ),
+        ));
+    }
+
+    let path = syn::parse_str::<TokenStream2>(path).map_err(|e| {
+        syn::Error::new(
+            span,
+            format!(
+                "invalid field path in the `{{{}}}` placeholder:\n{}",
+                placeholder, e
+            ),
+        )
+    })?;
+
+    let arg = FormatArg {
+        path,
+        fallback: fallback.to_string(),
+    };
+    Ok((arg, spec))
+}
@@ -5,7 +5,7 @@ use crate::{
     parse_utils::ParsePunctuated,
     tokenizers::tstr_tokens,
     utils::SpanExt,
-    write_docs::DocsFor,
+    write_docs::{self, DocsFor},
 };
 
 use as_derive_utils::{
@@ -34,10 +34,14 @@ use std::fmt::{self, Display};
 #[cfg(test)]
 mod tests;
 
+mod assoc_types;
 mod attribute_parsing;
 mod parsing;
 
-use self::attribute_parsing::StructuralAliasOptions;
+use self::{
+    assoc_types::{AssocTypes, Bounds},
+    attribute_parsing::StructuralAliasOptions,
+};
 
 ////////////////////////////////////////////////////////////////////////////////
 
@@ -201,6 +205,15 @@ impl<'a> IdentType<'a> {
         }
     }
 
+    /// The name as a string,for identifiers and integers.
+    pub(crate) fn name_string(self) -> String {
+        match self {
+            IdentType::Ident(ident) => ident.to_string(),
+            IdentType::Generic(ident) => ident.to_string(),
+            IdentType::SomeType(ty) => ty.to_token_stream().to_string(),
+        }
+    }
+
     pub(crate) fn span(self) -> Span {
         match self {
             IdentType::Ident(ident) => ident.span(),
@@ -355,6 +368,7 @@ pub(crate) fn macro_impl(aliases: StructuralAliases<'_>) -> Result<TokenStream2,
             variant_trait: None,
             enum_exhaustiveness: saf.options.enum_exhaustiveness,
             datatype: &saf.datatype,
+            assoc_types: false,
         }
         .tokens()?;
 
@@ -372,6 +386,8 @@ fn process_field(
     field: &StructuralField<'_>,
     variant_ident: Option<IdentType<'_>>,
     field_bounds: &mut TokenStream2,
+    impl_fields: &mut ImplFieldTraits<'_, '_>,
+    omit_ty: bool,
 ) {
     use self::FieldType as FT;
 
@@ -382,8 +398,17 @@ fn process_field(
     let field_name = field.ident.tstr_tokens();
     let aaoo = field.compute_trait(soe);
     let assoc_ty = match &field.ty {
+        FT::Ty(_) if omit_ty => TokenStream2::new(),
         FT::Ty(ty) => quote!(Ty=#ty),
-        FT::Impl(bounds) => quote!(Ty:#bounds),
+        FT::Impl(bounds) => {
+            let path = match variant_ident {
+                Some(vari) => quote!(structural::pmr::VariantField<#vari, #field_name>),
+                None => field_name.clone(),
+            };
+            let helper = impl_fields.add(&path, bounds, span);
+            field_bounds.append_all(quote_spanned!(span=> #helper+ ));
+            TokenStream2::new()
+        }
     };
 
     match variant_ident {
@@ -415,6 +440,129 @@ fn process_field(
     }
 }
 
+/// Generates the helper traits used to bound the type of `impl Trait` fields,
+/// since `Trait<Ty: Bounds>` (associated type bounds) is unstable.
+///
+/// Each helper trait has the field's `FieldType` impl as a supertrait,
+/// with the associated type bound by `Bounds`.
+struct ImplFieldTraits<'a, 'b> {
+    alias: &'a Ident,
+    vis: &'a syn::Visibility,
+    impl_generics: &'a GenParamsIn<'a, TokenStream2>,
+    decl_generics: &'a GenParamsIn<'a, &'a TokenStream2>,
+    ty_generics: &'a GenParamsIn<'a, &'a TokenStream2>,
+    where_preds: &'a Punctuated<syn::WherePredicate, syn::Token!(,)>,
+    extra_where_preds: &'a Punctuated<syn::WherePredicate, syn::Token!(,)>,
+    assoc_types: Option<&'a AssocTypes<'a>>,
+    count: usize,
+    tokens: &'b mut TokenStream2,
+}
+
+impl ImplFieldTraits<'_, '_> {
+    /// Outputs a helper trait for a field with `path` as its path,
+    /// returning the bound that the alias trait uses to require it.
+    fn add(&mut self, path: &TokenStream2, bounds: &TypeParamBounds, span: Span) -> TokenStream2 {
+        let helper = Ident::new(&format!("{}_ImplField{}", self.alias, self.count), span);
+        self.count += 1;
+
+        let Self {
+            vis,
+            impl_generics,
+            decl_generics,
+            ty_generics,
+            ..
+        } = *self;
+        let where_preds_a = self.where_preds.iter();
+        let where_preds_b = self.where_preds.iter();
+        let assoc_types_bounds = self.assoc_types.map(|at| at.field_type_bounds());
+        let assoc_types_bounds_a = assoc_types_bounds.iter();
+        let assoc_types_bounds_b = assoc_types_bounds.iter();
+        let extra_where_preds_a = self.extra_where_preds.iter();
+        let extra_where_preds_b = self.extra_where_preds.iter();
+
+        let helper_tokens = quote_spanned!(span=>
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #vis trait #helper <#decl_generics>:
+                #(#assoc_types_bounds_a)*
+                structural::pmr::FieldType<
+                    #path,
+                    Ty = <Self as #helper<#ty_generics>>::Ty,
+                >
+            where
+                #(#where_preds_a,)*
+                #(#extra_where_preds_a,)*
+            {
+                type Ty: #bounds;
+            }
+
+            impl<#impl_generics> #helper<#ty_generics> for __This
+            where
+                __This: ?Sized + #(#assoc_types_bounds_b)* structural::pmr::FieldType<#path>,
+                <__This as structural::pmr::FieldType<#path>>::Ty: #bounds,
+                #(#where_preds_b,)*
+                #(#extra_where_preds_b,)*
+            {
+                type Ty = <__This as structural::pmr::FieldType<#path>>::Ty;
+            }
+        );
+        self.tokens.append_all(match self.assoc_types {
+            Some(at) => at.in_impl::<syn::File>(helper_tokens),
+            None => helper_tokens,
+        });
+
+        quote_spanned!(span=> #helper<#ty_generics>)
+    }
+}
+
+/// The bounds for the fields of the trait alias,
+/// separate for the trait declaration and its impl
+/// because type parameters are replaced differently in `#[struc(assoc_types)]` mode.
+struct FieldBounds<'a> {
+    decl: TokenStream2,
+    impl_: TokenStream2,
+    assoc_types: Option<&'a AssocTypes<'a>>,
+    alias: TokenStream2,
+}
+
+impl FieldBounds<'_> {
+    fn push(&mut self, bounds: TokenStream2) {
+        match self.assoc_types {
+            Some(at) => {
+                self.decl
+                    .append_all(at.in_decl::<Bounds>(bounds.clone(), &self.alias));
+                self.impl_.append_all(at.in_impl::<Bounds>(bounds));
+            }
+            None => {
+                self.decl.append_all(bounds.clone());
+                self.impl_.append_all(bounds);
+            }
+        }
+    }
+
+    fn push_field(
+        &mut self,
+        field: &StructuralField<'_>,
+        variant_ident: Option<IdentType<'_>>,
+        impl_fields: &mut ImplFieldTraits<'_, '_>,
+    ) {
+        let mut bounds = TokenStream2::new();
+        process_field(field, variant_ident, &mut bounds, impl_fields, false);
+        match self.assoc_types {
+            // The impl can't constrain the type of the field that
+            // the associated type is defined as without causing an overflow error.
+            Some(at) if at.is_defining(variant_ident, field.ident) => {
+                let mut impl_bounds = TokenStream2::new();
+                process_field(field, variant_ident, &mut impl_bounds, impl_fields, true);
+                self.decl
+                    .append_all(at.in_decl::<Bounds>(bounds, &self.alias));
+                self.impl_.append_all(impl_bounds);
+            }
+            _ => self.push(bounds),
+        }
+    }
+}
+
 pub(crate) struct StructuralAliasParams<'a, A, I> {
     pub(crate) span: Span,
     pub(crate) attrs: A,
@@ -428,6 +576,9 @@ pub(crate) struct StructuralAliasParams<'a, A, I> {
     pub(crate) variant_trait: Option<&'a Ident>,
     pub(crate) enum_exhaustiveness: Exhaustiveness<'a>,
     pub(crate) datatype: &'a StructuralDataType<'a>,
+    /// Whether the type parameters are replaced with associated types,
+    /// from the `#[struc(assoc_types)]` attribute.
+    pub(crate) assoc_types: bool,
 }
 
 impl<'a, A, I> StructuralAliasParams<'a, A, I> {
@@ -460,6 +611,7 @@ pub(crate) fn for_delegation<'a, A, I>(
         variant_trait,
         enum_exhaustiveness,
         datatype,
+        assoc_types,
     }: StructuralAliasParams<'a, A, I>,
 ) -> Result<TokenStream2, syn::Error>
 where
@@ -502,6 +654,7 @@ where
             variant_trait: None,
             enum_exhaustiveness: Exhaustiveness::Nonexhaustive,
             datatype,
+            assoc_types,
         };
 
         tokens.append_all(sap.tokens()?);
@@ -528,7 +681,15 @@ where
 
     let attrs = attrs;
 
-    let mut field_bounds = TokenStream2::new();
+    let owned_assoc_types;
+    let (generics, extra_where_preds, assoc_types) =
+        if assoc_types && generics.type_params().next().is_some() {
+            owned_assoc_types = AssocTypes::new(generics, extra_where_preds, datatype)?;
+            let at = &owned_assoc_types;
+            (&at.generics, &at.extra_where_preds, Some(at))
+        } else {
+            (generics, extra_where_preds, None)
+        };
 
     let mut out_trait_items = TokenStream2::new();
     for item in trait_items {
@@ -553,32 +714,6 @@ where
         }
     }
 
-    for variant in &datatype.variants {
-        let span = variant.name.span();
-        let variant_name = variant.name;
-        let variant_ident = Some(variant.name);
-
-        field_bounds.append_all(quote_spanned!(span=>
-            structural::pmr::IsVariant<#variant_name>+
-        ));
-
-        match &variant.replace_bounds {
-            Some(replace_bounds) => {
-                replace_bounds.to_tokens(&mut field_bounds, variant.name)?;
-            }
-            None if !variant.is_newtype => {
-                for field in &variant.fields {
-                    process_field(field, variant_ident, &mut field_bounds);
-                }
-            }
-            None => {}
-        }
-    }
-
-    for field in datatype.fields.iter() {
-        process_field(field, None, &mut field_bounds);
-    }
-
     let supertraits_a = supertraits.into_iter();
     let supertraits_b = supertraits.into_iter();
 
@@ -607,6 +742,68 @@ where
     let where_preds_a = where_preds.into_iter();
     let where_preds_b = where_preds.into_iter();
 
+    let mut impl_fields = ImplFieldTraits {
+        alias: ident,
+        vis,
+        impl_generics: &impl_generics,
+        decl_generics: &decl_generics,
+        ty_generics: &ty_generics,
+        where_preds,
+        extra_where_preds,
+        assoc_types,
+        count: 0,
+        tokens: &mut tokens,
+    };
+
+    let mut field_bounds = FieldBounds {
+        decl: TokenStream2::new(),
+        impl_: TokenStream2::new(),
+        assoc_types,
+        alias: quote!(#ident<#ty_generics>),
+    };
+
+    for variant in &datatype.variants {
+        let span = variant.name.span();
+        let variant_name = variant.name;
+        let variant_ident = Some(variant.name);
+
+        field_bounds.push(quote_spanned!(span=>
+            structural::pmr::IsVariant<#variant_name>+
+        ));
+
+        match &variant.replace_bounds {
+            Some(replace_bounds) => {
+                let mut bounds = TokenStream2::new();
+                replace_bounds.to_tokens(&mut bounds, variant.name)?;
+                field_bounds.push(bounds);
+            }
+            None if !variant.is_newtype => {
+                for field in &variant.fields {
+                    field_bounds.push_field(field, variant_ident, &mut impl_fields);
+                }
+            }
+            None => {}
+        }
+    }
+
+    for field in datatype.fields.iter() {
+        field_bounds.push_field(field, None, &mut impl_fields);
+    }
+
+    let FieldBounds {
+        decl: field_bounds,
+        impl_: impl_field_bounds,
+        ..
+    } = field_bounds;
+
+    let (assoc_type_impl_preds, assoc_type_defs) = match assoc_types {
+        Some(at) => {
+            out_trait_items.append_all(at.decls(&quote!(#ident<#ty_generics>)));
+            (at.impl_where_preds(), at.defs())
+        }
+        None => (TokenStream2::new(), TokenStream2::new()),
+    };
+
     let mut exhaustive_bound = None;
 
     if let Exhaustiveness::Exhaustive | Exhaustiveness::AndExhaustive { .. } = enum_exhaustiveness {
@@ -633,9 +830,19 @@ where
                     NE = ident,
                 );
 
+                let on_unimplemented = on_unimplemented_attr(
+                    exhaus_ident,
+                    &format!(
+                        "- the `{}` trait\n- exactly {} variants\n",
+                        ident,
+                        datatype.variants.len(),
+                    ),
+                );
+
                 tokens.append_all(quote_spanned!(span=>
                     #(#attrs)*
                     #[doc=#exh_docs]
+                    #on_unimplemented
                     #[allow(non_camel_case_types)]
                     #vis trait #exhaus_ident <#decl_generics> :
                         #ident <#ty_generics>+
@@ -667,9 +874,17 @@ where
 
     let docs = docs.into_iter();
 
+    let on_unimplemented = {
+        let mut note = String::new();
+        write_docs::write_unimplemented_note(&mut note, datatype)
+            .expect("writing to a String can't fail");
+        on_unimplemented_attr(ident, &note)
+    };
+
     tokens.append_all(quote_spanned!(span=>
         #(#attrs)*
         #( #[doc=#docs] )*
+        #on_unimplemented
         #vis trait #ident <#decl_generics> :
             #( #supertraits_a+ )*
             #exhaustive_bound
@@ -689,11 +904,42 @@ where
                 ?Sized+
                 #( #supertraits_b+ )*
                 #exhaustive_bound
-                #field_bounds,
+                #impl_field_bounds,
+            #assoc_type_impl_preds
             #(#where_preds_b,)*
             #(#extra_where_preds_b,)*
-        {}
+        {
+            #assoc_type_defs
+        }
     ));
 
     Ok(tokens)
 }
+
+/// Outputs the `#[diagnostic::on_unimplemented]` attribute for a trait alias,
+/// `requirements` being the list written by `write_docs::write_unimplemented_note`.
+fn on_unimplemented_attr(ident: &Ident, requirements: &str) -> TokenStream2 {
+    if !cfg!(rust_1_78) {
+        return TokenStream2::new();
+    }
+
+    let message = format!(
+        "`{{Self}}` does not implement the `{}` structural alias",
+        ident
+    );
+    let note = if requirements.is_empty() {
+        None
+    } else {
+        // Braces are escaped because they're format placeholders in the attribute.
+        let list = requirements.replace('{', "{{").replace('}', "}}");
+        Some(format!("`{}` requires:\n{}", ident, list.trim_end()))
+    };
+    let note = note.into_iter();
+
+    quote!(
+        #[diagnostic::on_unimplemented(
+            message = #message,
+            #( note = #note, )*
+        )]
+    )
+}
@@ -0,0 +1,326 @@
+//! Generates structural aliases where the type parameters of the
+//! type are replaced with associated types,
+//! used by the `#[struc(assoc_types)]` attribute.
+
+use super::{FieldType, IdentType, StructuralDataType};
+
+use core_extensions::matches;
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::{quote, ToTokens, TokenStreamExt};
+
+use syn::{
+    parse::Parser,
+    punctuated::Punctuated,
+    visit_mut::{self, VisitMut},
+    GenericParam, Generics, Ident, PredicateType, TraitBoundModifier, TypeParamBound,
+    WherePredicate,
+};
+
+/// The bounds of a type parameter or associated type,eg: `Copy + Debug`.
+pub(crate) type Bounds = Punctuated<TypeParamBound, syn::Token!(+)>;
+
+/// The predicates of a where clause,eg: `T: Copy, U: Debug`.
+pub(crate) type WherePreds = Punctuated<WherePredicate, syn::Token!(,)>;
+
+pub(crate) struct AssocTypes<'a> {
+    /// The generics of the trait,
+    /// with the type parameters that became associated types and the where clause removed.
+    pub(crate) generics: Generics,
+    /// The where clause of the type and the `#[struc(bound="...")]` attributes.
+    pub(crate) extra_where_preds: Punctuated<WherePredicate, syn::Token!(,)>,
+    params: Vec<AssocTypeParam<'a>>,
+}
+
+struct AssocTypeParam<'a> {
+    ident: &'a Ident,
+    bounds: Bounds,
+    /// The variant and field that this type parameter is taken from.
+    variant: Option<IdentType<'a>>,
+    field: IdentType<'a>,
+    /// `FieldType<..>` for the field that this type parameter is taken from.
+    field_type_trait: TokenStream2,
+    /// `<Self as FieldType<..>>::Ty` for the field that this type parameter is taken from.
+    field_ty: TokenStream2,
+}
+
+impl<'a> AssocTypes<'a> {
+    pub(crate) fn new(
+        generics: &'a Generics,
+        extra_where_preds: &Punctuated<WherePredicate, syn::Token!(,)>,
+        datatype: &StructuralDataType<'a>,
+    ) -> Result<Self, syn::Error> {
+        let mut params = Vec::new();
+
+        for param in generics.type_params() {
+            let defining = datatype
+                .fields
+                .iter()
+                .map(|f| (None, f))
+                .chain(datatype.variants.iter().flat_map(|v| {
+                    let fields = match (v.is_newtype, v.replace_bounds) {
+                        (false, None) => &v.fields[..],
+                        _ => &[],
+                    };
+                    fields.iter().map(move |f| (Some(v.name), f))
+                }))
+                .find(|(_, f)| match f.ty {
+                    FieldType::Ty(syn::Type::Path(ty)) => {
+                        ty.qself.is_none() && ty.path.is_ident(&param.ident)
+                    }
+                    _ => false,
+                });
+
+            // Type parameters that aren't the type of an accessible field
+            // stay as type parameters of the trait.
+            let (variant, field) = match defining {
+                Some((variant, field)) => (variant, field.ident),
+                None => continue,
+            };
+
+            let field_name = field.tstr_tokens();
+            let path = match variant {
+                Some(variant) => quote!(structural::pmr::VariantField<#variant, #field_name>),
+                None => field_name,
+            };
+
+            let field_type_trait = quote!(structural::pmr::FieldType<#path>);
+            params.push(AssocTypeParam {
+                ident: &param.ident,
+                bounds: bounds_without_maybe(&param.bounds).collect(),
+                variant,
+                field,
+                field_ty: quote!(<Self as #field_type_trait>::Ty),
+                field_type_trait,
+            });
+        }
+
+        // `T: Bounds` predicates become bounds of the associated type,
+        // so that they're implied by the trait.
+        let mut where_preds = Vec::new();
+        let all_preds = generics
+            .where_clause
+            .iter()
+            .flat_map(|x| &x.predicates)
+            .chain(extra_where_preds);
+        for pred in all_preds {
+            let param = match pred {
+                WherePredicate::Type(PredicateType {
+                    lifetimes: None,
+                    bounded_ty: syn::Type::Path(ty),
+                    ..
+                }) if ty.qself.is_none() => params.iter_mut().find(|p| ty.path.is_ident(p.ident)),
+                _ => None,
+            };
+            match (param, pred) {
+                (Some(param), WherePredicate::Type(pred)) => {
+                    param.bounds.extend(bounds_without_maybe(&pred.bounds));
+                }
+                _ => where_preds.push(pred),
+            }
+        }
+
+        let mut this = Self {
+            generics: Generics {
+                params: generics
+                    .params
+                    .iter()
+                    .filter(|p| match p {
+                        GenericParam::Type(tp) => !params.iter().any(|x| *x.ident == tp.ident),
+                        _ => true,
+                    })
+                    .cloned()
+                    .collect(),
+                where_clause: None,
+                ..generics.clone()
+            },
+            extra_where_preds: Punctuated::new(),
+            params,
+        };
+
+        let where_preds = this.in_impl::<WherePreds>(quote!( #(#where_preds,)* ));
+        this.extra_where_preds =
+            syn::parse2::<syn::WhereClause>(quote!(where #where_preds))?.predicates;
+
+        Ok(this)
+    }
+
+    /// Whether the type of this field is the type of an associated type.
+    pub(crate) fn is_defining(&self, variant: Option<IdentType<'_>>, field: IdentType<'_>) -> bool {
+        self.params
+            .iter()
+            .any(|p| p.variant == variant && p.field == field)
+    }
+
+    /// The `FieldType` bounds for the fields that the associated types are taken from,
+    /// required to replace type parameters with `in_impl`.
+    pub(crate) fn field_type_bounds(&self) -> TokenStream2 {
+        let field_type_trait = self.params.iter().map(|p| &p.field_type_trait);
+        quote!( #(#field_type_trait+)* )
+    }
+
+    /// Replaces the type parameters in `tokens`,parsed as an `N`,
+    /// with the field types they're taken from.
+    pub(crate) fn in_impl<N: ReplaceIn>(&self, tokens: TokenStream2) -> TokenStream2 {
+        self.replace_params::<N>(tokens, &|param| param.field_ty.clone())
+    }
+
+    /// Replaces the type parameters in `tokens`,parsed as an `N`,
+    /// with the associated types of the `alias` trait.
+    pub(crate) fn in_decl<N: ReplaceIn>(
+        &self,
+        tokens: TokenStream2,
+        alias: &TokenStream2,
+    ) -> TokenStream2 {
+        self.replace_params::<N>(tokens, &|param| {
+            let ident = param.ident;
+            quote!(<Self as #alias>::#ident)
+        })
+    }
+
+    /// The associated types in the trait declaration.
+    pub(crate) fn decls(&self, alias: &TokenStream2) -> TokenStream2 {
+        let mut out = TokenStream2::new();
+        for param in &self.params {
+            let ident = param.ident;
+            let bounds = self.in_decl::<Bounds>(param.bounds.to_token_stream(), alias);
+            out.append_all(quote!( type #ident: #bounds; ));
+        }
+        out
+    }
+
+    /// The associated types in the impl of the trait.
+    pub(crate) fn defs(&self) -> TokenStream2 {
+        let mut out = TokenStream2::new();
+        for param in &self.params {
+            let ident = param.ident;
+            let field_ty = &param.field_ty;
+            out.append_all(quote!( type #ident = #field_ty; ));
+        }
+        out
+    }
+
+    /// The bounds that the impl of the trait requires for the associated types.
+    pub(crate) fn impl_where_preds(&self) -> TokenStream2 {
+        let mut out = TokenStream2::new();
+        for param in &self.params {
+            let field_ty = &param.field_ty;
+            let bounds = self.in_impl::<Bounds>(param.bounds.to_token_stream());
+            out.append_all(quote!( #field_ty: #bounds, ));
+        }
+        out
+    }
+
+    fn replace_params<N: ReplaceIn>(
+        &self,
+        tokens: TokenStream2,
+        with: &dyn Fn(&AssocTypeParam<'a>) -> TokenStream2,
+    ) -> TokenStream2 {
+        let mut node = match N::parse(tokens) {
+            Ok(x) => x,
+            Err(e) => return e.to_compile_error(),
+        };
+        node.replace_in(&mut ReplaceParams {
+            params: &self.params,
+            with,
+        });
+        node.into_token_stream()
+    }
+}
+
+/// The syntax that type parameters can be replaced in.
+pub(crate) trait ReplaceIn: ToTokens + Sized {
+    fn parse(tokens: TokenStream2) -> Result<Self, syn::Error>;
+
+    fn replace_in(&mut self, visitor: &mut ReplaceParams<'_, '_>);
+}
+
+impl ReplaceIn for syn::File {
+    fn parse(tokens: TokenStream2) -> Result<Self, syn::Error> {
+        syn::parse2(tokens)
+    }
+
+    fn replace_in(&mut self, visitor: &mut ReplaceParams<'_, '_>) {
+        visitor.visit_file_mut(self);
+    }
+}
+
+impl ReplaceIn for Bounds {
+    fn parse(tokens: TokenStream2) -> Result<Self, syn::Error> {
+        Bounds::parse_terminated.parse2(tokens)
+    }
+
+    fn replace_in(&mut self, visitor: &mut ReplaceParams<'_, '_>) {
+        for bound in self.iter_mut() {
+            visitor.visit_type_param_bound_mut(bound);
+        }
+    }
+}
+
+impl ReplaceIn for WherePreds {
+    fn parse(tokens: TokenStream2) -> Result<Self, syn::Error> {
+        WherePreds::parse_terminated.parse2(tokens)
+    }
+
+    fn replace_in(&mut self, visitor: &mut ReplaceParams<'_, '_>) {
+        for pred in self.iter_mut() {
+            visitor.visit_where_predicate_mut(pred);
+        }
+    }
+}
+
+/// Replaces the types that start with a type parameter that became an associated type
+/// (eg: `T` and `T::Assoc`),
+/// leaving the other paths (eg: `Self::T`) as they are.
+pub(crate) struct ReplaceParams<'a, 'b> {
+    params: &'b [AssocTypeParam<'a>],
+    with: &'b dyn Fn(&AssocTypeParam<'a>) -> TokenStream2,
+}
+
+impl VisitMut for ReplaceParams<'_, '_> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(ty_path) = ty {
+            let param = match (&ty_path.qself, ty_path.path.segments.first()) {
+                (None, Some(first)) if ty_path.path.leading_colon.is_none() => self
+                    .params
+                    .iter()
+                    .find(|p| first.ident == *p.ident && first.arguments.is_empty()),
+                _ => None,
+            };
+            if let Some(param) = param {
+                let replacement = (self.with)(param);
+                if ty_path.path.segments.len() == 1 {
+                    *ty = syn::parse_quote!(#replacement);
+                    return;
+                }
+                // `T::Assoc` paths are qualified with the trait bound of `T` when it's unique,
+                // since the associated type can't be looked up in the bounds
+                // of the type that replaces `T`.
+                let mut traits = param.bounds.iter().filter_map(|b| match b {
+                    TypeParamBound::Trait(b) => Some(&b.path),
+                    TypeParamBound::Lifetime(_) => None,
+                });
+                let rem = ty_path.path.segments.iter().skip(1);
+                *ty = match (traits.next(), traits.next()) {
+                    (Some(trait_), None) => {
+                        syn::parse_quote!( <#replacement as #trait_> #(::#rem)* )
+                    }
+                    _ => syn::parse_quote!( #replacement #(::#rem)* ),
+                };
+            }
+        }
+        visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+/// Removes the `?Sized` bound,since field types are always `Sized`.
+fn bounds_without_maybe(bounds: &Bounds) -> impl Iterator<Item = TypeParamBound> + '_ {
+    bounds
+        .iter()
+        .filter(|b| match b {
+            TypeParamBound::Trait(b) => !matches!(TraitBoundModifier::Maybe { .. } = b.modifier),
+            TypeParamBound::Lifetime(_) => true,
+        })
+        .cloned()
+}
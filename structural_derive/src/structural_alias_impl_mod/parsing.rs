@@ -365,25 +365,10 @@ fn check_no_repeated_field(fields: &[StructuralField<'_>]) -> Result<(), syn::Er
 
 impl<'a> FieldType<'a> {
     pub(super) fn parse(arenas: &'a Arenas, input: ParseStream<'_>) -> Result<Self, syn::Error> {
-        const ASSOC_TY_BOUNDS: bool = cfg!(feature = "impl_fields");
-
         use syn::Type;
 
         match input.parse::<syn::Type>()? {
-            Type::ImplTrait(x) => {
-                if ASSOC_TY_BOUNDS {
-                    Ok(FieldType::Impl(arenas.alloc(x.bounds)))
-                } else {
-                    use syn::spanned::Spanned;
-                    Err(syn::Error::new(
-                        x.span(),
-                        "\
-                         Cannot use an `impl Trait` field without enabling the \
-                         \"nightly_impl_fields\" or \"impl_fields\" feature.\
-                         ",
-                    ))
-                }
-            }
+            Type::ImplTrait(x) => Ok(FieldType::Impl(arenas.alloc(x.bounds))),
             x => Ok(FieldType::Ty(arenas.alloc(x))),
         }
     }
@@ -25,18 +25,36 @@ use quote::{quote, ToTokens, TokenStreamExt};
 
 use syn::{punctuated::Punctuated, DeriveInput, Ident, Visibility};
 
+mod all_fields;
+
 mod attribute_config;
 
 mod attribute_parsing;
 
+mod builder;
+
 mod delegation;
 
+mod field_offset;
+
 mod from_structural;
 
+mod pin_projection;
+
+mod remote;
+
+mod soa;
+
+mod str_map;
+
+mod validate;
+
 #[cfg(test)]
 mod tests;
 
-use self::{attribute_parsing::StructuralOptions, delegation::DelegateTo};
+use self::{
+    attribute_config::RemoteConfig, attribute_parsing::StructuralOptions, delegation::DelegateTo,
+};
 
 #[cfg(test)]
 fn derive_from_str(string: &str) -> Result<TokenStream2, syn::Error> {
@@ -174,6 +192,7 @@ fn deriving_structural<'a>(
         drop_params,
         fields: config_fields,
         with_trait_alias,
+        assoc_types,
         non_exhaustive_attr,
         ..
     } = options;
@@ -182,7 +201,14 @@ fn deriving_structural<'a>(
 
     let vis = ds.vis;
 
-    let tyname = ds.name;
+    // The `<deriving_type>_SI` trait is named after the newtype when deriving for a remote type.
+    let tyname = match &options.remote {
+        Some(RemoteConfig {
+            newtype: Some(newtype),
+            ..
+        }) => newtype,
+        _ => ds.name,
+    };
 
     let struct_or_enum = match ds.data_variant {
         DataVariant::Struct => StructOrEnum::Struct,
@@ -231,7 +257,7 @@ fn deriving_structural<'a>(
 
     let sdt = match struct_or_enum {
         StructOrEnum::Struct => StructuralDataType {
-            type_name: Some(ds.name),
+            type_name: Some(tyname),
             fields: make_fields(struct_),
             variants: Vec::new(),
         },
@@ -266,6 +292,12 @@ fn deriving_structural<'a>(
         },
     };
 
+    check_no_repeated_names(sdt.fields.iter().map(|f| f.ident), "field")?;
+    check_no_repeated_names(sdt.variants.iter().map(|v| v.name), "variant")?;
+    for variant in &sdt.variants {
+        check_no_repeated_names(variant.fields.iter().map(|f| f.ident), "field")?;
+    }
+
     {
         if sdt.fields.len() > STRUCTURAL_SIZE_LIMIT {
             return_spanned_err! {
@@ -288,6 +320,15 @@ fn deriving_structural<'a>(
         }
     }
 
+    let moved_bits = if options.has_delegated_fields {
+        delegation::delegated_moved_bits(ds, options, sdt.fields.len())?
+    } else {
+        Vec::new()
+    };
+    if !moved_bits.is_empty() {
+        contains_move_field = true;
+    }
+
     let mut structural_alias_trait = TokenStream2::new();
 
     if *with_trait_alias {
@@ -331,6 +372,30 @@ fn deriving_structural<'a>(
             StructOrEnum::Enum => None,
         };
 
+        // Structs with `#[struc(pin)]` fields require the `PinField` impls,
+        // so that they can be pin projected through the `<deriving_type>_SI` trait.
+        let supertraits = if options.has_pinned_fields {
+            pin_projection::pin_field_supertraits(ds, options)
+        } else {
+            Punctuated::new()
+        };
+
+        // The fields delegated with `#[struc(delegate_fields(...))]` are included in the
+        // `<deriving_type>_SI` trait,with the access of the field they're delegated to.
+        let si_datatype;
+        let si_datatype = if options.has_delegated_fields {
+            let delegated_fields = delegation::delegated_structural_fields(ds, options, _arenas);
+            si_datatype = StructuralDataType {
+                type_name: sdt.type_name,
+                fields: sdt.fields.iter().cloned().chain(delegated_fields).collect(),
+                variants: Vec::new(),
+            };
+            check_no_repeated_names(si_datatype.fields.iter().map(|f| f.ident), "field")?;
+            &si_datatype
+        } else {
+            &sdt
+        };
+
         let sop = StructuralAliasParams {
             span: tyname.span(),
             attrs: None::<&Ident>,
@@ -339,11 +404,12 @@ fn deriving_structural<'a>(
             ident: &trait_ident,
             generics: ds.generics,
             extra_where_preds: &options.bounds,
-            supertraits: &Punctuated::new(),
+            supertraits: &supertraits,
             trait_items: &[],
             variant_trait: struct_variant_trait.as_ref(),
             enum_exhaustiveness,
-            datatype: &sdt,
+            datatype: si_datatype,
+            assoc_types: *assoc_types,
         };
 
         structural_alias_trait.append_all(sop.tokens()?);
@@ -394,13 +460,32 @@ fn deriving_structural<'a>(
 
             let indices = (0..).map(expr_from_int);
 
+            // The fields that delegate by-value access are dropped with `DropFields`.
             let not_public_field_names = struct_
                 .fields
                 .iter()
                 .filter(|&f| !config_fields[f].is_pub)
+                .filter(|&f| !moved_bits.iter().any(|x| std::ptr::eq(x.field, f)))
                 .map(|f| &f.ident);
 
-            let field_names = fields.iter().map(|f| &f.ident);
+            let delegated_to = moved_bits.iter().map(|x| {
+                let ident = &x.field.ident;
+                let start = expr_from_int(u64::from(x.start));
+                let len = expr_from_int(u64::from(x.len));
+                quote!((#ident, #start, #len))
+            });
+
+            // The fields of a remote type are accessed through the newtype wrapping it.
+            let field_prefix = match &options.remote {
+                Some(RemoteConfig {
+                    newtype: Some(_), ..
+                }) => quote!(0 .),
+                _ => quote!(),
+            };
+            let field_names = fields.iter().map(|f| {
+                let ident = &f.ident;
+                quote!(#field_prefix #ident)
+            });
 
             let field_name_tstrs = sdt.fields.iter().map(|f| f.ident.tstr_tokens());
 
@@ -421,6 +506,7 @@ fn deriving_structural<'a>(
                     DropFields{
                         #drop_fields_arg
                         not_public( #(#not_public_field_names)* )
+                        delegated_to( #(#delegated_to)* )
                     }
 
                     #((
@@ -478,14 +564,18 @@ fn deriving_structural<'a>(
                             quote!((#ident = #var_ident))
                         });
 
+                    let field_name_strs = sdt_variant.fields.iter().map(|f| f.ident.name_string());
+
                     let variant_name = variant.name;
                     let variant_str = sdt_variant.name.tokens();
+                    let variant_name_str = sdt_variant.name.name_string();
                     quote!(
                         #variant_name,
                         #variant_str,
                         kind=#variant_kind,
                         not_public( #(#not_public)* ),
-                        fields( #( (#field_tokens) )* )
+                        fields( #( (#field_tokens) )* ),
+                        names( #variant_name_str, fields( #(#field_name_strs),* ) ),
                     )
                 });
 
@@ -538,6 +628,60 @@ fn deriving_structural<'a>(
         None => TokenStream2::new(),
     };
 
+    let validate_tokens = if options.has_validators {
+        validate::deriving_validate(ds, options)
+    } else {
+        TokenStream2::new()
+    };
+
+    let delegated_fields_tokens = if options.has_delegated_fields {
+        delegation::deriving_delegated_fields(ds, options, &moved_bits)
+    } else {
+        TokenStream2::new()
+    };
+
+    let soa_tokens = if options.soa {
+        soa::deriving_soa(ds, options)
+    } else {
+        TokenStream2::new()
+    };
+
+    let from_str_map_tokens = if options.from_str_map {
+        str_map::deriving_from_str_map(ds, options)?
+    } else {
+        TokenStream2::new()
+    };
+
+    let builder_tokens = if options.builder {
+        builder::deriving_builder(ds, options)?
+    } else {
+        TokenStream2::new()
+    };
+
+    let all_fields_tokens = match (struct_or_enum, &options.remote) {
+        (StructOrEnum::Struct, None) => all_fields::deriving_all_fields(ds, options),
+        _ => TokenStream2::new(),
+    };
+
+    let field_offset_tokens = match (struct_or_enum, &options.remote) {
+        (StructOrEnum::Struct, None) => field_offset::deriving_field_offsets(ds, options),
+        _ => TokenStream2::new(),
+    };
+
+    let (remote_tokens, self_ty) = match &options.remote {
+        Some(remote) => (
+            remote::deriving_remote(ds, options, remote),
+            remote::remote_self_type(ds, remote),
+        ),
+        None => (TokenStream2::new(), quote!(#tyname #ty_generics)),
+    };
+
+    let pin_projection_tokens = if options.has_pinned_fields {
+        pin_projection::deriving_pin_projection(ds, options)
+    } else {
+        TokenStream2::new()
+    };
+
     let mut impl_docs = String::new();
     if options.generate_docs {
         write_docs::write_datatype_docs(&mut impl_docs, DocsFor::Type, &sdt)?;
@@ -549,13 +693,31 @@ fn deriving_structural<'a>(
     quote!(
         #from_structural_tokens
 
+        #validate_tokens
+
+        #pin_projection_tokens
+
+        #all_fields_tokens
+
+        #field_offset_tokens
+
+        #builder_tokens
+
+        #soa_tokens
+
+        #from_str_map_tokens
+
+        #delegated_fields_tokens
+
+        #remote_tokens
+
         #structural_alias_trait
 
         #soe_specific_out
 
         ::structural::#which_macro!{
             #[doc=#impl_docs]
-            impl[#impl_generics] #tyname #ty_generics
+            impl[#impl_generics] #self_ty
             where[
                 #(#where_preds,)*
                 #(#extra_where_preds,)*
@@ -565,3 +727,26 @@ fn deriving_structural<'a>(
     )
     .piped(Ok)
 }
+
+/// Errors when two fields (or variants) have the same name after being renamed.
+fn check_no_repeated_names<'a, I>(names: I, kind: &str) -> Result<(), syn::Error>
+where
+    I: IntoIterator<Item = IdentType<'a>>,
+{
+    let mut set = std::collections::HashSet::new();
+    for name in names {
+        if let IdentType::Ident(ident) = name {
+            let string = ident.to_string();
+            if !set.insert(string) {
+                return_syn_err!(
+                    ident.span(),
+                    "There are multiple {0}s named `{1}`,\n\
+                     consider renaming one of them with the `#[struc(rename=\"...\")]` attribute.",
+                    kind,
+                    ident.display(),
+                )
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use super::attribute_parsing::StructuralOptions;
+
+use as_derive_utils::{
+    datastructure::DataStructure,
+    gen_params_in::{GenParamsIn, InWhat},
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::quote;
+
+use syn::punctuated::Punctuated;
+
+/// Generates the `AllFields` impl,
+/// if every field of the struct has mutable accessor impls.
+pub(crate) fn deriving_all_fields<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+) -> TokenStream2 {
+    let struct_ = &ds.variants[0];
+
+    let all_mutable = struct_.fields.iter().all(|field| {
+        let f_options = &options.fields[field];
+        f_options.is_pub && f_options.access.has_mutable_access()
+    });
+    if !all_mutable {
+        return TokenStream2::new();
+    }
+
+    let impl_generics = GenParamsIn::new(ds.generics, InWhat::ImplHeader);
+
+    let tyname = ds.name;
+    let (_, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let empty_preds = Punctuated::new();
+    let where_preds = where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates)
+        .iter();
+    let extra_where_preds = options.bounds.iter();
+
+    let names = struct_
+        .fields
+        .iter()
+        .rev()
+        .fold(quote!(::structural::pmr::TNil), |rem, field| {
+            let f_tstr = options.fields[field].renamed_ident().tstr_tokens();
+            quote!(::structural::pmr::TList<#f_tstr, #rem>)
+        });
+
+    quote!(
+        unsafe impl<#impl_generics> ::structural::pmr::AllFields for #tyname #ty_generics
+        where
+            #(#where_preds,)*
+            #(#extra_where_preds,)*
+        {
+            type Names = #names;
+        }
+    )
+}
@@ -34,8 +34,36 @@ pub(crate) struct FieldConfig<'a> {
     /// `true`: means that the field gets an accessor.
     pub(crate) is_pub: bool,
 
-    /// How to initialize the field in the FromStructural implementation.
+    /// How to initialize the field in the FromStructural implementation,
+    /// and the default value of the field in the builder.
     pub(crate) init_with: Option<InitWith>,
+
+    /// The function that validates the field in the generated `Validate` impl.
+    pub(crate) validate: Option<syn::Path>,
+
+    /// Whether the field is structurally pinned,with the `#[struc(pin)]` attribute.
+    pub(crate) pinned: Option<proc_macro2::Span>,
+
+    /// The fields whose accessor impls are delegated to this field,
+    /// with the `#[struc(delegate_fields(...))]` attribute.
+    pub(crate) delegated_fields: Vec<IdentOrIndex>,
+
+    /// Whether the field is loaded like the values that `str_map::from_str_map` loads,
+    /// with the `#[struc(str_map_nested)]` attribute.
+    pub(crate) str_map_nested: Option<proc_macro2::Span>,
+}
+
+/// The `#[struc(remote = "...")]` attribute,
+/// for implementing the accessor traits for a type defined elsewhere.
+#[derive(Debug)]
+pub(crate) struct RemoteConfig {
+    /// The path to the remote type,without generic arguments.
+    pub(crate) path: syn::Path,
+    /// The name of the newtype wrapper that the accessor traits are implemented for,
+    /// with the `#[struc(newtype = "...")]` attribute.
+    ///
+    /// If this is `None`,the accessor traits are implemented for the remote type itself.
+    pub(crate) newtype: Option<syn::Ident>,
 }
 
 #[derive(Debug, Clone, Default)]
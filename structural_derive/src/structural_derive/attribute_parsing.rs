@@ -6,7 +6,7 @@ use crate::{
 };
 
 use super::{
-    attribute_config::{DropParams, FieldConfig, VariantConfig},
+    attribute_config::{DropParams, FieldConfig, RemoteConfig, VariantConfig},
     delegation::{DelegateTo, RawMutImplParam},
     from_structural::{FromStructuralConfig, InitWith},
 };
@@ -42,31 +42,229 @@ pub(crate) struct StructuralOptions<'a> {
     pub(crate) drop_params: DropParams,
     pub(crate) debug_print: bool,
     pub(crate) with_trait_alias: bool,
+    pub(crate) assoc_types: bool,
     pub(crate) generate_docs: bool,
     pub(crate) non_exhaustive_attr: bool,
     pub(crate) delegate_to: Option<DelegateTo<'a>>,
+    pub(crate) validate: Option<syn::Path>,
+    /// Whether the `#[struc(validate = "...")]` attribute was used anywhere.
+    pub(crate) has_validators: bool,
+    /// Whether the `#[struc(pin)]` attribute was used on any field.
+    pub(crate) has_pinned_fields: bool,
+    /// Whether the `#[struc(builder)]` attribute was used.
+    pub(crate) builder: bool,
+    /// Whether the `#[struc(delegate_fields(...))]` attribute was used on any field.
+    pub(crate) has_delegated_fields: bool,
+    /// Whether the `#[struc(soa)]` attribute was used.
+    pub(crate) soa: bool,
+    /// Whether the `#[struc(from_str_map)]` attribute was used.
+    pub(crate) from_str_map: bool,
+    pub(crate) remote: Option<RemoteConfig>,
 
     _marker: PhantomData<&'a ()>,
 }
 
 impl<'a> StructuralOptions<'a> {
-    fn new(_ds: &'a DataStructure<'a>, this: StructuralAttrs<'a>) -> Result<Self, syn::Error> {
+    fn new(ds: &'a DataStructure<'a>, this: StructuralAttrs<'a>) -> Result<Self, syn::Error> {
         let StructuralAttrs {
             from_struc,
             variants,
-            fields,
+            mut fields,
             make_variant_count_alias,
             bounds,
             drop_params,
             debug_print,
             with_trait_alias,
+            assoc_types,
             generate_docs,
             non_exhaustive_attr,
             delegate_to,
+            validate,
+            builder,
+            soa,
+            from_str_map,
+            remote,
+            remote_newtype,
             errors: _,
             _marker,
         } = this;
 
+        let first_validator = validate
+            .as_ref()
+            .or_else(|| fields.iter().filter_map(|(_, f)| f.validate.as_ref()).next());
+
+        if let Some(validator) = first_validator {
+            if ds.data_variant == DataVariant::Enum {
+                return_spanned_err!(
+                    validator,
+                    "Can only use the `#[struc(validate = \"...\")]` attribute on structs"
+                )
+            }
+            if delegate_to.is_some() {
+                return_spanned_err!(
+                    validator,
+                    "Cannot use the `#[struc(validate = \"...\")]` attribute \
+                     in combination with the `#[struc(delegate_to)]` attribute"
+                )
+            }
+        }
+        let has_validators = first_validator.is_some();
+
+        let first_pinned = fields.iter().filter_map(|(_, f)| f.pinned).next();
+        if let Some(span) = first_pinned {
+            if ds.data_variant == DataVariant::Enum {
+                return_syn_err!(span, "Can only use the `#[struc(pin)]` attribute on structs")
+            }
+            if delegate_to.is_some() {
+                return_syn_err!(
+                    span,
+                    "Cannot use the `#[struc(pin)]` attribute \
+                     in combination with the `#[struc(delegate_to)]` attribute"
+                )
+            }
+        }
+        let has_pinned_fields = first_pinned.is_some();
+
+        if let Some(span) = builder {
+            if ds.data_variant == DataVariant::Enum {
+                return_syn_err!(span, "Can only use the `#[struc(builder)]` attribute on structs")
+            }
+            if delegate_to.is_some() {
+                return_syn_err!(
+                    span,
+                    "Cannot use the `#[struc(builder)]` attribute \
+                     in combination with the `#[struc(delegate_to)]` attribute"
+                )
+            }
+            if cfg!(all(feature = "use_const_str", not(feature = "disable_const_str"))) {
+                return_syn_err!(
+                    span,
+                    "Cannot use the `#[struc(builder)]` attribute \
+                     when the `use_const_str` feature is enabled,\
+                     because it requires type-level comparison of field names."
+                )
+            }
+        }
+
+        if let Some(span) = soa {
+            if ds.data_variant == DataVariant::Enum {
+                return_syn_err!(span, "Can only use the `#[struc(soa)]` attribute on structs")
+            }
+            if delegate_to.is_some() {
+                return_syn_err!(
+                    span,
+                    "Cannot use the `#[struc(soa)]` attribute \
+                     in combination with the `#[struc(delegate_to)]` attribute"
+                )
+            }
+            // The fields are moved into the columns with `StructuralExt::into_fields`.
+            let no_by_value = fields
+                .iter()
+                .find(|(_, f)| f.is_pub && !f.access.has_by_value_access());
+            if let Some((_, field)) = no_by_value {
+                return_spanned_err!(
+                    field.field.ident,
+                    "All the fields with accessors need by-value access \
+                     to use the `#[struc(soa)]` attribute",
+                )
+            }
+        }
+
+        if let Some(span) = from_str_map {
+            if ds.data_variant == DataVariant::Enum {
+                return_syn_err!(
+                    span,
+                    "Can only use the `#[struc(from_str_map)]` attribute on structs"
+                )
+            }
+            if delegate_to.is_some() {
+                return_syn_err!(
+                    span,
+                    "Cannot use the `#[struc(from_str_map)]` attribute \
+                     in combination with the `#[struc(delegate_to)]` attribute"
+                )
+            }
+        }
+
+        let first_delegated = fields
+            .iter()
+            .filter_map(|(_, f)| f.delegated_fields.first())
+            .next();
+        if let Some(name) = first_delegated {
+            if ds.data_variant == DataVariant::Enum {
+                return_syn_err!(
+                    name.span(),
+                    "Can only use the `#[struc(delegate_fields(...))]` attribute on structs"
+                )
+            }
+            if delegate_to.is_some() {
+                return_syn_err!(
+                    name.span(),
+                    "Cannot use the `#[struc(delegate_fields(...))]` attribute \
+                     in combination with the `#[struc(delegate_to)]` attribute"
+                )
+            }
+        }
+        let has_delegated_fields = first_delegated.is_some();
+
+        // Fields that other fields are delegated to can't be accessed directly,
+        // since that would allow aliasing mutable references to the delegated fields.
+        for (_, field) in fields.iter_mut() {
+            if !field.delegated_fields.is_empty() {
+                field.is_pub = false;
+            }
+        }
+
+        let remote = match (remote, remote_newtype) {
+            (Some((span, path)), newtype) => {
+                if ds.data_variant == DataVariant::Enum {
+                    return_syn_err!(
+                        span,
+                        "Can only use the `#[struc(remote = \"...\")]` attribute on structs"
+                    )
+                }
+
+                let incompatible = [
+                    (delegate_to.is_some(), "delegate_to"),
+                    (from_struc.is_some(), "from_structural"),
+                    (builder.is_some(), "builder"),
+                    (soa.is_some(), "soa"),
+                    (from_str_map.is_some(), "from_str_map"),
+                    (has_validators, "validate"),
+                    (has_pinned_fields, "pin"),
+                    (has_delegated_fields, "delegate_fields"),
+                ];
+                if let Some((_, attr)) = incompatible.iter().find(|(used, _)| *used) {
+                    return_syn_err!(
+                        span,
+                        "Cannot use the `#[struc({})]` attribute \
+                         in combination with the `#[struc(remote = \"...\")]` attribute",
+                        attr,
+                    )
+                }
+
+                // The newtype can't drop the fields that it has no accessors for
+                // after a field is moved out.
+                if newtype.is_some() {
+                    if let Some((_, field)) = fields.iter().find(|(_, f)| !f.is_pub) {
+                        return_spanned_err!(
+                            field.field.ident,
+                            "All fields need accessors when using the \
+                             `#[struc(newtype = \"...\")]` attribute",
+                        )
+                    }
+                }
+
+                Some(RemoteConfig { path, newtype })
+            }
+            (None, Some(newtype)) => return_spanned_err!(
+                newtype,
+                "Cannot use the `#[struc(newtype = \"...\")]` attribute \
+                 without the `#[struc(remote = \"...\")]` attribute",
+            ),
+            (None, None) => None,
+        };
+
         let make_variant_count_alias = match (make_variant_count_alias, non_exhaustive_attr) {
             (Some(span), true) => return_syn_err!(
                 span,
@@ -76,8 +274,22 @@ impl<'a> StructuralOptions<'a> {
             (x, _) => x.is_some(),
         };
 
+        let assoc_types = match (assoc_types, with_trait_alias) {
+            (Some(span), false) => return_syn_err!(
+                span,
+                "Cannot use the `#[struc(assoc_types)]` attribute when \
+                 no trait alias is being generated."
+            ),
+            (x, _) => x.is_some(),
+        };
+
         Ok(Self {
-            from_struc,
+            // Values loaded from string maps are constructed with `TryFromStructural`.
+            from_struc: from_struc.or_else(|| {
+                from_str_map.map(|_| FromStructuralConfig {
+                    bounds: bounds.clone(),
+                })
+            }),
             variants,
             fields,
             make_variant_count_alias,
@@ -85,9 +297,18 @@ impl<'a> StructuralOptions<'a> {
             drop_params,
             debug_print,
             with_trait_alias,
+            assoc_types,
             generate_docs,
             non_exhaustive_attr,
             delegate_to,
+            validate,
+            has_validators,
+            has_pinned_fields,
+            builder: builder.is_some(),
+            has_delegated_fields,
+            soa: soa.is_some(),
+            from_str_map: from_str_map.is_some(),
+            remote,
             _marker,
         })
     }
@@ -106,6 +327,7 @@ struct StructuralAttrs<'a> {
 
     debug_print: bool,
     with_trait_alias: bool,
+    assoc_types: Option<Span>,
     generate_docs: bool,
 
     /// Whether the built-in `#[non_exhaustive]` attribute was used.
@@ -113,6 +335,17 @@ struct StructuralAttrs<'a> {
 
     delegate_to: Option<DelegateTo<'a>>,
 
+    validate: Option<syn::Path>,
+
+    builder: Option<Span>,
+
+    soa: Option<Span>,
+
+    from_str_map: Option<Span>,
+
+    remote: Option<(Span, syn::Path)>,
+    remote_newtype: Option<Ident>,
+
     errors: LinearResult<()>,
 
     _marker: PhantomData<&'a ()>,
@@ -148,15 +381,26 @@ pub(crate) fn parse_attrs_for_structural<'a>(
             init_with: None,
             is_impl: None,
             is_pub: field.is_public() || ds.data_variant == DataVariant::Enum,
+            validate: None,
+            pinned: None,
+            delegated_fields: Vec::new(),
+            str_map_nested: None,
         }),
         make_variant_count_alias: None,
         bounds: Punctuated::new(),
         drop_params: DropParams::default(),
         debug_print: false,
         with_trait_alias: true,
+        assoc_types: None,
         generate_docs: matches!(syn::Visibility::Public{..} = ds.vis),
         non_exhaustive_attr: false,
         delegate_to: None,
+        validate: None,
+        builder: None,
+        soa: None,
+        from_str_map: None,
+        remote: None,
+        remote_newtype: None,
         errors: LinearResult::ok(()),
         _marker: PhantomData,
     };
@@ -257,16 +501,7 @@ fn parse_sabi_attr<'a>(
                 fa.access = access;
                 fa.is_pub = true;
             } else if path.is_ident("impl") {
-                if !cfg!(feature = "impl_fields") {
-                    return_spanned_err! {
-                        path,
-                        "\
-                            Cannot use the `#[struc(impl=\"Trait\")]` \
-                            attribute without enabling the \
-                            \"nightly_impl_fields\" or \"impl_fields\" feature.\
-                        ",
-                    }
-                } else if !this.with_trait_alias {
+                if !this.with_trait_alias {
                     return Err(trait_alias_err(&path));
                 }
                 let bounds: TypeParamBounds = value.parse::<ParsePunctuated<_, _>>()?.list;
@@ -277,6 +512,8 @@ fn parse_sabi_attr<'a>(
                 set_init_with(this, InitWith::Val(value.parse()?), field, &path)?;
             } else if path.is_ident("init_with_lit") {
                 set_init_with(this, InitWith::Lit(Lit::Str(value)), field, &path)?;
+            } else if path.is_ident("validate") {
+                this.fields[field].validate = Some(value.parse::<syn::Path>()?);
             } else {
                 return Err(make_err(&path));
             }
@@ -297,6 +534,17 @@ fn parse_sabi_attr<'a>(
                 parse_delegate_to(this, Default::default(), path.span(), field)?;
             } else if path.is_ident("init_with_default") {
                 set_init_with(this, InitWith::Default, field, &path)?;
+            } else if path.is_ident("pin") {
+                this.fields[field].pinned = Some(path.span());
+            } else if path.is_ident("str_map_nested") {
+                if this.from_str_map.is_none() {
+                    return_spanned_err!(
+                        path,
+                        "Cannot use the `#[struc(str_map_nested)]` attribute \
+                         without the `#[struc(from_str_map)]` attribute"
+                    )
+                }
+                this.fields[field].str_map_nested = Some(path.span());
             } else {
                 return Err(make_err(&path));
             }
@@ -304,6 +552,11 @@ fn parse_sabi_attr<'a>(
         (ParseContext::Field { field, .. }, Meta::List(MetaList { path, nested, .. })) => {
             if path.is_ident("delegate_to") {
                 parse_delegate_to(this, nested, path.span(), field)?;
+            } else if path.is_ident("delegate_fields") {
+                let names = syn::parse2::<ParsePunctuated<IdentOrIndex, syn::Token!(,)>>(
+                    nested.into_token_stream(),
+                )?;
+                this.fields[field].delegated_fields.extend(names.list);
             } else {
                 return Err(make_err(&path));
             }
@@ -365,6 +618,8 @@ fn parse_sabi_attr<'a>(
                 this.debug_print = true;
             } else if path.is_ident("no_trait") {
                 this.with_trait_alias = false;
+            } else if path.is_ident("assoc_types") {
+                this.assoc_types = Some(path.span());
             } else if path.is_ident("no_docs") {
                 this.generate_docs = false;
             } else if path.is_ident("public") {
@@ -387,6 +642,12 @@ fn parse_sabi_attr<'a>(
                 }
             } else if path.is_ident("from_structural") {
                 this.from_struc = Some(parse_from_struc(Punctuated::new())?);
+            } else if path.is_ident("builder") {
+                this.builder = Some(path.span());
+            } else if path.is_ident("soa") {
+                this.soa = Some(path.span());
+            } else if path.is_ident("from_str_map") {
+                this.from_str_map = Some(path.span());
             } else {
                 return Err(make_err(&path));
             }
@@ -423,6 +684,18 @@ fn parse_sabi_attr<'a>(
                     )
                 }
                 this.drop_params.pre_move = Some(unparsed_lit.parse::<syn::Path>()?);
+            } else if ident == "validate" {
+                if this.validate.is_some() {
+                    return_spanned_err!(
+                        ident,
+                        "Cannot use the `#[struc(validate = \"...\")]` attribute twice"
+                    )
+                }
+                this.validate = Some(unparsed_lit.parse::<syn::Path>()?);
+            } else if ident == "remote" {
+                this.remote = Some((ident.span(), unparsed_lit.parse::<syn::Path>()?));
+            } else if ident == "newtype" {
+                this.remote_newtype = Some(unparsed_lit.parse::<Ident>()?);
             } else {
                 return Err(make_err(path));
             }
@@ -438,11 +711,13 @@ fn set_init_with<'a>(
     field: &'a Field<'a>,
     tokens: &dyn ToTokens,
 ) -> Result<(), syn::Error> {
-    if this.from_struc.is_none() {
+    if this.from_struc.is_none() && this.builder.is_none() && this.from_str_map.is_none() {
         return_spanned_err!(
             tokens,
-            "Cannot use this attribute without deriving FromStructural.\n\
+            "Cannot use this attribute without deriving FromStructural or a builder.\n\
              You can use the `#[struc(from_structural)]` attribute to derive FromStructural.\n\
+             You can use the `#[struc(builder)]` attribute to derive a builder.\n\
+             The `#[struc(from_str_map)]` attribute also derives FromStructural.\n\
             "
         )
     }
@@ -0,0 +1,284 @@
+use super::attribute_parsing::StructuralOptions;
+
+use as_derive_utils::{
+    datastructure::{DataStructure, FieldIdent},
+    gen_params_in::{GenParamsIn, InWhat},
+    spanned_err,
+    utils::{LinearResult, SynResultExt},
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::quote;
+
+use syn::{punctuated::Punctuated, Ident};
+
+/// The methods of the builder that setter methods can't be named after.
+const BUILDER_METHODS: &[&str] = &["new", "default", "field", "build"];
+
+/// Generates the `<DerivingType>Builder` type-state builder,
+/// for the `#[struc(builder)]` attribute.
+pub(crate) fn deriving_builder<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+) -> Result<TokenStream2, syn::Error> {
+    let struct_ = &ds.variants[0];
+
+    let mut res = LinearResult::ok(());
+
+    for field in &struct_.fields {
+        let f_options = &options.fields[field];
+        if let FieldIdent::Named(f_ident) = &field.ident {
+            let f_name = f_ident.to_string();
+            let f_name = f_name.trim_start_matches("r#");
+            if f_options.is_pub && BUILDER_METHODS.contains(&f_name) {
+                res.push_err(spanned_err!(
+                    f_ident,
+                    "Public fields named `{}` conflict with the `{}` method of the builder.\n\
+                     The builder methods are: {}",
+                    f_name,
+                    f_name,
+                    BUILDER_METHODS.join(", "),
+                ));
+            }
+        }
+        if !f_options.is_pub && f_options.init_with.is_none() {
+            res.push_err(spanned_err!(
+                field.ident(),
+                "Private fields must have an explicit initialization attribute \
+                 to derive a builder.\n\
+                 Eg: `#[struc(init_with_default)]`\n\
+                 Eg: `#[struc(init_with_lit = 0)]`\n\
+                "
+            ));
+        }
+    }
+
+    res.take()?;
+
+    let vis = ds.vis;
+    let tyname = ds.name;
+    let builder_name = Ident::new(&format!("{}Builder", tyname), tyname.span());
+
+    let decl_generics = GenParamsIn::with_after_types(
+        ds.generics,
+        InWhat::ItemDecl,
+        quote!(__Struc_Set = ::structural::pmr::TNil,),
+    );
+    let impl_generics = GenParamsIn::new(ds.generics, InWhat::ImplHeader);
+    let impl_generics_set =
+        GenParamsIn::with_after_types(ds.generics, InWhat::ImplHeader, quote!(__Struc_Set,));
+    let empty_set_generics = GenParamsIn::with_after_types(
+        ds.generics,
+        InWhat::ItemUse,
+        quote!(::structural::pmr::TNil,),
+    );
+    let set_generics =
+        GenParamsIn::with_after_types(ds.generics, InWhat::ItemUse, quote!(__Struc_Set,));
+
+    // The generic arguments of the builder after setting a field.
+    let output_generics = |f_tstr: &TokenStream2| {
+        GenParamsIn::with_after_types(
+            ds.generics,
+            InWhat::ItemUse,
+            quote!(::structural::pmr::TList<#f_tstr, __Struc_Set>,),
+        )
+    };
+
+    let (_, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let empty_preds = Punctuated::new();
+    let where_preds = where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates);
+    let where_preds = where_preds.iter();
+    let where_preds = quote!( #(#where_preds,)* );
+
+    // The fields that can be set with the builder.
+    let settable = struct_
+        .fields
+        .iter()
+        .filter(|field| options.fields[*field].is_pub)
+        .collect::<Vec<_>>();
+
+    let settable_names = settable.iter().map(|f| f.ident()).collect::<Vec<_>>();
+    let settable_tys = settable.iter().map(|f| f.ty).collect::<Vec<_>>();
+
+    let builder_docs = format!(
+        "A type-state builder for [`{0}`](./struct.{0}.html),\
+         generated by the `#[struc(builder)]` attribute.\n\n\
+         The `__Struc_Set` type parameter is a type-level list of the names \
+         of the fields that were set.",
+        tyname,
+    );
+
+    let set_field_impls = settable.iter().map(|&field| {
+        let f_tstr = options.fields[field].renamed_ident().tstr_tokens();
+        let f_ident = field.ident();
+        let f_ty = field.ty;
+        let output_generics = output_generics(&f_tstr);
+        let others = settable_names.iter().filter(|&&x| x != f_ident);
+        quote!(
+            impl<#impl_generics_set> ::structural::builder::BuilderField<#f_tstr>
+            for #builder_name<#set_generics>
+            where
+                #where_preds
+            {
+                type Ty = #f_ty;
+                type Output = #builder_name<#output_generics>;
+
+                #[inline]
+                fn set_field(self, _: #f_tstr, value: #f_ty) -> Self::Output {
+                    #builder_name {
+                        #f_ident: ::structural::pmr::Some(value),
+                        #( #others: self.#others, )*
+                        __struc_set: ::structural::pmr::PhantomData,
+                    }
+                }
+            }
+        )
+    });
+
+    let setter_methods = settable.iter().filter_map(|&field| {
+        let f_ident = match &field.ident {
+            FieldIdent::Named(x) => *x,
+            FieldIdent::Index(..) => return None,
+        };
+        let f_tstr = options.fields[field].renamed_ident().tstr_tokens();
+        let f_ty = field.ty;
+        let output_generics = output_generics(&f_tstr);
+        let docs = format!(
+            "Sets the `{}` field.",
+            options.fields[field].renamed_ident().to_string()
+        );
+        Some(quote!(
+            #[doc = #docs]
+            #[inline]
+            #vis fn #f_ident(
+                self,
+                value: #f_ty,
+            ) -> #builder_name<#output_generics> {
+                ::structural::builder::BuilderField::set_field(
+                    self,
+                    <#f_tstr as ::structural::pmr::ConstDefault>::DEFAULT,
+                    value,
+                )
+            }
+        ))
+    });
+
+    let required_tstrs = struct_
+        .fields
+        .iter()
+        .filter(|f| options.fields[*f].is_pub && options.fields[*f].init_with.is_none())
+        .map(|f| options.fields[f].renamed_ident().tstr_tokens());
+
+    let field_inits = struct_.fields.iter().map(|field| {
+        let f_options = &options.fields[field];
+        let ident = &field.ident;
+        let f_ident = field.ident();
+        match (f_options.is_pub, &f_options.init_with) {
+            (true, None) => {
+                let unset_msg = format!(
+                    "The `build` method requires the `{}` field to be set",
+                    f_options.renamed_ident().to_string()
+                );
+                quote!(
+                #ident: match self.#f_ident {
+                    ::structural::pmr::Some(x) => x,
+                    ::structural::pmr::None => {
+                        unreachable!("{}", #unset_msg)
+                    }
+                },
+                )
+            }
+            (true, Some(init)) => quote!(
+                #ident: match self.#f_ident {
+                    ::structural::pmr::Some(x) => x,
+                    ::structural::pmr::None => #init,
+                },
+            ),
+            (false, Some(init)) => quote!( #ident: #init, ),
+            (false, None) => {
+                unreachable!("private fields without an `init_*` attribute are errors")
+            }
+        }
+    });
+
+    let extra_where_preds = options.bounds.iter();
+
+    Ok(quote!(
+        #[doc = #builder_docs]
+        #vis struct #builder_name<#decl_generics>
+        where
+            #where_preds
+        {
+            #( #settable_names: ::structural::pmr::Option<#settable_tys>, )*
+            __struc_set: ::structural::pmr::PhantomData<
+                fn() -> (#tyname #ty_generics, __Struc_Set)
+            >,
+        }
+
+        impl<#impl_generics> #builder_name<#empty_set_generics>
+        where
+            #where_preds
+        {
+            /// Constructs this builder,with no fields set.
+            #[inline]
+            #vis fn new() -> Self {
+                #builder_name {
+                    #( #settable_names: ::structural::pmr::None, )*
+                    __struc_set: ::structural::pmr::PhantomData,
+                }
+            }
+        }
+
+        impl<#impl_generics> ::structural::pmr::Default for #builder_name<#empty_set_generics>
+        where
+            #where_preds
+        {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        #(#set_field_impls)*
+
+        impl<#impl_generics_set> #builder_name<#set_generics>
+        where
+            #where_preds
+        {
+            /// Sets the `name` field.
+            #[inline]
+            #vis fn field<__Struc_N>(
+                self,
+                name: __Struc_N,
+                value: <Self as ::structural::builder::BuilderField<__Struc_N>>::Ty,
+            ) -> <Self as ::structural::builder::BuilderField<__Struc_N>>::Output
+            where
+                Self: ::structural::builder::BuilderField<__Struc_N>,
+            {
+                ::structural::builder::BuilderField::set_field(self, name, value)
+            }
+
+            #(#setter_methods)*
+        }
+
+        impl<#impl_generics_set> #builder_name<#set_generics>
+        where
+            #where_preds
+            #(#extra_where_preds,)*
+            #( __Struc_Set: ::structural::pmr::Remove<#required_tstrs>, )*
+        {
+            /// Constructs the value,
+            /// only callable once every field without a default has been set.
+            #[inline]
+            #vis fn build(self) -> #tyname #ty_generics {
+                #tyname {
+                    #(#field_inits)*
+                }
+            }
+        }
+    ))
+}
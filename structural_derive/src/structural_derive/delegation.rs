@@ -1,4 +1,18 @@
-use as_derive_utils::datastructure::Field;
+use super::attribute_parsing::StructuralOptions;
+
+use super::STRUCTURAL_SIZE_LIMIT;
+
+use crate::{
+    arenas::Arenas,
+    structural_alias_impl_mod::{FieldType, IdentType, StructuralField},
+};
+
+use as_derive_utils::{
+    datastructure::{DataStructure, Field},
+    gen_params_in::{GenParamsIn, InWhat},
+    return_spanned_err,
+    utils::expr_from_int,
+};
 
 use proc_macro2::TokenStream as TokenStream2;
 
@@ -38,3 +52,130 @@ impl ToTokens for RawMutImplParam {
         <syn::Token!(;)>::default().to_tokens(ts);
     }
 }
+
+/// A field with a `#[struc(delegate_fields(...))]` attribute that delegates by-value access,
+/// with the range of bits in `MovedOutFields` used to track the fields moved out of it.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct DelegatedMovedBits<'a> {
+    pub(crate) field: &'a Field<'a>,
+    pub(crate) start: u8,
+    pub(crate) len: u8,
+}
+
+/// Splits the bits in `MovedOutFields` that aren't used by the `own_fields`
+/// fields with accessors between the fields that delegate by-value access.
+pub(crate) fn delegated_moved_bits<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+    own_fields: usize,
+) -> Result<Vec<DelegatedMovedBits<'a>>, syn::Error> {
+    let delegating = ds.variants[0]
+        .fields
+        .iter()
+        .filter(|field| {
+            let f_options = &options.fields[*field];
+            !f_options.delegated_fields.is_empty() && f_options.access.has_by_value_access()
+        })
+        .collect::<Vec<_>>();
+
+    let first = match delegating.first() {
+        Some(x) => x,
+        None => return Ok(Vec::new()),
+    };
+
+    let len = (STRUCTURAL_SIZE_LIMIT - own_fields) / delegating.len();
+    if len == 0 {
+        return_spanned_err!(
+            first.ident(),
+            "There are not enough bits left to track the fields moved out of \
+             the fields with the `#[struc(delegate_fields(...))]` attribute.\n\
+             You can use `#[struc(access = \"mut\")]` on this field to not delegate by-value access."
+        )
+    }
+
+    Ok(delegating
+        .into_iter()
+        .enumerate()
+        .map(|(i, field)| DelegatedMovedBits {
+            field,
+            start: (own_fields + i * len) as u8,
+            len: len as u8,
+        })
+        .collect())
+}
+
+/// The fields delegated with `#[struc(delegate_fields(...))]` attributes,
+/// as fields of the `<deriving_type>_SI` trait,
+/// with the access of the field that they're delegated to.
+pub(crate) fn delegated_structural_fields<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+    arenas: &'a Arenas,
+) -> impl Iterator<Item = StructuralField<'a>> + 'a {
+    ds.variants[0].fields.iter().flat_map(move |field| {
+        let f_options = &options.fields[field];
+        let member_ty = field.ty;
+        f_options.delegated_fields.iter().map(move |name| {
+            let name_tstr = name.tstr_tokens();
+            let ty: syn::Type =
+                syn::parse_quote!(::structural::GetFieldType<#member_ty, #name_tstr>);
+            StructuralField {
+                access: f_options.access,
+                ident: IdentType::Ident(name.borrowed()),
+                pub_field_rename: None,
+                ty: FieldType::Ty(arenas.alloc(ty)),
+            }
+        })
+    })
+}
+
+/// Delegates the accessor impls of the fields listed in
+/// `#[struc(delegate_fields(...))]` attributes to the field that the attribute is on.
+pub(crate) fn deriving_delegated_fields<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+    moved_bits: &[DelegatedMovedBits<'a>],
+) -> TokenStream2 {
+    let struct_ = &ds.variants[0];
+
+    let impl_generics = GenParamsIn::new(ds.generics, InWhat::ImplHeader);
+
+    let tyname = ds.name;
+    let (_, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let empty_preds = Punctuated::new();
+    let where_preds = where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates)
+        .iter();
+    let extra_where_preds = options.bounds.iter();
+
+    let delegations = struct_
+        .fields
+        .iter()
+        .filter(|field| !options.fields[*field].delegated_fields.is_empty())
+        .map(|field| {
+            let names = &options.fields[field].delegated_fields;
+            let member = &field.ident;
+            let member_ty = field.ty;
+            let into_field = moved_bits
+                .iter()
+                .find(|x| std::ptr::eq(x.field, field))
+                .map(|x| {
+                    let start = expr_from_int(u64::from(x.start));
+                    let end = expr_from_int(u64::from(x.start) + u64::from(x.len));
+                    quote!(, IntoField(moved_bits = #start..#end))
+                });
+            quote!( delegate_fields(fp!( #(#names),* ) => #member: #member_ty #into_field); )
+        });
+
+    quote!(::structural::unsafe_delegate_structural_with! {
+        impl[#impl_generics] #tyname #ty_generics
+        where[
+            #(#where_preds,)*
+            #(#extra_where_preds,)*
+        ]
+
+        #(#delegations)*
+    })
+}
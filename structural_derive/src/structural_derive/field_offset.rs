@@ -0,0 +1,128 @@
+use super::attribute_parsing::StructuralOptions;
+
+use as_derive_utils::{
+    datastructure::DataStructure,
+    gen_params_in::{GenParamsIn, InWhat},
+};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+use quote::quote;
+
+use syn::{punctuated::Punctuated, Attribute, Ident, Meta, NestedMeta};
+
+/// Whether the attributes have a `#[repr(C)]` without a `#[repr(packed)]`,
+/// which is when the offsets of fields can be computed from their types.
+fn is_repr_c(attrs: &[Attribute]) -> bool {
+    let mut repr_c = false;
+    let mut packed = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list,
+            _ => continue,
+        };
+        for nested in &list.nested {
+            let path = match nested {
+                NestedMeta::Meta(Meta::Path(path)) => path,
+                NestedMeta::Meta(Meta::List(nested_list)) => &nested_list.path,
+                _ => continue,
+            };
+            if path.is_ident("C") {
+                repr_c = true;
+            } else if path.is_ident("packed") {
+                packed = true;
+            }
+        }
+    }
+
+    repr_c && !packed
+}
+
+/// Generates the `FieldOffset` impls for every field with accessor impls,
+/// if the struct is `#[repr(C)]`.
+pub(crate) fn deriving_field_offsets<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+) -> TokenStream2 {
+    if !is_repr_c(ds.attrs) {
+        return TokenStream2::new();
+    }
+
+    let struct_ = &ds.variants[0];
+
+    let impl_generics = GenParamsIn::new(ds.generics, InWhat::ImplHeader);
+
+    let tyname = ds.name;
+    let (_, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let empty_preds = Punctuated::new();
+    let where_preds = where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates);
+    let extra_where_preds = &options.bounds;
+
+    let offset_idents = (0..struct_.fields.len())
+        .map(|i| Ident::new(&format!("__STRUC_OFFSET_{}", i), Span::call_site()))
+        .collect::<Vec<Ident>>();
+
+    // The offsets of every field (including private ones),
+    // each one computed from the offset and size of the previous field.
+    let offset_consts = struct_.fields.iter().enumerate().map(|(i, field)| {
+        let offset_ident = &offset_idents[i];
+        if i == 0 {
+            quote!( const #offset_ident: usize = 0; )
+        } else {
+            let prev_ident = &offset_idents[i - 1];
+            let prev_ty = struct_.fields[i - 1].ty;
+            let ty = field.ty;
+            quote!(
+                const #offset_ident: usize =
+                    ::structural::pmr::next_field_offset::<#prev_ty, #ty>(Self::#prev_ident);
+            )
+        }
+    });
+
+    let field_offset_impls = struct_
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| options.fields[*field].is_pub)
+        .map(|(i, field)| {
+            let offset_ident = &offset_idents[i];
+            let f_tstr = options.fields[field].renamed_ident().tstr_tokens();
+            let ty = field.ty;
+            let where_preds = where_preds.iter();
+            let extra_where_preds = extra_where_preds.iter();
+            quote!(
+                unsafe impl<#impl_generics> ::structural::field::FieldOffset<#f_tstr>
+                for #tyname #ty_generics
+                where
+                    #(#where_preds,)*
+                    #(#extra_where_preds,)*
+                {
+                    type Ty = #ty;
+                    const OFFSET: usize = Self::#offset_ident;
+                }
+            )
+        });
+
+    let where_preds = where_preds.iter();
+    let extra_where_preds = extra_where_preds.iter();
+
+    quote!(
+        #[allow(dead_code)]
+        impl<#impl_generics> #tyname #ty_generics
+        where
+            #(#where_preds,)*
+            #(#extra_where_preds,)*
+        {
+            #(
+                #[doc(hidden)]
+                #offset_consts
+            )*
+        }
+
+        #(#field_offset_impls)*
+    )
+}
@@ -145,7 +145,9 @@ pub(crate) fn deriving_from_structural<'a>(
     res.take()?;
 
     let tokens = match ds.data_variant {
-        DataVariant::Struct => deriving_from_structural_struct(ds, &init_type_with[0], from_opts),
+        DataVariant::Struct => {
+            deriving_from_structural_struct(ds, options, &init_type_with[0], from_opts)
+        }
         DataVariant::Enum => deriving_from_structural_enum(ds, &init_type_with, from_opts),
         DataVariant::Union => unreachable!("unions can't derive FromStructural"),
     };
@@ -154,6 +156,7 @@ pub(crate) fn deriving_from_structural<'a>(
 
 fn deriving_from_structural_struct<'a>(
     ds: &DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
     fields_init: &FieldsInit<'a>,
     from_opts: &'a FromStructuralConfig,
 ) -> TokenStream2 {
@@ -180,37 +183,165 @@ fn deriving_from_structural_struct<'a>(
 
     let extra_bounds = from_opts.bounds.iter();
 
-    quote!(::structural::z_impl_from_structural! {
-        impl[#impl_generics] FromStructural<__Struc_From> for #tyname #ty_generics
-        where [
+    let moving_out = quote!(
+        let mut from = ::structural::field::ownership::IntoFieldsWrapper::new(from);
+
+        let (from, moved_out) = from.inner_and_moved_mut();
+
+        let (#(#from_fields,)*) = (
+            #(
+                ::structural::IntoField::move_out_field_(
+                    from,
+                    <#from_names_str_b>::NEW,
+                    moved_out,
+                ),
+            )*
+        );
+
+        Self{
+            #(#into_fields)*
+            #(#init_fields)*
+        }
+    );
+
+    // `str_map::from_str_map` converts the loaded fields without validating them,
+    // validating the value after the fields with `init_*` attributes are loaded.
+    let from_loaded = if options.from_str_map {
+        let where_preds = where_preds.clone();
+        let from_names_str_a = from_names_str_a.clone();
+        let from_tys = from_tys.clone();
+        let extra_bounds = extra_bounds.clone();
+        quote!(
+            impl<#impl_generics> ::structural::str_map::FromLoaded<__Struc_From>
+            for #tyname #ty_generics
+            where
+                #(#where_preds,)*
+                __Struc_From: ::structural::field::DropFields,
+                #(__Struc_From: ::structural::IntoField<#from_names_str_a, Ty = #from_tys>,)*
+                #(#extra_bounds,)*
+            {
+                fn from_loaded(from: __Struc_From) -> Self {
+                    unsafe{ #moving_out }
+                }
+            }
+        )
+    } else {
+        TokenStream2::new()
+    };
+
+    if !options.has_validators {
+        return quote!(
+            ::structural::z_impl_from_structural! {
+                impl[#impl_generics] FromStructural<__Struc_From> for #tyname #ty_generics
+                where [
+                    #(#where_preds,)*
+                    __Struc_From: ::structural::field::DropFields,
+                    #(__Struc_From: ::structural::IntoField<#from_names_str_a, Ty = #from_tys>,)*
+                    #(#extra_bounds,)*
+                ] {
+                    fn from_structural(from){
+                        unsafe{ #moving_out }
+                    }
+                }
+            }
+
+            #from_loaded
+        );
+    }
+
+    // Only the fields taken from the converted-from value are validated,
+    // before moving them out,so that it can be returned in the error.
+    let field_checks = fields_init.from.iter().filter_map(|ff| {
+        let f_options = &options.fields[ff.field];
+        f_options.validate.as_ref().map(|validator| {
+            let name_str = f_options.renamed_ident().to_string();
+            let accessor_name = &ff.accessor_name;
+            quote!(
+                errors.check_field(
+                    #name_str,
+                    #validator(::structural::GetField::get_field_(
+                        &from,
+                        <#accessor_name>::NEW,
+                    )),
+                );
+            )
+        })
+    });
+
+    // The container validator needs the converted value,
+    // which is constructed from clones of the fields,
+    // so that the converted-from value can be returned in the error without
+    // having moved out of it.
+    let (container_bounds, converting) = match &options.validate {
+        Some(validator) => {
+            let cloned_tys = fields_init.from.iter().map(|f| f.field.ty);
+            let cloned_names = fields_init.from.iter().map(|ff| &ff.accessor_name);
+            let cloned_fields = fields_init.from.iter().map(|ff| &ff.field.ident);
+            let init_fields_c = fields_init.init.iter();
+
+            let bounds = quote!(
+                #(#cloned_tys: ::structural::pmr::Clone,)*
+            );
+
+            let converting = quote!(
+                let this = Self{
+                    #(
+                        #cloned_fields: ::structural::pmr::Clone::clone(
+                            ::structural::GetField::get_field_(&from, <#cloned_names>::NEW)
+                        ),
+                    )*
+                    #(#init_fields_c)*
+                };
+
+                errors.check_container(#validator(&this));
+                if errors.is_empty() {
+                    ::structural::pmr::Ok(this)
+                } else {
+                    ::structural::pmr::Err(
+                        ::structural::pmr::TryFromError::new(from, errors)
+                    )
+                }
+            );
+            (bounds, converting)
+        }
+        None => (
+            TokenStream2::new(),
+            quote!( ::structural::pmr::Ok(unsafe{ #moving_out }) ),
+        ),
+    };
+
+    quote!(
+        impl<#impl_generics> ::structural::pmr::TryFromStructural<__Struc_From>
+        for #tyname #ty_generics
+        where
             #(#where_preds,)*
+            __Struc_From: ::structural::field::DropFields,
             #(__Struc_From: ::structural::IntoField<#from_names_str_a, Ty = #from_tys>,)*
+            #container_bounds
             #(#extra_bounds,)*
-        ] {
-            fn from_structural(from){
-                unsafe{
-                    let mut from = ::structural::field::ownership::IntoFieldsWrapper::new(from);
-
-                    let (from, moved_out) = from.inner_and_moved_mut();
-
-                    let (#(#from_fields,)*) = (
-                        #(
-                            ::structural::IntoField::move_out_field_(
-                                from,
-                                <#from_names_str_b>::NEW,
-                                moved_out,
-                            ),
-                        )*
+        {
+            type Error = ::structural::validate::ValidationErrors;
+
+            fn try_from_structural(
+                from: __Struc_From,
+            ) -> ::structural::pmr::Result<
+                Self,
+                ::structural::pmr::TryFromError<__Struc_From, Self::Error>,
+            > {
+                let mut errors = ::structural::validate::ValidationErrors::new();
+                #(#field_checks)*
+                if !errors.is_empty() {
+                    return ::structural::pmr::Err(
+                        ::structural::pmr::TryFromError::new(from, errors)
                     );
-
-                    Self{
-                        #(#into_fields)*
-                        #(#init_fields)*
-                    }
                 }
+
+                #converting
             }
         }
-    })
+
+        #from_loaded
+    )
 }
 
 fn deriving_from_structural_enum<'a>(
@@ -0,0 +1,129 @@
+use super::attribute_parsing::StructuralOptions;
+
+use as_derive_utils::{
+    datastructure::DataStructure,
+    gen_params_in::{GenParamsIn, InWhat},
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::quote;
+
+use syn::{punctuated::Punctuated, token, TypeParamBound};
+
+/// The `PinField` bounds for every field with mutable accessor impls,
+/// used as supertraits of the `<deriving_type>_SI` trait.
+pub(crate) fn pin_field_supertraits<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+) -> Punctuated<TypeParamBound, token::Add> {
+    pin_fields(ds, options)
+        .map(|(f_tstr, pinning)| -> TypeParamBound {
+            syn::parse_quote!(
+                ::structural::pmr::PinField<
+                    #f_tstr,
+                    Pinning = ::structural::field::pin_projection::#pinning,
+                >
+            )
+        })
+        .collect()
+}
+
+/// The names and `PinField::Pinning` types of every field with mutable accessor impls.
+fn pin_fields<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+) -> impl Iterator<Item = (TokenStream2, TokenStream2)> + 'a {
+    ds.variants[0]
+        .fields
+        .iter()
+        .filter(move |field| {
+            let f_options = &options.fields[*field];
+            f_options.is_pub && f_options.access.has_mutable_access()
+        })
+        .map(move |field| {
+            let f_options = &options.fields[field];
+            let pinning = if f_options.pinned.is_some() {
+                quote!(Pinned)
+            } else {
+                quote!(Unpinned)
+            };
+            (f_options.renamed_ident().tstr_tokens(), pinning)
+        })
+}
+
+/// Generates the `PinField` impls for every field with mutable accessor impls,
+/// as well as the impls that make structurally pinning fields sound.
+pub(crate) fn deriving_pin_projection<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+) -> TokenStream2 {
+    let struct_ = &ds.variants[0];
+
+    let impl_generics = GenParamsIn::new(ds.generics, InWhat::ImplHeader);
+
+    let tyname = ds.name;
+    let (_, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let empty_preds = Punctuated::new();
+    let where_preds = where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates);
+    let extra_where_preds = &options.bounds;
+
+    let pin_field_impls = pin_fields(ds, options).map(|(f_tstr, pinning)| {
+        let where_preds = where_preds.iter();
+        let extra_where_preds = extra_where_preds.iter();
+        quote!(
+            unsafe impl<#impl_generics> ::structural::pmr::PinField<#f_tstr>
+            for #tyname #ty_generics
+            where
+                #(#where_preds,)*
+                #(#extra_where_preds,)*
+            {
+                type Pinning = ::structural::field::pin_projection::#pinning;
+            }
+        )
+    });
+
+    let pinned_tys = struct_
+        .fields
+        .iter()
+        .filter(|field| options.fields[*field].pinned.is_some())
+        .map(|field| field.ty);
+
+    let unpin_generics =
+        GenParamsIn::with_after_lifetimes(ds.generics, InWhat::ImplHeader, quote!('__struc_pin,));
+
+    let where_preds_a = where_preds.iter();
+    let where_preds_b = where_preds.iter();
+
+    quote!(
+        #(#pin_field_impls)*
+
+        // Only requiring the pinned fields to be `Unpin`,
+        // also preventing users from implementing `Unpin` for this type.
+        impl<#unpin_generics> ::structural::pmr::Unpin for #tyname #ty_generics
+        where
+            #(#where_preds_a,)*
+            #(
+                ::structural::pmr::_Structural_PinnedField<'__struc_pin, #pinned_tys>:
+                    ::structural::pmr::Unpin,
+            )*
+        {}
+
+        // Prevents users from implementing `Drop` for this type,
+        // since its `drop` method could move the pinned fields.
+        const _: () = {
+            trait __Struc_MustNotImplDrop {}
+
+            #[allow(drop_bounds)]
+            impl<__Struc_T: ::structural::pmr::Drop> __Struc_MustNotImplDrop for __Struc_T {}
+
+            impl<#impl_generics> __Struc_MustNotImplDrop for #tyname #ty_generics
+            where
+                #(#where_preds_b,)*
+            {}
+        };
+    )
+}
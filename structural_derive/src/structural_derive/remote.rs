@@ -0,0 +1,131 @@
+use super::{attribute_config::RemoteConfig, attribute_parsing::StructuralOptions};
+
+use as_derive_utils::{
+    datastructure::DataStructure,
+    gen_params_in::{GenParamsIn, InWhat},
+};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+use quote::{quote, ToTokens};
+
+use syn::{punctuated::Punctuated, Ident};
+
+/// The type that the accessor traits are implemented for,
+/// either the remote type or the newtype wrapping it.
+pub(crate) fn remote_self_type<'a>(
+    ds: &'a DataStructure<'a>,
+    remote: &RemoteConfig,
+) -> TokenStream2 {
+    let (_, ty_generics, _) = ds.generics.split_for_impl();
+    match &remote.newtype {
+        Some(newtype) => quote!(#newtype #ty_generics),
+        None => {
+            let path = &remote.path;
+            quote!(#path #ty_generics)
+        }
+    }
+}
+
+/// Generates the newtype wrapper (if there is one),
+/// and checks that the deriving type mirrors the fields of the remote type.
+pub(crate) fn deriving_remote<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+    remote: &RemoteConfig,
+) -> TokenStream2 {
+    let struct_ = &ds.variants[0];
+
+    let impl_generics = GenParamsIn::new(ds.generics, InWhat::ImplHeader);
+
+    let tyname = ds.name;
+    let path = &remote.path;
+    let (_, ty_generics, where_clause) = ds.generics.split_for_impl();
+    let remote_ty = quote!(#path #ty_generics);
+
+    let empty_preds = Punctuated::new();
+    let where_preds = where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates);
+    let extra_where_preds = &options.bounds;
+
+    let field_names = struct_.fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let field_vars = (0..struct_.fields.len())
+        .map(|i| Ident::new(&format!("field_{}", i), Span::call_site()))
+        .collect::<Vec<_>>();
+    let field_names = &field_names;
+    let field_vars = &field_vars;
+
+    let fields_check = {
+        let where_preds = where_preds.iter();
+        let extra_where_preds = extra_where_preds.iter();
+        quote!(
+            const _: () = {
+                // Errors if the deriving type doesn't have the same fields as the remote type,
+                // with the same types.
+                #[allow(dead_code)]
+                fn __struc_check_remote_fields<#impl_generics>(this: #remote_ty) -> #remote_ty
+                where
+                    #(#where_preds,)*
+                    #(#extra_where_preds,)*
+                {
+                    let #path { #( #field_names: #field_vars, )* } = this;
+                    let #tyname { #( #field_names: #field_vars, )* } =
+                        #tyname { #( #field_names: #field_vars, )* };
+                    #path { #( #field_names: #field_vars, )* }
+                }
+            };
+        )
+    };
+
+    let newtype = match &remote.newtype {
+        Some(x) => x,
+        None => return fields_check,
+    };
+
+    let vis = ds.vis;
+    let generics = ds.generics;
+    let docs = format!(
+        "A newtype wrapper around `{}`,implementing the accessor traits for its fields.",
+        path.to_token_stream(),
+    );
+    let where_preds_a = where_preds.iter();
+    let extra_where_preds_a = extra_where_preds.iter();
+    let where_preds_b = where_preds.iter();
+    let extra_where_preds_b = extra_where_preds.iter();
+    let where_preds_c = where_preds.iter();
+    let extra_where_preds_c = extra_where_preds.iter();
+
+    quote!(
+        #fields_check
+
+        #[doc=#docs]
+        #[repr(transparent)]
+        #vis struct #newtype #generics (pub #remote_ty)
+        where
+            #(#where_preds_a,)*
+            #(#extra_where_preds_a,)*;
+
+        impl<#impl_generics> ::structural::pmr::From<#remote_ty> for #newtype #ty_generics
+        where
+            #(#where_preds_b,)*
+            #(#extra_where_preds_b,)*
+        {
+            #[inline(always)]
+            fn from(this: #remote_ty) -> Self {
+                #newtype(this)
+            }
+        }
+
+        impl<#impl_generics> ::structural::pmr::From<#newtype #ty_generics> for #remote_ty
+        where
+            #(#where_preds_c,)*
+            #(#extra_where_preds_c,)*
+        {
+            #[inline(always)]
+            fn from(this: #newtype #ty_generics) -> Self {
+                this.0
+            }
+        }
+    )
+}
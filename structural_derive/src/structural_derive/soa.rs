@@ -0,0 +1,128 @@
+use super::attribute_parsing::StructuralOptions;
+
+use as_derive_utils::{
+    datastructure::DataStructure,
+    gen_params_in::{GenParamsIn, InWhat},
+};
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+use quote::quote;
+
+use syn::{punctuated::Punctuated, Ident};
+
+/// Generates the `<DerivingType>Columns` struct and the `SoaFields` impl,
+/// for the `#[struc(soa)]` attribute.
+pub(crate) fn deriving_soa<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+) -> TokenStream2 {
+    let struct_ = &ds.variants[0];
+
+    let vis = ds.vis;
+    let tyname = ds.name;
+    let columns_name = Ident::new(&format!("{}Columns", tyname), tyname.span());
+
+    let decl_generics = GenParamsIn::new(ds.generics, InWhat::ItemDecl);
+    let impl_generics = GenParamsIn::new(ds.generics, InWhat::ImplHeader);
+    let (_, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let empty_preds = Punctuated::new();
+    let where_preds = where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates)
+        .iter()
+        .chain(options.bounds.iter())
+        .collect::<Vec<_>>();
+    let where_preds = quote!( #(#where_preds,)* );
+
+    let pub_fields = struct_
+        .fields
+        .iter()
+        .filter(|field| options.fields[*field].is_pub)
+        .collect::<Vec<_>>();
+
+    let column_idents = pub_fields.iter().map(|f| f.ident()).collect::<Vec<_>>();
+    let field_vars = (0..pub_fields.len())
+        .map(|i| Ident::new(&format!("__struc_field_{}", i), Span::call_site()))
+        .collect::<Vec<_>>();
+    let column_names = pub_fields.iter().map(|f| {
+        let (name, _) = options.fields[*f].renamed_ident().string_and_span();
+        name
+    });
+    let column_tys = pub_fields.iter().map(|f| f.ty);
+    let field_tstrs = pub_fields
+        .iter()
+        .map(|f| options.fields[*f].renamed_ident().tstr_tokens());
+
+    let push_columns = if pub_fields.is_empty() {
+        quote!(::structural::pmr::drop(self);)
+    } else {
+        quote!(
+            let (#(#field_vars,)*) = ::structural::StructuralExt::into_fields(
+                self,
+                unsafe {
+                    ::structural::pmr::FieldPathSet::<
+                        (#(#field_tstrs,)*),
+                        ::structural::pmr::AliasedPaths,
+                    >::NEW
+                    .upgrade_unchecked()
+                },
+            );
+            #( columns.#column_idents.push(#field_vars); )*
+        )
+    };
+
+    let columns_docs = format!(
+        "The columns of a `SoaVec<{0}>`,with a `Vec` for each public field of `{0}`.",
+        tyname,
+    );
+
+    quote!(
+        #[doc = #columns_docs]
+        #[derive(::structural::Structural)]
+        #[struc(no_trait, no_docs, access = "mut")]
+        #vis struct #columns_name<#decl_generics>
+        where
+            #where_preds
+        {
+            #(
+                #[struc(rename = #column_names)]
+                pub #column_idents: ::structural::pmr::Vec<#column_tys>,
+            )*
+            __struc_marker: ::structural::pmr::PhantomData<fn() -> #tyname #ty_generics>,
+        }
+
+        impl<#impl_generics> ::structural::pmr::Default for #columns_name #ty_generics
+        where
+            #where_preds
+        {
+            fn default() -> Self {
+                Self {
+                    #( #column_idents: ::structural::pmr::Vec::new(), )*
+                    __struc_marker: ::structural::pmr::PhantomData,
+                }
+            }
+        }
+
+        #[allow(unused_variables)]
+        impl<#impl_generics> ::structural::soa::SoaFields for #tyname #ty_generics
+        where
+            #where_preds
+        {
+            type Columns = #columns_name #ty_generics;
+
+            fn push_columns(self, columns: &mut Self::Columns) {
+                #push_columns
+            }
+
+            fn reserve_columns(columns: &mut Self::Columns, additional: usize) {
+                #( columns.#column_idents.reserve(additional); )*
+            }
+
+            fn truncate_columns(columns: &mut Self::Columns, len: usize) {
+                #( columns.#column_idents.truncate(len); )*
+            }
+        }
+    )
+}
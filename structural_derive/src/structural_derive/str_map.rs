@@ -0,0 +1,115 @@
+use super::attribute_parsing::StructuralOptions;
+
+use as_derive_utils::{
+    datastructure::DataStructure,
+    gen_params_in::{GenParamsIn, InWhat},
+    spanned_err,
+    utils::{LinearResult, SynResultExt},
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::quote;
+
+use syn::punctuated::Punctuated;
+
+/// Generates the `StrMapFields` impl,for the `#[struc(from_str_map)]` attribute.
+///
+/// The `FromStructural` impl that the loaded fields are converted with
+/// is derived by the `#[struc(from_structural)]` attribute,which this attribute implies.
+pub(crate) fn deriving_from_str_map<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+) -> Result<TokenStream2, syn::Error> {
+    let struct_ = &ds.variants[0];
+
+    let mut res = LinearResult::ok(());
+
+    for field in &struct_.fields {
+        let f_options = &options.fields[field];
+        if let Some(span) = f_options.str_map_nested {
+            if !f_options.is_pub {
+                res.push_err(syn::Error::new(
+                    span,
+                    "Cannot use the `#[struc(str_map_nested)]` attribute on private fields",
+                ));
+            }
+        }
+        if f_options.is_pub
+            && f_options.init_with.is_some()
+            && !f_options.access.has_mutable_access()
+        {
+            res.push_err(spanned_err!(
+                field.ident(),
+                "Public fields with an `init_*` attribute must have mutable access \
+                 to use the `#[struc(from_str_map)]` attribute,\
+                 since they're assigned after converting the other fields.",
+            ));
+        }
+    }
+
+    res.take()?;
+
+    let impl_generics = GenParamsIn::new(ds.generics, InWhat::ImplHeader);
+
+    let tyname = ds.name;
+    let (_, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let empty_preds = Punctuated::new();
+    let where_preds = where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates)
+        .iter();
+    let extra_where_preds = options.bounds.iter();
+
+    // Gets the `TList` of the names of the public fields
+    // with(out) the `str_map_nested` and `init_*` attributes.
+    let field_names = |nested: bool, with_init: bool| {
+        struct_
+            .fields
+            .iter()
+            .rev()
+            .filter(|field| {
+                let f_options = &options.fields[*field];
+                f_options.is_pub
+                    && f_options.str_map_nested.is_some() == nested
+                    && f_options.init_with.is_some() == with_init
+            })
+            .fold(quote!(::structural::pmr::TNil), |rem, field| {
+                let f_tstr = options.fields[field].renamed_ident().tstr_tokens();
+                quote!(::structural::pmr::TList<#f_tstr, #rem>)
+            })
+    };
+    let parsed = field_names(false, false);
+    let nested = field_names(true, false);
+    let init_parsed = field_names(false, true);
+    let init_nested = field_names(true, true);
+
+    let validation = if options.has_validators {
+        quote!(
+            fn validate_loaded(
+                &self,
+            ) -> ::structural::pmr::Result<(), ::structural::validate::ValidationErrors>
+            {
+                ::structural::validate::Validate::validate(self)
+            }
+        )
+    } else {
+        TokenStream2::new()
+    };
+
+    Ok(quote!(
+        impl<#impl_generics> ::structural::str_map::StrMapFields for #tyname #ty_generics
+        where
+            #(#where_preds,)*
+            #(#extra_where_preds,)*
+        {
+            type Parsed = #parsed;
+            type Nested = #nested;
+            type InitParsed = #init_parsed;
+            type InitNested = #init_nested;
+
+            #validation
+        }
+    ))
+}
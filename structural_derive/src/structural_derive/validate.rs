@@ -0,0 +1,101 @@
+use super::attribute_parsing::StructuralOptions;
+
+use as_derive_utils::{
+    datastructure::DataStructure,
+    gen_params_in::{GenParamsIn, InWhat},
+};
+
+use proc_macro2::TokenStream as TokenStream2;
+
+use quote::quote;
+
+use syn::punctuated::Punctuated;
+
+/// Generates the `Validate` impl,and a `ValidateField` impl for every public field.
+pub(crate) fn deriving_validate<'a>(
+    ds: &'a DataStructure<'a>,
+    options: &'a StructuralOptions<'a>,
+) -> TokenStream2 {
+    let struct_ = &ds.variants[0];
+
+    let impl_generics = GenParamsIn::new(ds.generics, InWhat::ImplHeader);
+
+    let tyname = ds.name;
+    let (_, ty_generics, where_clause) = ds.generics.split_for_impl();
+
+    let empty_preds = Punctuated::new();
+    let where_preds = where_clause
+        .as_ref()
+        .map_or(&empty_preds, |x| &x.predicates);
+    let extra_where_preds = &options.bounds;
+
+    let field_checks = struct_
+        .fields
+        .iter()
+        .map(|field| {
+            let f_options = &options.fields[field];
+            f_options.validate.as_ref().map(|validator| {
+                let name_str = f_options.renamed_ident().to_string();
+                let ident = &field.ident;
+                quote!( errors.check_field(#name_str, #validator(&self.#ident)); )
+            })
+        })
+        .collect::<Vec<Option<TokenStream2>>>();
+
+    let all_field_checks = field_checks.iter().flatten();
+
+    let container_check = options.validate.iter();
+
+    let field_impls = struct_
+        .fields
+        .iter()
+        .zip(&field_checks)
+        .filter(|(field, _)| options.fields[*field].is_pub)
+        .map(|(field, check)| {
+            let f_tstr = options.fields[field].renamed_ident().tstr_tokens();
+            let check = check.iter();
+            let where_preds = where_preds.iter();
+            let extra_where_preds = extra_where_preds.iter();
+            quote!(
+                impl<#impl_generics> ::structural::validate::ValidateField<#f_tstr>
+                for #tyname #ty_generics
+                where
+                    #(#where_preds,)*
+                    #(#extra_where_preds,)*
+                {
+                    #[inline]
+                    #[allow(unused_variables)]
+                    fn validate_field(
+                        &self,
+                        _: #f_tstr,
+                        errors: &mut ::structural::validate::ValidationErrors,
+                    ) {
+                        #(#check)*
+                    }
+                }
+            )
+        });
+
+    let where_preds = where_preds.iter();
+    let extra_where_preds = extra_where_preds.iter();
+
+    quote!(
+        impl<#impl_generics> ::structural::validate::Validate for #tyname #ty_generics
+        where
+            #(#where_preds,)*
+            #(#extra_where_preds,)*
+        {
+            fn validate(&self) -> ::structural::pmr::Result<
+                (),
+                ::structural::validate::ValidationErrors,
+            > {
+                let mut errors = ::structural::validate::ValidationErrors::new();
+                #(#all_field_checks)*
+                #( errors.check_container(#container_check(self)); )*
+                errors.into_result()
+            }
+        }
+
+        #(#field_impls)*
+    )
+}
@@ -262,6 +262,70 @@ fn write_field_docs(
     Ok(())
 }
 
+/// Writes the plain text list of requirements of a trait alias,
+/// used as the `note` of its `#[diagnostic::on_unimplemented]` attribute.
+pub(crate) fn write_unimplemented_note(
+    buff: &mut String,
+    datatype: &StructuralDataType<'_>,
+) -> std::fmt::Result {
+    for variant in &datatype.variants {
+        let variant_name = ident_type_name(variant.name);
+        match &variant.replace_bounds {
+            Some(replace_bounds) => writeln!(
+                buff,
+                "- the `{}` variant, with the `{}` bounds",
+                variant_name,
+                replace_bounds.get_docs(variant.name),
+            )?,
+            None => writeln!(buff, "- the `{}` variant", variant_name)?,
+        }
+        if variant.replace_bounds.is_none() && !variant.is_newtype {
+            for field in &variant.fields {
+                write_unimplemented_field(buff, Some(&variant_name), field)?;
+            }
+        }
+    }
+    for field in &datatype.fields {
+        write_unimplemented_field(buff, None, field)?;
+    }
+    Ok(())
+}
+
+fn write_unimplemented_field(
+    buff: &mut String,
+    variant_name: Option<&str>,
+    field: &StructuralField<'_>,
+) -> std::fmt::Result {
+    let access_desc = match field.access {
+        Access::Shared => "by reference",
+        Access::Mutable => "by reference and mutable reference",
+        Access::Value => "by reference and by value",
+        Access::MutValue => "by reference, mutable reference, and by value",
+    };
+    let field_ty = match field.ty {
+        FieldType::Ty(ty) => format!("{}", ty.to_token_stream()),
+        FieldType::Impl(bounds) => format!("impl {}", bounds.to_token_stream()),
+    };
+    write!(
+        buff,
+        "- the `{}: {}` field",
+        ident_type_name(field.ident),
+        field_ty
+    )?;
+    if let Some(variant_name) = variant_name {
+        write!(buff, " in the `{}` variant", variant_name)?;
+    }
+    writeln!(buff, ", accessible {}", access_desc)
+}
+
+fn ident_type_name(ident: IdentType<'_>) -> String {
+    match ident {
+        IdentType::Ident(ident) => ident.to_string(),
+        IdentType::Generic(generic) => format!("<{}>", generic),
+        IdentType::SomeType(ty) => format!("<{}>", ty.to_token_stream()),
+    }
+}
+
 const SPACES_X8: &str = "&nbsp;&nbsp;&nbsp;&nbsp;&nbsp;&nbsp;&nbsp;&nbsp;";
 
 const GENERIC_ENUM_NAME_DOCS: &str = "